@@ -1,12 +1,20 @@
 use std::{fs, io};
 
-pub fn classes_to_lib_rs(mut class_names: Vec<&str>) -> io::Result<()> {
-    class_names.sort_unstable();
+/// Replaces the list called `list_name` in the `pynamedmodule!` call in `src/lib.rs`
+fn replace_list(lib_rs: &mut String, list_name: &str, mut names: Vec<String>) {
+    names.sort_unstable();
     let file_contents = format!(
-        "    classes: [\n        {}\n    ],",
-        class_names.join(",\n        ")
+        "    {list_name}: [\n        {}\n    ],",
+        names.join(",\n        ")
     );
 
+    let start = lib_rs.find(&format!("    {list_name}: [\n")).unwrap();
+    let end = lib_rs[start..].find("],").unwrap() + 2;
+
+    lib_rs.replace_range(start..start + end, &file_contents);
+}
+
+pub fn classes_to_lib_rs(class_names: Vec<String>, enum_names: Vec<String>) -> io::Result<()> {
     let mut lib_rs = fs::read_to_string("src/lib.rs")?;
 
     #[cfg(windows)]
@@ -14,10 +22,8 @@ pub fn classes_to_lib_rs(mut class_names: Vec<&str>) -> io::Result<()> {
         lib_rs = lib_rs.replace("\r\n", "\n");
     }
 
-    let start = lib_rs.find("    classes: [\n").unwrap();
-    let end = lib_rs[start..].find("],").unwrap() + 2;
-
-    lib_rs.replace_range(start..start + end, &file_contents);
+    replace_list(&mut lib_rs, "classes", class_names);
+    replace_list(&mut lib_rs, "enums", enum_names);
     fs::write("src/lib.rs", lib_rs)?;
 
     Ok(())
@@ -31,7 +37,7 @@ pub fn add_pyclass_to_planus_enum(file: &mut String, name: &str) {
     file.replace_range(
         start_pos..end_pos,
         &format!(
-            "#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n#[repr(u8)]\n#[::pyo3::pyclass(module = \"rlbot_flatbuffers\", from_py_object, frozen, hash, eq, eq_int)]\n{find_str}#[default]"
+            "#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n#[repr(u8)]\n#[::pyo3::pyclass(module = \"rlbot_flatbuffers\", from_py_object, frozen)]\n{find_str}#[default]"
         )
     );
 }
@@ -121,6 +121,109 @@ impl<'a> EnumBindGenerator<'a> {
         write_str!(self, "    }");
     }
 
+    fn generate_from_name_method(&mut self) {
+        write_str!(self, "    #[staticmethod]");
+        write_str!(self, "    pub fn from_name(name: &str) -> PyResult<Self> {");
+        write_str!(self, "        match crate::enum_name_key(name).as_str() {");
+
+        for var_info in self.variants.values() {
+            let var_name = normalize_caps(&var_info.name);
+            write_fmt!(
+                self,
+                "            \"{}\" => Ok(Self::{var_name}),",
+                var_name.to_ascii_lowercase()
+            );
+        }
+
+        write_fmt!(
+            self,
+            "            _ => Err(PyValueError::new_err(format!(\"Unknown {} name '{{name}}'\"))),",
+            self.name
+        );
+
+        write_str!(self, "        }");
+        write_str!(self, "    }");
+    }
+
+    fn generate_members_attr(&mut self) {
+        write_str!(self, "    #[classattr]");
+        write_str!(
+            self,
+            "    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {"
+        );
+        write_str!(self, "        let members = PyDict::new(py);");
+
+        for var_info in self.variants.values() {
+            let var_name = normalize_caps(&var_info.name);
+            write_fmt!(
+                self,
+                "        members.set_item(\"{var_name}\", Self::{var_name})?;"
+            );
+        }
+
+        write_str!(
+            self,
+            "        Ok(PyMappingProxy::new(py, members.as_mapping()))"
+        );
+        write_str!(self, "    }");
+    }
+
+    fn generate_members_method(&mut self) {
+        write_str!(self, "    #[classmethod]");
+        write_str!(self, "    fn members(_cls: &Bound<PyType>) -> Vec<Self> {");
+        write_str!(self, "        vec![");
+
+        for var_info in self.variants.values() {
+            write_fmt!(
+                self,
+                "            Self::{},",
+                normalize_caps(&var_info.name)
+            );
+        }
+
+        write_str!(self, "        ]");
+        write_str!(self, "    }");
+    }
+
+    fn generate_name_value_getters(&mut self) {
+        write_str!(self, "    #[getter]");
+        write_str!(self, "    pub fn name(&self) -> &'static str {");
+        write_str!(self, "        match self {");
+
+        for var_info in self.variants.values() {
+            let var_name = normalize_caps(&var_info.name);
+            write_fmt!(self, "            Self::{var_name} => \"{var_name}\",");
+        }
+
+        write_str!(self, "        }");
+        write_str!(self, "    }");
+        write_str!(self, "");
+
+        write_str!(self, "    #[getter]");
+        write_str!(self, "    pub fn value(&self) -> u8 {");
+        write_str!(self, "        *self as u8");
+        write_str!(self, "    }");
+    }
+
+    fn generate_int_methods(&mut self) {
+        write_str!(self, "    pub fn __index__(&self) -> u8 {");
+        write_str!(self, "        *self as u8");
+        write_str!(self, "    }");
+        write_str!(self, "");
+
+        write_str!(self, "    pub fn __hash__(&self) -> u64 {");
+        write_str!(self, "        *self as u64");
+        write_str!(self, "    }");
+        write_str!(self, "");
+
+        write_str!(
+            self,
+            "    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {"
+        );
+        write_str!(self, "        crate::enum_richcmp(*self as u8, other, op)");
+        write_str!(self, "    }");
+    }
+
     fn generate_str_method(&mut self) {
         write_str!(self, "    pub fn __str__(&self) -> String {");
         write_str!(self, "        self.__repr__()");
@@ -140,6 +243,21 @@ impl<'a> EnumBindGenerator<'a> {
         self.generate_new_method();
         write_str!(self, "");
 
+        self.generate_from_name_method();
+        write_str!(self, "");
+
+        self.generate_members_attr();
+        write_str!(self, "");
+
+        self.generate_members_method();
+        write_str!(self, "");
+
+        self.generate_name_value_getters();
+        write_str!(self, "");
+
+        self.generate_int_methods();
+        write_str!(self, "");
+
         self.generate_str_method();
         write_str!(self, "");
 
@@ -152,7 +270,7 @@ impl<'a> EnumBindGenerator<'a> {
         write_str!(self, "use crate::flat;");
         write_str!(
             self,
-            "use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};"
+            "use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};"
         );
         write_str!(self, "");
         write_fmt!(self, "pub use flat::{};", self.name);
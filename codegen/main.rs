@@ -18,6 +18,7 @@ use crate::{
 mod class_inject;
 mod enums;
 mod pyi;
+mod schema;
 mod structs;
 mod table;
 mod unions;
@@ -162,7 +163,8 @@ fn main() -> eyre::Result<()> {
     }
 
     let mut python_mod: Vec<String> = Vec::with_capacity(declarations.declarations.len());
-    let mut class_names: Vec<&str> = Vec::with_capacity(declarations.declarations.len());
+    let mut class_names: Vec<String> = Vec::with_capacity(declarations.declarations.len());
+    let mut enum_names: Vec<String> = Vec::new();
     let mut python_files = Vec::with_capacity(declarations.declarations.len() + 1);
     python_files.push(String::from("mod.rs"));
 
@@ -195,24 +197,36 @@ fn main() -> eyre::Result<()> {
 
                     let mut file_name = camel_to_snake(item_name);
 
-                    let (class_name, file_contents) = match &item.kind {
+                    let (class_names, enum_names, file_contents) = match &item.kind {
                         DeclarationKind::Table(info) => {
                             let bind_gen =
                                 TableBindGenerator::new(item_name, &info.fields, declarations);
-                            (Some(item_name), bind_gen.generate_binds())
+                            (
+                                bind_gen.class_names(),
+                                Vec::new(),
+                                bind_gen.generate_binds(),
+                            )
                         }
                         DeclarationKind::Struct(info) => {
                             let bind_gen =
                                 StructBindGenerator::new(item_name, &info.fields, declarations);
-                            (Some(item_name), bind_gen.generate_binds())
+                            (
+                                bind_gen.class_names(),
+                                Vec::new(),
+                                bind_gen.generate_binds(),
+                            )
                         }
                         DeclarationKind::Enum(info) => {
                             let bind_gen = EnumBindGenerator::new(item_name, &info.variants);
-                            (Some(item_name), bind_gen.generate_binds())
+                            (
+                                Vec::new(),
+                                vec![item_name.to_string()],
+                                bind_gen.generate_binds(),
+                            )
                         }
                         DeclarationKind::Union(info) => {
                             let bind_gen = UnionBindGenerator::new(item_name, &info.variants);
-                            (None, bind_gen.generate_binds())
+                            (Vec::new(), Vec::new(), bind_gen.generate_binds())
                         }
                         DeclarationKind::RpcService(_) => unimplemented!(),
                     };
@@ -229,17 +243,17 @@ fn main() -> eyre::Result<()> {
                     )
                     .unwrap();
 
-                    tx.send((class_name, mod_lines, file_name)).unwrap();
+                    tx.send((class_names, enum_names, mod_lines, file_name))
+                        .unwrap();
                 }
             });
         }
 
         drop(tx);
 
-        for (class_name, mod_lines, file_name) in rx.iter() {
-            if let Some(class_name) = class_name {
-                class_names.push(class_name);
-            }
+        for (new_class_names, new_enum_names, mod_lines, file_name) in rx.iter() {
+            class_names.extend(new_class_names);
+            enum_names.extend(new_enum_names);
 
             python_mod.push(mod_lines);
             python_files.push(file_name);
@@ -283,8 +297,11 @@ fn main() -> eyre::Result<()> {
 
     fs::write(OUT_FILE, format_string(&generated_planus)?.as_bytes())?;
 
-    class_inject::classes_to_lib_rs(class_names)?;
+    class_inject::classes_to_lib_rs(class_names, enum_names)?;
     pyi::generator(&declarations)?;
 
+    let schema_data = schema::generator(&declarations);
+    fs::write(schema::SCHEMA_OUT_FILE, format_string(&schema_data)?)?;
+
     Ok(())
 }
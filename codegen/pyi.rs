@@ -1,4 +1,4 @@
-use crate::{enums::normalize_caps, structs::DEFAULT_OVERRIDES};
+use crate::{FROZEN_TYPES, enums::normalize_caps, structs::DEFAULT_OVERRIDES};
 use planus_types::{
     ast::IntegerType,
     intermediate::{AssignMode, DeclarationKind, Declarations, SimpleType, TypeKind},
@@ -17,19 +17,104 @@ macro_rules! write_fmt {
     };
 }
 
+/// Stubs for the base classes of all generated types
+const BASES_STUB: &str = include_str!("stubs/bases.pyi");
+
+/// Stubs for the hand-written methods of generated classes,
+/// which are added to the end of the class
+const CLASS_STUBS: [(&str, &str); 11] = [
+    ("BallPrediction", include_str!("stubs/ball_prediction.pyi")),
+    (
+        "DesiredGameState",
+        include_str!("stubs/desired_game_state.pyi"),
+    ),
+    ("FieldInfo", include_str!("stubs/field_info.pyi")),
+    ("GamePacket", include_str!("stubs/game_packet.pyi")),
+    ("Physics", include_str!("stubs/physics.pyi")),
+    ("PlayerInfo", include_str!("stubs/player_info.pyi")),
+    ("PolyLine3D", include_str!("stubs/poly_line3_d.pyi")),
+    ("Rotator", include_str!("stubs/rotator.pyi")),
+    ("Vector2", include_str!("stubs/vector2.pyi")),
+    ("Vector3", include_str!("stubs/vector3.pyi")),
+    ("Vector3Partial", include_str!("stubs/vector3_partial.pyi")),
+];
+
+/// The package that the stubs are written to,
+/// which maturin ships with the extension module
+const PACKAGE_DIR: &str = "python/rlbot_flatbuffers";
+
+/// Stubs of the submodules, which aren't generated from the schema.
+/// Each one is written to its own file, so they can be imported like `import rlbot_flatbuffers.schema`
+const SUBMODULE_STUBS: [(&str, &str); 9] = [
+    ("arena", include_str!("stubs/arena.pyi")),
+    ("arrow", include_str!("stubs/arrow.pyi")),
+    ("boost", include_str!("stubs/boost.pyi")),
+    ("delta", include_str!("stubs/delta.pyi")),
+    ("events", include_str!("stubs/events.pyi")),
+    ("intercept", include_str!("stubs/intercept.pyi")),
+    ("path", include_str!("stubs/path.pyi")),
+    ("recording", include_str!("stubs/recording.pyi")),
+    ("schema", include_str!("stubs/schema.pyi")),
+];
+
 pub fn generator(type_data: &Declarations) -> io::Result<()> {
     let mut file = vec![
         Cow::Borrowed("from __future__ import annotations"),
         Cow::Borrowed(""),
-        Cow::Borrowed("from typing import Sequence"),
+        Cow::Borrowed("from abc import ABC"),
+        Cow::Borrowed("from typing import Any, Iterable, Iterator, Literal, Mapping, Sequence"),
+        Cow::Borrowed(""),
+        Cow::Borrowed("from . import arena as arena"),
+        Cow::Borrowed("from . import arrow as arrow"),
+        Cow::Borrowed("from . import boost as boost"),
+        Cow::Borrowed("from . import delta as delta"),
+        Cow::Borrowed("from . import events as events"),
+        Cow::Borrowed("from . import intercept as intercept"),
+        Cow::Borrowed("from . import path as path"),
+        Cow::Borrowed("from . import recording as recording"),
+        Cow::Borrowed("from . import schema as schema"),
         Cow::Borrowed(""),
         Cow::Borrowed("__doc__: str"),
         Cow::Borrowed("__version__: str"),
         Cow::Borrowed(""),
         Cow::Borrowed("class InvalidFlatbuffer(ValueError): ..."),
         Cow::Borrowed(""),
+        Cow::Borrowed(BASES_STUB.trim_end()),
+        Cow::Borrowed(""),
     ];
 
+    let mut unions: Vec<_> = type_data
+        .iter_declarations()
+        .filter_map(|(_, full_type_name, item)| match &item.kind {
+            DeclarationKind::Union(info) => Some((full_type_name.0.last().unwrap(), item, info)),
+            _ => None,
+        })
+        .collect();
+    unions.sort_by_key(|(type_name, _, _)| *type_name);
+
+    for (type_name, item, info) in &unions {
+        write_fmt!(file, "class {type_name}(ABC):");
+        write_str!(file, "    \"\"\"");
+
+        if item.docstrings.docstrings.is_empty() {
+            write_fmt!(file, "    Marker class of the `{type_name}` union.");
+        } else {
+            for docstring in &item.docstrings.docstrings {
+                write_fmt!(file, "    {}", docstring.value.trim());
+            }
+        }
+
+        write_str!(file, "    \"\"\"\n");
+
+        let variants: Vec<_> = info
+            .variants
+            .keys()
+            .map(|var_name| format!("type[{var_name}]"))
+            .collect();
+        write_fmt!(file, "    variants: tuple[{}]", variants.join(", "));
+        write_str!(file, "");
+    }
+
     let mut sorted_types: Vec<_> = type_data.iter_declarations().collect();
     sorted_types.sort_by_cached_key(|(idx, full_type_name, item)| match &item.kind {
         DeclarationKind::Enum(_) => (0, 0, 0, 0, full_type_name.0.last().unwrap().as_str()),
@@ -59,7 +144,23 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
 
         let type_name = full_type_name.0.last().unwrap();
 
-        write_fmt!(file, "class {type_name}:");
+        let mut bases = match &item.kind {
+            DeclarationKind::Table(_) => vec!["Table"],
+            DeclarationKind::Struct(_) => vec!["Struct"],
+            _ => Vec::new(),
+        };
+        bases.extend(
+            unions
+                .iter()
+                .filter(|(_, _, info)| info.variants.contains_key(type_name))
+                .map(|(union_name, _, _)| union_name.as_str()),
+        );
+
+        if bases.is_empty() {
+            write_fmt!(file, "class {type_name}:");
+        } else {
+            write_fmt!(file, "class {type_name}({}):", bases.join(", "));
+        }
 
         if !item.docstrings.docstrings.is_empty() {
             write_str!(file, "    \"\"\"");
@@ -71,6 +172,14 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
             write_str!(file, "    \"\"\"\n");
         }
 
+        let fields_start = file.len();
+        let has_builder = FROZEN_TYPES.contains(&type_name.as_str())
+            && match &item.kind {
+                DeclarationKind::Struct(info) => !info.fields.is_empty(),
+                DeclarationKind::Table(info) => !info.fields.is_empty(),
+                _ => false,
+            };
+
         match &item.kind {
             DeclarationKind::Enum(info) => {
                 for (var_val, var_info) in &info.variants {
@@ -94,6 +203,11 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
                     write_str!(file, "    \"\"\"");
                 }
 
+                write_str!(file, "");
+                write_fmt!(file, "    __members__: Mapping[str, {type_name}]");
+                write_str!(file, "    \"\"\"");
+                write_str!(file, "    All members of this enum, by name");
+                write_str!(file, "    \"\"\"");
                 write_str!(file, "");
                 write_fmt!(
                     file,
@@ -106,8 +220,51 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
                     "        :raises ValueError: If the `value` is not a valid enum value"
                 );
                 write_str!(file, "        \"\"\"");
+                write_str!(file, "    @staticmethod");
+                write_fmt!(file, "    def from_name(name: str) -> {type_name}:");
+                write_str!(file, "        \"\"\"");
+                write_str!(
+                    file,
+                    "        Gets the member with the given name, ignoring case and underscores\n"
+                );
+                write_str!(
+                    file,
+                    "        :raises ValueError: If there is no member with the given `name`"
+                );
+                write_str!(file, "        \"\"\"");
+                write_str!(file, "    @classmethod");
+                write_fmt!(file, "    def members(cls) -> list[{type_name}]:");
+                write_str!(file, "        \"\"\"");
+                write_str!(
+                    file,
+                    "        All members of this enum, in the order of their values."
+                );
+                write_fmt!(
+                    file,
+                    "        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in {type_name}.members():`"
+                );
+                write_str!(file, "        \"\"\"");
+                write_str!(file, "    @property");
+                write_str!(file, "    def name(self) -> str: ...");
+                write_str!(file, "    @property");
+                write_str!(file, "    def value(self) -> int: ...");
                 write_str!(file, "    def __int__(self) -> int: ...");
-                write_str!(file, "    def __eq__(self, other) -> bool: ...");
+                write_str!(file, "    def __index__(self) -> int:");
+                write_str!(file, "        \"\"\"");
+                write_str!(
+                    file,
+                    "        Lets enums be used in place of an int, e.g. as a list index,"
+                );
+                write_str!(
+                    file,
+                    "        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`"
+                );
+                write_str!(file, "        \"\"\"");
+                write_str!(file, "    def __eq__(self, other: object) -> bool: ...");
+                write_str!(file, "    def __lt__(self, other: int) -> bool: ...");
+                write_str!(file, "    def __le__(self, other: int) -> bool: ...");
+                write_str!(file, "    def __gt__(self, other: int) -> bool: ...");
+                write_str!(file, "    def __ge__(self, other: int) -> bool: ...");
                 write_str!(file, "    def __hash__(self) -> int: ...");
             }
             DeclarationKind::Struct(info) => {
@@ -238,6 +395,29 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
                     "        :raises InvalidFlatbuffer: If the `data` is invalid for this type"
                 );
                 write_str!(file, "        \"\"\"\n");
+
+                write_fmt!(
+                    file,
+                    "    def replace(self, **changes: Any) -> {type_name}:"
+                );
+                write_str!(file, "        \"\"\"");
+                write_str!(
+                    file,
+                    "        Creates a copy of this instance with the given fields replaced."
+                );
+                write_str!(
+                    file,
+                    "        Nested fields can be replaced by using a dotted path as the key.\n"
+                );
+                write_str!(
+                    file,
+                    "        :raises TypeError: If a field in `changes` doesn't exist"
+                );
+                write_str!(file, "        \"\"\"\n");
+
+                if has_builder {
+                    write_to_builder_method(&mut file, type_name);
+                }
             }
             DeclarationKind::Table(info) => {
                 for (field_name, field_info) in &info.fields {
@@ -482,16 +662,113 @@ pub fn generator(type_data: &Declarations) -> io::Result<()> {
                     "        :raises InvalidFlatbuffer: If the `data` is invalid for this type"
                 );
                 write_str!(file, "        \"\"\"\n");
+
+                write_fmt!(
+                    file,
+                    "    def replace(self, **changes: Any) -> {type_name}:"
+                );
+                write_str!(file, "        \"\"\"");
+                write_str!(
+                    file,
+                    "        Creates a copy of this instance with the given fields replaced."
+                );
+                write_str!(
+                    file,
+                    "        Nested fields can be replaced by using a dotted path as the key.\n"
+                );
+                write_str!(
+                    file,
+                    "        :raises TypeError: If a field in `changes` doesn't exist"
+                );
+                write_str!(file, "        \"\"\"\n");
+
+                if has_builder {
+                    write_to_builder_method(&mut file, type_name);
+                }
             }
             _ => unimplemented!(),
         }
 
+        for (_, stub) in CLASS_STUBS
+            .iter()
+            .filter(|(class_name, _)| class_name == type_name)
+        {
+            file.push(Cow::Borrowed(stub.trim_end()));
+        }
+
         write_str!(file, "    def __str__(self) -> str: ...");
         write_str!(file, "    def __repr__(self) -> str: ...");
         write_str!(file, "");
+
+        if has_builder {
+            write_builder_class(&mut file, type_name, fields_start);
+        }
     }
 
-    fs::write("rlbot_flatbuffers.pyi", file.join("\n"))?;
+    fs::write(format!("{PACKAGE_DIR}/__init__.pyi"), file.join("\n"))?;
+
+    for (name, stub) in SUBMODULE_STUBS {
+        fs::write(format!("{PACKAGE_DIR}/{name}.pyi"), stub)?;
+    }
 
     Ok(())
 }
+
+fn write_to_builder_method(file: &mut Vec<Cow<str>>, type_name: &str) {
+    write_fmt!(file, "    def to_builder(self) -> {type_name}Builder:");
+    write_str!(file, "        \"\"\"");
+    write_fmt!(
+        file,
+        "        Creates a mutable `{type_name}Builder` with the values of this instance"
+    );
+    write_str!(file, "        \"\"\"\n");
+}
+
+/// The builder of a frozen type has the same fields and constructor,
+/// so those lines are copied from the frozen type's class
+fn write_builder_class(file: &mut Vec<Cow<str>>, type_name: &str, fields_start: usize) {
+    let pack_start = fields_start
+        + file[fields_start..]
+            .iter()
+            .position(|line| line == "    def pack(self) -> bytes:")
+            .unwrap();
+    let new_return = format!("    ) -> {type_name}: ...");
+
+    let builder_lines: Vec<_> = file[fields_start..pack_start]
+        .iter()
+        .map(|line| {
+            if *line == new_return {
+                Cow::Owned(format!("    ) -> {type_name}Builder: ..."))
+            } else {
+                line.clone()
+            }
+        })
+        .collect();
+
+    write_fmt!(file, "class {type_name}Builder(Packable):");
+    write_str!(file, "    \"\"\"");
+    write_fmt!(file, "    A mutable builder for `{type_name}`.");
+    write_str!(file, "    \"\"\"\n");
+
+    file.extend(builder_lines);
+
+    write_fmt!(file, "    def build(self) -> {type_name}:");
+    write_str!(file, "        \"\"\"");
+    write_fmt!(
+        file,
+        "        Creates a new `{type_name}` from the current values of this builder"
+    );
+    write_str!(file, "        \"\"\"\n");
+
+    write_str!(file, "    def pack(self) -> bytes:");
+    write_str!(file, "        \"\"\"");
+    write_fmt!(
+        file,
+        "        Serializes this builder as a `{type_name}` into a byte array"
+    );
+    write_str!(file, "        \"\"\"\n");
+
+    write_str!(file, "    def __str__(self) -> str: ...");
+    write_str!(file, "    def __repr__(self) -> str: ...");
+    write_str!(file, "");
+}
@@ -0,0 +1,318 @@
+use crate::{enums::normalize_caps, structs::DEFAULT_OVERRIDES};
+use planus_types::{
+    ast::IntegerType,
+    intermediate::{
+        AssignMode, DeclarationKind, Declarations, Docstrings, SimpleType, Type, TypeKind,
+    },
+};
+use std::borrow::Cow;
+
+pub const SCHEMA_OUT_FILE: &str = "./src/schema/data.rs";
+
+macro_rules! write_str {
+    ($self:ident, $s:expr) => {
+        $self.push(Cow::Borrowed($s))
+    };
+}
+
+macro_rules! write_fmt {
+    ($self:ident, $($arg:tt)*) => {
+        $self.push(Cow::Owned(format!($($arg)*)))
+    };
+}
+
+/// The type of a field, as it would be written in a Python type hint
+struct FieldType<'a> {
+    hint: Cow<'a, str>,
+    base: &'a str,
+    is_list: bool,
+}
+
+fn declaration_name(type_data: &Declarations, idx: usize) -> &str {
+    let (path, _) = type_data.declarations.get_index(idx).unwrap();
+    path.0.last().unwrap()
+}
+
+fn simple_type_name<'a>(type_data: &'a Declarations, simple_type: &SimpleType) -> &'a str {
+    match simple_type {
+        SimpleType::Bool => "bool",
+        SimpleType::Float(_) => "float",
+        SimpleType::Integer(_) => "int",
+        SimpleType::Enum(idx) => declaration_name(type_data, idx.0),
+        SimpleType::Struct(idx) => match declaration_name(type_data, idx.0) {
+            "Float" => "float",
+            name => name,
+        },
+    }
+}
+
+fn field_type<'a>(type_data: &'a Declarations, type_: &Type) -> FieldType<'a> {
+    let base = match &type_.kind {
+        TypeKind::SimpleType(simple_type) => simple_type_name(type_data, simple_type),
+        TypeKind::Table(idx) | TypeKind::Union(idx) => declaration_name(type_data, idx.0),
+        TypeKind::String => "str",
+        TypeKind::Vector(inner_type) => {
+            return match &inner_type.kind {
+                TypeKind::SimpleType(SimpleType::Integer(IntegerType::U8)) => FieldType {
+                    hint: Cow::Borrowed("bytes"),
+                    base: "bytes",
+                    is_list: false,
+                },
+                _ => {
+                    let base = field_type(type_data, inner_type).base;
+                    FieldType {
+                        hint: Cow::Owned(format!("Sequence[{base}]")),
+                        base,
+                        is_list: true,
+                    }
+                }
+            };
+        }
+        _ => unimplemented!(),
+    };
+
+    let hint = match &type_.kind {
+        TypeKind::Union(idx) => {
+            let (_, info) = type_data.declarations.get_index(idx.0).unwrap();
+            let DeclarationKind::Union(union_info) = &info.kind else {
+                unreachable!()
+            };
+
+            let mut keys: Vec<_> = union_info.variants.keys().cloned().collect();
+            keys.sort_unstable();
+
+            Cow::Owned(keys.join(" | "))
+        }
+        _ => Cow::Borrowed(base),
+    };
+
+    FieldType {
+        hint,
+        base,
+        is_list: false,
+    }
+}
+
+fn simple_type_default(type_data: &Declarations, simple_type: &SimpleType) -> Cow<'static, str> {
+    match simple_type {
+        SimpleType::Bool => Cow::Borrowed("False"),
+        SimpleType::Float(_) => Cow::Borrowed("0.0"),
+        SimpleType::Integer(_) => Cow::Borrowed("0"),
+        SimpleType::Enum(idx) | SimpleType::Struct(idx) => match declaration_name(type_data, idx.0)
+        {
+            "Float" => Cow::Borrowed("0.0"),
+            name => Cow::Owned(format!("{name}()")),
+        },
+    }
+}
+
+fn field_default(type_data: &Declarations, type_: &Type) -> Cow<'static, str> {
+    match &type_.kind {
+        TypeKind::SimpleType(simple_type) => simple_type_default(type_data, simple_type),
+        TypeKind::String => Cow::Borrowed("\"\""),
+        TypeKind::Vector(inner_type) => match &inner_type.kind {
+            TypeKind::SimpleType(SimpleType::Integer(IntegerType::U8)) => Cow::Borrowed("bytes()"),
+            _ => Cow::Borrowed("[]"),
+        },
+        TypeKind::Table(idx) => Cow::Owned(format!("{}()", declaration_name(type_data, idx.0))),
+        TypeKind::Union(idx) => {
+            let (_, info) = type_data.declarations.get_index(idx.0).unwrap();
+            let DeclarationKind::Union(union_info) = &info.kind else {
+                unreachable!()
+            };
+
+            let mut keys: Vec<_> = union_info.variants.keys().collect();
+            keys.sort_unstable();
+
+            Cow::Owned(format!("{}()", keys[0]))
+        }
+        _ => unimplemented!(),
+    }
+}
+
+fn doc_literal(docstrings: &Docstrings) -> String {
+    if docstrings.docstrings.is_empty() {
+        return String::from("None");
+    }
+
+    let doc = docstrings
+        .docstrings
+        .iter()
+        .map(|line| line.value.trim())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("Some({doc:?})")
+}
+
+fn write_field(
+    file: &mut Vec<Cow<str>>,
+    name: &str,
+    field_type: FieldType,
+    default: &str,
+    optional: bool,
+    docstrings: &Docstrings,
+) {
+    let FieldType {
+        hint,
+        base,
+        is_list,
+    } = field_type;
+
+    let hint = if optional {
+        Cow::Owned(format!("{hint} | None"))
+    } else {
+        hint
+    };
+
+    write_str!(file, "            FieldSchema {");
+    write_fmt!(file, "                name: {name:?},");
+    write_fmt!(file, "                type_hint: {hint:?},");
+    write_fmt!(file, "                base_type: {base:?},");
+    write_fmt!(file, "                default: {default:?},");
+    write_fmt!(file, "                optional: {optional},");
+    write_fmt!(file, "                is_list: {is_list},");
+    write_fmt!(file, "                doc: {},", doc_literal(docstrings));
+    write_str!(file, "            },");
+}
+
+/// Generates a static table of every type in the schema,
+/// which is exposed at runtime through `rlbot_flatbuffers.schema`
+pub fn generator(type_data: &Declarations) -> String {
+    let mut sorted_types: Vec<_> = type_data
+        .iter_declarations()
+        .filter(|(_, full_type_name, item)| {
+            // Float is always inlined as a float, so it's not a type on the Python side
+            full_type_name.0.last().unwrap() != "Float"
+                && !matches!(item.kind, DeclarationKind::RpcService(_))
+        })
+        .collect();
+    sorted_types.sort_by_key(|(_, full_type_name, _)| full_type_name.0.last().unwrap());
+
+    let mut file = vec![
+        Cow::Borrowed("//! The schema of every generated type, sorted by name."),
+        Cow::Borrowed("//! This file is generated by `codegen/schema.rs`."),
+        Cow::Borrowed(""),
+        Cow::Borrowed("use super::{FieldSchema, SchemaKind, TypeSchema, VariantSchema};"),
+        Cow::Borrowed(""),
+    ];
+
+    write_fmt!(
+        file,
+        "pub static TYPES: [TypeSchema; {}] = [",
+        sorted_types.len()
+    );
+
+    for (_, full_type_name, item) in sorted_types {
+        let type_name = full_type_name.0.last().unwrap();
+
+        let kind = match &item.kind {
+            DeclarationKind::Table(_) => "Table",
+            DeclarationKind::Struct(_) => "Struct",
+            DeclarationKind::Enum(_) => "Enum",
+            DeclarationKind::Union(_) => "Union",
+            DeclarationKind::RpcService(_) => unreachable!(),
+        };
+
+        write_str!(file, "    TypeSchema {");
+        write_fmt!(file, "        name: {type_name:?},");
+        write_fmt!(file, "        kind: SchemaKind::{kind},");
+        write_fmt!(file, "        doc: {},", doc_literal(&item.docstrings));
+
+        write_str!(file, "        fields: &[");
+        match &item.kind {
+            DeclarationKind::Table(info) => {
+                for (field_name, field_info) in &info.fields {
+                    let optional = matches!(field_info.assign_mode, AssignMode::Optional);
+                    let default = if optional {
+                        Cow::Borrowed("None")
+                    } else {
+                        field_default(type_data, &field_info.type_)
+                    };
+
+                    write_field(
+                        &mut file,
+                        field_name,
+                        field_type(type_data, &field_info.type_),
+                        &default,
+                        optional,
+                        &field_info.docstrings,
+                    );
+                }
+            }
+            DeclarationKind::Struct(info) => {
+                for (field_name, field_info) in &info.fields {
+                    let default = DEFAULT_OVERRIDES
+                        .iter()
+                        .find(|(struct_name, field, _)| {
+                            struct_name == type_name && field == field_name
+                        })
+                        .map(|(_, _, value)| Cow::Borrowed(*value))
+                        .unwrap_or_else(|| simple_type_default(type_data, &field_info.type_));
+
+                    let base = simple_type_name(type_data, &field_info.type_);
+                    let field_type = FieldType {
+                        hint: Cow::Borrowed(base),
+                        base,
+                        is_list: false,
+                    };
+
+                    write_field(
+                        &mut file,
+                        field_name,
+                        field_type,
+                        &default,
+                        false,
+                        &field_info.docstrings,
+                    );
+                }
+            }
+            _ => {}
+        }
+        write_str!(file, "        ],");
+
+        write_str!(file, "        variants: &[");
+        match &item.kind {
+            DeclarationKind::Enum(info) => {
+                for (var_val, var_info) in &info.variants {
+                    write_str!(file, "            VariantSchema {");
+                    write_fmt!(
+                        file,
+                        "                name: {:?},",
+                        normalize_caps(&var_info.name)
+                    );
+                    write_fmt!(file, "                value: {var_val},");
+                    write_fmt!(
+                        file,
+                        "                doc: {},",
+                        doc_literal(&var_info.docstrings)
+                    );
+                    write_str!(file, "            },");
+                }
+            }
+            DeclarationKind::Union(info) => {
+                // the union's type tag starts at 1, 0 is reserved for NONE
+                for (i, (var_name, var_info)) in info.variants.iter().enumerate() {
+                    write_str!(file, "            VariantSchema {");
+                    write_fmt!(file, "                name: {var_name:?},");
+                    write_fmt!(file, "                value: {},", i + 1);
+                    write_fmt!(
+                        file,
+                        "                doc: {},",
+                        doc_literal(&var_info.docstrings)
+                    );
+                    write_str!(file, "            },");
+                }
+            }
+            _ => {}
+        }
+        write_str!(file, "        ],");
+
+        write_str!(file, "    },");
+    }
+
+    write_str!(file, "];");
+    write_str!(file, "");
+
+    file.join("\n")
+}
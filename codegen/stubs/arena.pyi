@@ -0,0 +1,41 @@
+"""
+Constants of the standard soccar arena, in unreal units.
+"""
+
+from __future__ import annotations
+
+from . import BoxShape
+
+SIDE_WALL_X: float
+"""
+The distance from the center of the field to the side walls
+"""
+BACK_WALL_Y: float
+"""
+The distance from the center of the field to the back walls, where the goals are
+"""
+CEILING_Z: float
+GOAL_WIDTH: float
+GOAL_HEIGHT: float
+GOAL_DEPTH: float
+"""
+How far the goals extend past the back walls
+"""
+BALL_RADIUS: float
+BIG_PAD_RESPAWN_TIME: float
+"""
+The seconds it takes for a big boost pad to become active again
+"""
+SMALL_PAD_RESPAWN_TIME: float
+"""
+The seconds it takes for a small boost pad to become active again
+"""
+BIG_PAD_BOOST: float
+SMALL_PAD_BOOST: float
+
+OCTANE_HITBOX: BoxShape
+DOMINUS_HITBOX: BoxShape
+PLANK_HITBOX: BoxShape
+BREAKOUT_HITBOX: BoxShape
+HYBRID_HITBOX: BoxShape
+MERC_HITBOX: BoxShape
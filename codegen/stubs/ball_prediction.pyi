@@ -0,0 +1,107 @@
+    def to_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every slice as an N×K float32 array (a `memoryview`),
+        which `numpy.asarray` can wrap without copying.
+        By default, `game_seconds` and then the 12 numbers of `physics` are exported.
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    @staticmethod
+    def from_array(
+        times: Any,
+        locations: Any,
+        velocities: Any | None = None,
+        rotations: Any | None = None,
+        angular_velocities: Any | None = None,
+    ) -> BallPrediction:
+        """
+        Creates a ball prediction from a 1-dimensional array of `times`
+        and N×3 arrays of the physics at each of them, all float32s or float64s
+        (anything that implements the buffer protocol, like a numpy array).
+        `rotations` are in pitch, yaw and roll, and any array that isn't given is all zeros.
+        This still creates a `PredictionSlice` for every row, so `pack_array` is faster.
+
+        :raises TypeError: If an array isn't an array of float32s or float64s
+        :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+        """
+    @staticmethod
+    def pack_array(
+        times: Any,
+        locations: Any,
+        velocities: Any | None = None,
+        rotations: Any | None = None,
+        angular_velocities: Any | None = None,
+    ) -> bytes:
+        """
+        Packs a ball prediction from the same arrays as `from_array`,
+        which are read straight into the flat representation without creating a `PredictionSlice` per row.
+        The bytes are the same as `BallPrediction.from_array(...).pack()`.
+
+        :raises TypeError: If an array isn't an array of float32s or float64s
+        :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+        """
+    def state_at(self, game_seconds: float) -> PredictionSlice | None:
+        """
+        The predicted state of the ball at `game_seconds`, interpolated between the two closest slices,
+        or `None` if `game_seconds` isn't within the prediction
+        """
+    def time_range(
+        self, start_seconds: float | None = None, stop_seconds: float | None = None
+    ) -> range:
+        """
+        The indices of the slices from `start_seconds` up to (but not including) `stop_seconds`,
+        found with a binary search
+        """
+    def find_first(
+        self,
+        *,
+        min_height: float | None = None,
+        max_height: float | None = None,
+        region: tuple[Vector3, Vector3] | None = None,
+        goal: GoalInfo | None = None,
+        start_seconds: float | None = None,
+        stop_seconds: float | None = None,
+    ) -> int | None:
+        """
+        The index of the first slice where the ball meets all of the given conditions,
+        or `None` if there isn't one.
+        The ball's center must be between `min_height` and `max_height`,
+        inside the box between the two corners of `region`,
+        and entirely inside `goal` (past the goal line, between the posts and under the crossbar).
+        The search can be limited to the slices from `start_seconds` up to (but not including) `stop_seconds`
+        """
+    def find_all(
+        self,
+        *,
+        min_height: float | None = None,
+        max_height: float | None = None,
+        region: tuple[Vector3, Vector3] | None = None,
+        goal: GoalInfo | None = None,
+        start_seconds: float | None = None,
+        stop_seconds: float | None = None,
+    ) -> list[int]:
+        """
+        The indices of every slice where the ball meets all of the given conditions,
+        which are the same as in `find_first`
+        """
+    @staticmethod
+    def simulate(
+        ball: BallInfo | Physics,
+        gravity: float = -650.0,
+        game_seconds: float = 0.0,
+        seconds: float = 6.0,
+    ) -> BallPrediction:
+        """
+        Simulates a prediction of `ball` at 120Hz for `seconds`,
+        without collisions with cars, for testing without RLBot's core.
+        The first slice is at `game_seconds`, and `gravity` is usually `MatchInfo.world_gravity_z`.
+        The ball bounces off the floor, walls and ceiling of the standard soccar arena (without its rounded corners),
+        and its radius is that of its `SphereShape` or `CylinderShape`, or the standard ball's otherwise
+
+        :raises TypeError: If `ball` isn't a `BallInfo` or `Physics`
+        :raises ValueError: If `seconds` isn't finite or is over 60
+        """
+    def mirrored(self) -> BallPrediction:
+        """
+        A copy of the prediction from the other team's perspective, like `GamePacket.mirrored`
+        """
@@ -0,0 +1,17 @@
+class Packable(ABC):
+    """
+    Base class of everything that can be packed into a flatbuffer with `pack()`:
+    the generated tables and structs, and their builders.
+    """
+
+    def pack(self) -> bytes: ...
+
+class Table(Packable):
+    """
+    Base class of all generated flatbuffer tables.
+    """
+
+class Struct(Packable):
+    """
+    Base class of all generated flatbuffer structs.
+    """
@@ -0,0 +1,67 @@
+"""
+Tracks the boost pads of the arena across game packets.
+"""
+
+from __future__ import annotations
+
+from . import FieldInfo, GamePacket, Vector3
+
+class TrackedBoostPad:
+    """
+    A boost pad, with the state from the latest packet
+    """
+
+    index: int
+    """
+    The index of the pad in `FieldInfo.boost_pads` and `GamePacket.boost_pads`
+    """
+    location: Vector3
+    is_full_boost: bool
+    is_active: bool
+    timer: float
+    """
+    The seconds since the pad was picked up, or 0 if it's active
+    """
+    time_until_active: float
+    """
+    The estimated seconds until the pad is active, or 0 if it's active
+    """
+
+class BoostPadTracker:
+    """
+    Joins the boost pads of `FieldInfo` with their state in each `GamePacket`
+    """
+
+    pads: list[TrackedBoostPad]
+
+    def __init__(self, field_info: FieldInfo | None = None) -> None:
+        """
+        Uses the boost pads of `field_info`, or of the standard soccar arena if it's not given
+        """
+    def update(self, packet: GamePacket) -> None:
+        """
+        Updates the state of every pad from `packet`
+
+        :raises ValueError: If the packet doesn't have the same number of boost pads as the tracker
+        """
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> TrackedBoostPad: ...
+    def nearest(
+        self,
+        location: Vector3,
+        is_full_boost: bool | None = None,
+        within: float = 0.0,
+    ) -> TrackedBoostPad | None:
+        """
+        The closest pad to `location` that's active, or will be within `within` seconds.
+        `is_full_boost` limits the search to only big or small pads
+        """
+    def available_within(
+        self,
+        seconds: float = 0.0,
+        is_full_boost: bool | None = None,
+    ) -> list[TrackedBoostPad]:
+        """
+        The pads that are active, or will be within `seconds`.
+        `is_full_boost` limits the result to only big or small pads
+        """
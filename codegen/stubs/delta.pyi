@@ -0,0 +1,53 @@
+"""
+Delta compression of consecutive game packets.
+"""
+
+from __future__ import annotations
+
+from . import GamePacket
+
+class GamePacketEncoder:
+    """
+    Encodes packed `GamePacket`s as keyframes and deltas against the previous packet
+    """
+
+    keyframe_interval: int
+
+    def __init__(self, keyframe_interval: int = 120) -> None:
+        """
+        A keyframe is written at least every `keyframe_interval` frames,
+        so decoding can start from any of them
+
+        :raises ValueError: If `keyframe_interval` is 0
+        """
+    def encode(self, packet: GamePacket) -> bytes:
+        """
+        Encodes `packet` as a keyframe, or as a delta against the previous packet
+        """
+    def reset(self) -> None:
+        """
+        Forgets the previous packet, so the next frame is a keyframe
+        """
+
+class GamePacketDecoder:
+    """
+    Decodes the frames of a `GamePacketEncoder`, in the same order they were encoded
+    """
+
+    def __init__(self) -> None: ...
+    def decode(self, data: bytes) -> GamePacket:
+        """
+        Decodes a frame into the `GamePacket` that was encoded
+
+        :raises ValueError: If the frame is a corrupted delta, or doesn't follow the frame it was encoded against
+        :raises InvalidFlatbuffer: If the frame doesn't contain a valid `GamePacket`
+        """
+    def reset(self) -> None:
+        """
+        Forgets the previous packet, so the next frame must be a keyframe
+        """
+
+def is_keyframe(data: bytes) -> bool:
+    """
+    Whether `data` is a keyframe, which can be decoded without the frames before it
+    """
@@ -0,0 +1,48 @@
+    @staticmethod
+    def from_packet(
+        packet: GamePacket,
+        include: (
+            Literal["balls", "cars", "boost", "match_info"]
+            | Iterable[Literal["balls", "cars", "boost", "match_info"]]
+            | None
+        ) = None,
+    ) -> DesiredGameState:
+        """
+        Creates a desired game state that restores everything in `packet`.
+        `include` limits it to some of `"balls"`, `"cars"`, `"boost"` and `"match_info"`
+
+        :raises ValueError: If `include` contains an unknown option
+        """
+    @staticmethod
+    def diff(
+        current: GamePacket,
+        target: GamePacket | DesiredGameState,
+        tolerance: float = 0.01,
+    ) -> DesiredGameState:
+        """
+        Creates the smallest desired game state that turns `current` into `target`.
+        Only values that differ by more than `tolerance` are set,
+        and balls and cars that don't need to change are left empty.
+        `tolerance` is intentionally shared by every value regardless of its unit,
+        since it's meant to skip float rounding rather than small but real changes
+
+        :raises TypeError: If `target` isn't a `GamePacket` or `DesiredGameState`
+        """
+    @staticmethod
+    def merge(
+        *states: DesiredGameState,
+        conflict: Literal["last", "error"] = "last",
+    ) -> DesiredGameState:
+        """
+        Combines `states` into one, where later states take priority.
+        Balls and cars are merged by index, and console commands are concatenated.
+        With `conflict="error"`, setting a value to two different values raises a `ValueError`
+
+        :raises ValueError: If `conflict` is `"error"` and two states conflict
+        """
+    def mirrored(self) -> DesiredGameState:
+        """
+        A copy of the state from the other team's perspective, like `GamePacket.mirrored`.
+        Mirroring is its own inverse up to the float32 rounding of yaws, so this also turns a state made in the mirrored frame back into the real one.
+        Only the values that are set are changed
+        """
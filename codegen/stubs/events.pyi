@@ -0,0 +1,154 @@
+"""
+Detects game events by comparing consecutive game packets.
+"""
+
+from __future__ import annotations
+
+from . import GamePacket, MatchPhase, Touch, Vector2
+
+class Event:
+    """
+    The base class of every event
+    """
+
+    frame: int
+    """
+    `MatchInfo.frame_num` of the packet the event was detected in
+    """
+    seconds_elapsed: float
+    """
+    `MatchInfo.seconds_elapsed` of the packet the event was detected in
+    """
+
+class GoalScored(Event):
+    """
+    A team scored a goal
+    """
+
+    team: int
+    """
+    The team that was awarded the goal
+    """
+    player_index: int | None
+    """
+    The index in `GamePacket.players` of the player that scored, if known
+    """
+    player_id: int | None
+    is_own_goal: bool
+    """
+    If the player scored into their own goal
+    """
+
+    __match_args__ = ("team", "player_index", "player_id", "is_own_goal")
+
+class Demolition(Event):
+    """
+    A player was demolished
+    """
+
+    victim_index: int
+    victim_id: int
+    attacker_index: int | None
+    """
+    The index in `GamePacket.players` of the player that caused the demolition, if known
+    """
+    attacker_id: int | None
+
+    __match_args__ = ("victim_index", "victim_id", "attacker_index", "attacker_id")
+
+class BallTouch(Event):
+    """
+    A player touched a ball
+    """
+
+    player_index: int
+    player_id: int
+    touch: Touch
+
+    __match_args__ = ("player_index", "player_id", "touch")
+
+class Jump(Event):
+    """
+    A player jumped or double jumped
+    """
+
+    player_index: int
+    player_id: int
+    is_double_jump: bool
+
+    __match_args__ = ("player_index", "player_id", "is_double_jump")
+
+class Dodge(Event):
+    """
+    A player dodged
+    """
+
+    player_index: int
+    player_id: int
+    direction: Vector2
+
+    __match_args__ = ("player_index", "player_id", "direction")
+
+class PhaseChanged(Event):
+    """
+    `MatchInfo.match_phase` changed, e.g. to `MatchPhase.Kickoff`
+    """
+
+    previous: MatchPhase
+    phase: MatchPhase
+
+    __match_args__ = ("previous", "phase")
+
+class PlayerJoined(Event):
+    """
+    A player was added to `GamePacket.players`
+    """
+
+    player_index: int
+    player_id: int
+    name: str
+    team: int
+
+    __match_args__ = ("player_index", "player_id", "name", "team")
+
+class PlayerLeft(Event):
+    """
+    A player was removed from `GamePacket.players`
+    """
+
+    player_index: int
+    """
+    The index the player had in the previous packet
+    """
+    player_id: int
+    name: str
+    team: int
+
+    __match_args__ = ("player_index", "player_id", "name", "team")
+
+class EventDetector:
+    """
+    Detects events by comparing each `GamePacket` to the previous one.
+    The first packet is only used as the starting point, so it never has any events
+    """
+
+    def __init__(self) -> None: ...
+    def update(
+        self, packet: GamePacket
+    ) -> list[
+        GoalScored
+        | Demolition
+        | BallTouch
+        | Jump
+        | Dodge
+        | PhaseChanged
+        | PlayerJoined
+        | PlayerLeft
+    ]:
+        """
+        Returns the events that happened since the previous packet
+        """
+    def reset(self) -> None:
+        """
+        Forgets the previous packet, so the next packet is used as a new starting point
+        """
@@ -0,0 +1,11 @@
+    @staticmethod
+    def standard_soccar() -> FieldInfo:
+        """
+        The field info of the standard soccar arena, like DFH Stadium,
+        with its 34 boost pads and the two goals
+        """
+    def mirrored(self) -> FieldInfo:
+        """
+        A copy of the field info from the other team's perspective, like `GamePacket.mirrored`.
+        The boost pads are in reverse order, so they stay in the same order as the mirrored `GamePacket.boost_pads`
+        """
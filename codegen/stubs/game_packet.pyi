@@ -0,0 +1,24 @@
+    def player_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every player as an N×K float32 array (a `memoryview`),
+        which `numpy.asarray` can wrap without copying.
+        Fields are paths in `PlayerInfo` like `"boost"` or `"physics.location"`,
+        where structs are expanded into their numbers (e.g. x, y and z).
+        By default, the 12 numbers of `physics` are exported.
+        Booleans become 0 or 1, and fields of a `latest_touch` that isn't set become NaN.
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    def ball_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every ball as an N×K float32 array (a `memoryview`),
+        like `player_array` does for the players
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    def mirrored(self) -> GamePacket:
+        """
+        A copy of the packet from the other team's perspective, with the field rotated 180° around the z axis.
+        Locations, velocities and angular velocities have their x and y negated,
+        yaws are turned around, and the boost pads are in reverse order
+        """
@@ -0,0 +1,10 @@
+    def to_local(self, point: Vector3) -> Vector3:
+        """
+        Converts a point in world coordinates into coordinates relative to this object,
+        where +x is forward, +y is right and +z is up
+        """
+    def to_world(self, local: Vector3) -> Vector3:
+        """
+        Converts a point relative to this object into world coordinates,
+        where +x is forward, +y is right and +z is up
+        """
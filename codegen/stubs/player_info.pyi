@@ -0,0 +1,30 @@
+    def can_jump(self) -> bool:
+        """
+        Whether the car can jump, which is when it's on the ground and not demolished.
+        A `demolished_timeout` of 0 or more counts as demolished, so a `PlayerInfo()` with the default of 0 can't jump
+        """
+    def can_dodge(self) -> bool:
+        """
+        Whether the car can dodge or double jump,
+        which is until `dodge_timeout` runs out after the first jump, or until landing with a flip reset.
+        Like `can_jump`, it's false while `demolished_timeout` is 0 or more
+        """
+    def dodge_time_remaining(self) -> float:
+        """
+        The seconds left to dodge or double jump, 0 if the car can't,
+        or infinity if it's holding a flip reset
+        """
+    def hitbox_corners(self) -> list[Vector3]:
+        """
+        The 8 corners of the hitbox in world coordinates,
+        with the front corners first, then the right corners before the left, then the top corners before the bottom
+        """
+    def closest_point(self, ball: BallInfo) -> Vector3:
+        """
+        The closest point of the hitbox to the ball's `CollisionShape`,
+        which is inside the ball if they overlap
+        """
+    def distance_to(self, ball: BallInfo) -> float:
+        """
+        The distance between the hitbox and the ball's `CollisionShape`, or 0 if they overlap
+        """
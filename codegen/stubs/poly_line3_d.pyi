@@ -0,0 +1,20 @@
+    @staticmethod
+    def from_points(points: Any, color: Color | None = None) -> PolyLine3D:
+        """
+        Creates a poly line from an N×3 array (or a flat array) of float32s or float64s
+        (anything that implements the buffer protocol, like a numpy array).
+        This still creates a `Vector3` for every point, so `pack_points` is faster for long lines.
+
+        :raises TypeError: If `points` isn't an array of float32s or float64s
+        :raises ValueError: If `points` doesn't have 3 numbers per row
+        """
+    @staticmethod
+    def pack_points(points: Any, color: Color | None = None) -> bytes:
+        """
+        Packs a poly line from an N×3 array (or a flat array) of float32s or float64s,
+        which is read straight into the flat representation without creating a `Vector3` per point.
+        The bytes are the same as `PolyLine3D.from_points(points, color).pack()`.
+
+        :raises TypeError: If `points` isn't an array of float32s or float64s
+        :raises ValueError: If `points` doesn't have 3 numbers per row
+        """
@@ -0,0 +1,23 @@
+    def forward(self) -> Vector3:
+        """
+        The direction the nose of the object is pointing
+        """
+    def right(self) -> Vector3: ...
+    def up(self) -> Vector3: ...
+    def to_matrix(self) -> tuple[Vector3, Vector3, Vector3]:
+        """
+        Returns the forward, right and up vectors,
+        which are the columns of the matrix that rotates local coordinates into world coordinates
+        """
+    def to_quaternion(self) -> tuple[float, float, float, float]:
+        """
+        Returns the rotation as a unit quaternion in `(w, x, y, z)` order
+        """
+    @staticmethod
+    def from_quaternion(w: float, x: float, y: float, z: float) -> Rotator:
+        """
+        Creates a rotator from a quaternion in `(w, x, y, z)` order,
+        which doesn't need to be normalized
+
+        :raises ValueError: If the quaternion doesn't have a finite, non-zero length
+        """
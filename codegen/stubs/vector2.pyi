@@ -0,0 +1,23 @@
+    def __add__(self, other: Vector2) -> Vector2: ...
+    def __sub__(self, other: Vector2) -> Vector2: ...
+    def __mul__(self, scalar: float) -> Vector2: ...
+    def __rmul__(self, scalar: float) -> Vector2: ...
+    def __truediv__(self, scalar: float) -> Vector2: ...
+    def __neg__(self) -> Vector2: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float: ...
+    def __iter__(self) -> Iterator[float]: ...
+    def dot(self, other: Vector2) -> float: ...
+    def length(self) -> float: ...
+    def normalized(self) -> Vector2:
+        """
+        Returns a unit vector in the same direction,
+        or a zero vector if this vector has no length
+        """
+    def distance(self, other: Vector2) -> float: ...
+    def lerp(self, other: Vector2, t: float) -> Vector2: ...
+    def angle_to(self, other: Vector2) -> float:
+        """
+        The angle between the two vectors in radians,
+        or 0 if either vector has no length
+        """
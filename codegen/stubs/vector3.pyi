@@ -0,0 +1,24 @@
+    def __add__(self, other: Vector3) -> Vector3: ...
+    def __sub__(self, other: Vector3) -> Vector3: ...
+    def __mul__(self, scalar: float) -> Vector3: ...
+    def __rmul__(self, scalar: float) -> Vector3: ...
+    def __truediv__(self, scalar: float) -> Vector3: ...
+    def __neg__(self) -> Vector3: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float: ...
+    def __iter__(self) -> Iterator[float]: ...
+    def dot(self, other: Vector3) -> float: ...
+    def cross(self, other: Vector3) -> Vector3: ...
+    def length(self) -> float: ...
+    def normalized(self) -> Vector3:
+        """
+        Returns a unit vector in the same direction,
+        or a zero vector if this vector has no length
+        """
+    def distance(self, other: Vector3) -> float: ...
+    def lerp(self, other: Vector3, t: float) -> Vector3: ...
+    def angle_to(self, other: Vector3) -> float:
+        """
+        The angle between the two vectors in radians,
+        or 0 if either vector has no length
+        """
@@ -0,0 +1,7 @@
+    def __mul__(self, scalar: float) -> Vector3Partial: ...
+    def __rmul__(self, scalar: float) -> Vector3Partial: ...
+    def __truediv__(self, scalar: float) -> Vector3Partial: ...
+    def __neg__(self) -> Vector3Partial: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float | None: ...
+    def __iter__(self) -> Iterator[float | None]: ...
@@ -115,6 +115,13 @@ impl<'a> TableBindGenerator<'a> {
         }
 
         write_str!(self, "}\n");
+    }
+
+    fn generate_py_default(&mut self) {
+        if self.fields.is_empty() {
+            return;
+        }
+
         write_fmt!(self, "impl crate::PyDefault for {} {{", self.name);
         write_str!(self, "    fn py_default(py: Python) -> Py<Self> {");
         write_str!(self, "        Py::new(py, Self {");
@@ -884,6 +891,180 @@ impl<'a> TableBindGenerator<'a> {
         write_str!(self, "    }");
     }
 
+    fn generate_replace_method(&mut self) {
+        if self.fields.is_empty() {
+            return;
+        }
+
+        write_str!(self, "    #[pyo3(signature = (**changes))]");
+        write_str!(self, "    fn replace<'py>(");
+        write_str!(self, "        slf: &Bound<'py, Self>,");
+        write_str!(self, "        changes: Option<&Bound<'py, PyDict>>,");
+        write_str!(self, "    ) -> PyResult<Bound<'py, PyAny>> {");
+
+        let field_names = self
+            .fields
+            .keys()
+            .map(|field_name| format!("\"{field_name}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write_fmt!(
+            self,
+            "        crate::path::replace(slf.as_any(), &[{field_names}], changes)"
+        );
+        write_str!(self, "    }");
+    }
+
+    fn has_builder(&self) -> bool {
+        self.is_frozen && !self.fields.is_empty()
+    }
+
+    pub fn class_names(&self) -> Vec<String> {
+        let mut class_names = vec![self.name.to_string()];
+
+        if self.has_builder() {
+            class_names.push(format!("{}Builder", self.name));
+        }
+
+        class_names
+    }
+
+    /// Rust expression that copies a field from `self` into a new instance,
+    /// lists are shallow-copied so that the frozen type and its builder never share one
+    fn copy_field_expr(field_name: &str, field_info: &TableField) -> String {
+        let copy = |var: &str| match &field_info.type_.kind {
+            TypeKind::SimpleType(
+                SimpleType::Bool | SimpleType::Integer(_) | SimpleType::Enum(_),
+            ) => var.to_string(),
+            TypeKind::Vector(inner_type)
+                if !matches!(
+                    inner_type.kind,
+                    TypeKind::SimpleType(SimpleType::Integer(IntegerType::U8))
+                ) =>
+            {
+                format!("PyList::new(py, {var}.bind_borrowed(py).iter()).unwrap().unbind()")
+            }
+            _ => format!("{var}.clone_ref(py)"),
+        };
+
+        let is_scalar = matches!(
+            &field_info.type_.kind,
+            TypeKind::SimpleType(SimpleType::Bool | SimpleType::Integer(_) | SimpleType::Enum(_))
+        );
+
+        if matches!(field_info.assign_mode, AssignMode::Optional) && !is_scalar {
+            format!("self.{field_name}.as_ref().map(|x| {})", copy("x"))
+        } else {
+            copy(&format!("self.{field_name}"))
+        }
+    }
+
+    fn generate_to_builder_method(&mut self) {
+        write_str!(self, "    #[allow(unused_variables)]");
+        write_fmt!(
+            self,
+            "    fn to_builder(&self, py: Python) -> {}Builder {{",
+            self.name
+        );
+        write_fmt!(self, "        {}Builder {{", self.name);
+
+        for (field_name, field_info) in self.fields {
+            let copy = Self::copy_field_expr(field_name, field_info);
+            write_fmt!(self, "            {field_name}: {copy},");
+        }
+
+        write_str!(self, "        }");
+        write_str!(self, "    }");
+    }
+
+    fn generate_builder_methods(&mut self, frozen_name: &str) {
+        write_fmt!(self, "impl {} {{", self.name);
+        write_str!(self, "    #[allow(unused_variables)]");
+        write_fmt!(
+            self,
+            "    fn to_frozen(&self, py: Python) -> {frozen_name} {{"
+        );
+        write_fmt!(self, "        {frozen_name} {{");
+
+        for (field_name, field_info) in self.fields {
+            let copy = Self::copy_field_expr(field_name, field_info);
+            write_fmt!(self, "            {field_name}: {copy},");
+        }
+
+        write_str!(self, "        }");
+        write_str!(self, "    }");
+        write_str!(self, "}");
+        write_str!(self, "");
+
+        write_str!(self, "#[pymethods]");
+        write_fmt!(self, "impl {} {{", self.name);
+
+        self.generate_new_method();
+        write_str!(self, "");
+
+        self.generate_str_method();
+        write_str!(self, "");
+
+        write_str!(self, "    pub fn __repr__(&self, py: Python) -> String {");
+        write_str!(self, "        let repr = self.to_frozen(py).__repr__(py);");
+        write_fmt!(
+            self,
+            "        format!(\"{}{{}}\", &repr[{}..])",
+            self.name,
+            frozen_name.len()
+        );
+        write_str!(self, "    }");
+        write_str!(self, "");
+
+        self.generate_args();
+
+        write_fmt!(
+            self,
+            "    fn build(&self, py: Python) -> Py<{frozen_name}> {{"
+        );
+        write_str!(self, "        Py::new(py, self.to_frozen(py)).unwrap()");
+        write_str!(self, "    }");
+        write_str!(self, "");
+
+        write_str!(
+            self,
+            "    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {"
+        );
+        write_str!(
+            self,
+            "        let mut builder = Builder::with_capacity(u16::MAX as usize);\n"
+        );
+        write_fmt!(
+            self,
+            "        let flat_t = flat::{frozen_name}::from_gil(py, &self.to_frozen(py));"
+        );
+        write_str!(
+            self,
+            "        PyBytes::new(py, builder.finish(flat_t, None))"
+        );
+        write_str!(self, "    }");
+        write_str!(self, "}");
+        write_str!(self, "");
+    }
+
+    /// Mutable companion of a frozen type, which has the same fields
+    /// but can be assembled step by step before calling `build()`
+    fn generate_builder(&mut self) {
+        let builder_name = format!("{}Builder", self.name);
+        let mut builder_gen = TableBindGenerator {
+            name: &builder_name,
+            fields: self.fields,
+            all_items: self.all_items,
+            file_contents: Vec::new(),
+            is_frozen: false,
+        };
+
+        builder_gen.generate_definition();
+        builder_gen.generate_builder_methods(self.name);
+
+        self.file_contents.extend(builder_gen.file_contents);
+    }
+
     fn generate_py_methods(&mut self) {
         write_str!(self, "#[pymethods]");
         write_fmt!(self, "impl {} {{", self.name);
@@ -903,6 +1084,18 @@ impl<'a> TableBindGenerator<'a> {
         write_str!(self, "");
 
         self.generate_unpack_method();
+
+        if !self.fields.is_empty() {
+            write_str!(self, "");
+        }
+
+        self.generate_replace_method();
+
+        if self.has_builder() {
+            write_str!(self, "");
+            self.generate_to_builder_method();
+        }
+
         write_str!(self, "}");
         write_str!(self, "");
     }
@@ -920,10 +1113,15 @@ impl<'a> TableBindGenerator<'a> {
         write_str!(self, "");
 
         self.generate_definition();
+        self.generate_py_default();
         self.generate_from_flat_impls();
         self.generate_to_flat_impls();
         self.generate_py_methods();
 
+        if self.has_builder() {
+            self.generate_builder();
+        }
+
         self.file_contents
     }
 }
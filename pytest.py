@@ -1,9 +1,26 @@
+import array
+import ctypes
+import math
+import os
+import tempfile
+from enum import IntEnum
 from random import randrange
 from time import time_ns
 
 import numpy as np
 
 from rlbot_flatbuffers import *
+from rlbot_flatbuffers import (
+    arena,
+    arrow,
+    boost,
+    delta,
+    events,
+    intercept,
+    path,
+    recording,
+    schema,
+)
 
 
 class MyVector(Vector3):
@@ -53,6 +70,84 @@ if __name__ == "__main__":
     assert vec3.y == 7
     assert vec3.z == 9
 
+    vec4 = vec2 - Vector3(1, 1, 1) * 2
+    assert (vec4.x, vec4.y, vec4.z) == (2, 3, 4)
+    assert vec2.dot(Vector3(1, 0, 0)) == 4
+    assert Vector3(1, 0, 0).cross(Vector3(0, 1, 0)).z == 1
+    assert Vector3(3, 4, 0).length() == 5
+    assert abs(Vector3(0, 0, 2).normalized().z - 1) < 1e-9
+    assert abs(Vector2(1, 0).angle_to(Vector2(0, 1)) - 1.5707963) < 1e-6
+    x, y, z = -vec2 / 2
+    assert (x, y, z) == (-2, -2.5, -3)
+    assert list(Vector3Partial(1, None, 3) * 2) == [2, None, 6]
+
+    rotator = Rotator(0.3, 1.2, -0.5)
+    forward, right, up = rotator.to_matrix()
+    assert abs(forward.cross(right).distance(up)) < 1e-9
+    assert Rotator(yaw=1.5707963267948966).right().distance(Vector3(-1, 0, 0)) < 1e-9
+    same_rotator = Rotator.from_quaternion(*rotator.to_quaternion())
+    assert abs(same_rotator.pitch - rotator.pitch) < 1e-9
+    assert abs(same_rotator.roll - rotator.roll) < 1e-9
+    straight_up = Rotator(math.pi / 2, 1, 0.5)
+    locked = Rotator.from_quaternion(*straight_up.to_quaternion())
+    assert locked.roll == 0 and abs(locked.pitch - math.pi / 2) < 1e-6
+    for a, b in zip(locked.to_matrix(), straight_up.to_matrix()):
+        assert a.distance(b) < 1e-6
+
+    car_physics = Physics(Vector3(100, 0, 17), Rotator(yaw=1.5707963267948966))
+    local = car_physics.to_local(Vector3(100, 50, 17))
+    assert local.distance(Vector3(50, 0, 0)) < 1e-9
+    assert car_physics.to_world(local).distance(Vector3(100, 50, 17)) < 1e-9
+
+    field_info = FieldInfo.standard_soccar()
+    assert len(field_info.boost_pads) == 34
+    assert sum(pad.is_full_boost for pad in field_info.boost_pads) == 6
+    assert field_info.goals[1].location.y == arena.BACK_WALL_Y
+    assert arena.OCTANE_HITBOX.length > arena.OCTANE_HITBOX.width
+    FieldInfo.unpack(field_info.pack())
+
+    tracker = boost.BoostPadTracker(field_info)
+    pad_states = [BoostPadState(True) for _ in range(34)]
+    pad_states[4] = BoostPadState(False, 7)
+    tracker.update(GamePacket(boost_pads=pad_states))
+    assert tracker[4].time_until_active == 3
+    nearest = tracker.nearest(Vector3(3000, -4000, 0), is_full_boost=True)
+    assert nearest.index == 18
+    assert tracker.nearest(Vector3(3000, -4000, 0), True, within=3).index == 4
+    assert len(tracker.available_within(0, is_full_boost=True)) == 5
+
+    detector = events.EventDetector()
+    scorer = PlayerInfo(player_id=7, team=1, demolished_timeout=-1)
+    assert detector.update(GamePacket([scorer], teams=[TeamInfo(0), TeamInfo(1)])) == []
+    goal_packet = GamePacket(
+        [scorer.replace(score_info=ScoreInfo(goals=1))],
+        match_info=MatchInfo(frame_num=9, match_phase=MatchPhase.GoalScored),
+        teams=[TeamInfo(0), TeamInfo(1, 1)],
+    )
+    phase_event, goal_event = detector.update(goal_packet)
+    assert isinstance(phase_event, events.PhaseChanged)
+    match goal_event:
+        case events.GoalScored(team, player_index, player_id, is_own_goal):
+            assert (team, player_index, player_id, is_own_goal) == (1, 0, 7, False)
+            assert goal_event.frame == 9
+        case _:
+            assert False
+    print(goal_event)
+
+    detector = events.EventDetector()
+    teammate = scorer.replace(player_id=8)
+    detector.update(GamePacket([scorer, teammate], teams=[TeamInfo(0), TeamInfo(1)]))
+    two_goals = GamePacket(
+        [
+            scorer.replace(score_info=ScoreInfo(goals=1)),
+            teammate.replace(score_info=ScoreInfo(goals=1)),
+        ],
+        teams=[TeamInfo(0), TeamInfo(1, 2)],
+    )
+    goals = [e for e in detector.update(two_goals) if isinstance(e, events.GoalScored)]
+    assert sorted(goal.player_id for goal in goals) == [7, 8]
+    print()
+
     player_info = PlayerInfo(name="HELLO", accolades=["MVP", "Hat Trick"])
     eval(repr(player_info))
     print()
@@ -82,6 +177,468 @@ if __name__ == "__main__":
     eval(repr(dgs))
     print()
 
+    packet = GamePacket(players=[PlayerInfo(name="HELLO")], match_info=MatchInfo())
+    fast_packet = packet.replace(
+        **{"match_info.game_speed": 2, "match_info.frame_num": 7}
+    )
+    assert fast_packet.match_info.game_speed == 2
+    assert fast_packet.match_info.frame_num == 7
+    assert packet.match_info.game_speed == 0
+    assert fast_packet.players[0].name == "HELLO"
+
+    try:
+        packet.replace(not_a_field=1)
+        assert False
+    except TypeError as e:
+        print(e)
+    print()
+
+    builder = packet.to_builder()
+    builder.players.append(PlayerInfo(name="WORLD"))
+    builder.match_info = MatchInfo(frame_num=3)
+    built_packet = builder.build()
+    assert len(packet.players) == 1
+    assert len(built_packet.players) == 2
+    assert built_packet.match_info.frame_num == 3
+    assert builder.pack() == built_packet.pack()
+    print(repr(builder))
+    print()
+
+    packet_schema = schema.get(packet)
+    assert packet_schema.kind == "table"
+    assert [field.name for field in packet_schema.fields] == list(
+        GamePacket.__match_args__
+    )
+    assert packet_schema.field("players").base_type == "PlayerInfo"
+    assert schema.get("Color").field("a").default == "255"
+    assert schema.get(AirState).variants[0].name == "OnGround"
+    print(schema.get(MyVector))
+    print()
+
+    assert path.get(built_packet, "players[-1].name") == "WORLD"
+    path.set(builder, "players[0]", PlayerInfo(name="BUILT"))
+    assert builder.players[0].name == "BUILT"
+    path.set(dgs, "match_info.game_speed", 1)
+    assert dgs.match_info.game_speed == 1
+
+    try:
+        path.set(packet, "players[0].name", "FROZEN")
+        assert False
+    except TypeError as e:
+        print(e)
+
+    leaves = dict(path.walk(Vector3(1, 2, 3)))
+    assert leaves == {"x": 1, "y": 2, "z": 3}
+    leaves = dict(path.walk(GamePacket(balls=[BallInfo()])))
+    assert leaves["players"] == [] and leaves["balls[0].physics.location.x"] == 0
+    print()
+
+    snapshot_packet = GamePacket(
+        players=[PlayerInfo(physics=Physics(Vector3(1, 2, 3)), boost=42)],
+        balls=[BallInfo(Physics(velocity=Vector3(0, 0, 500)))],
+        match_info=MatchInfo(game_speed=1.5),
+    )
+    snapshot = DesiredGameState.from_packet(snapshot_packet)
+    assert snapshot.car_states[0].physics.location.y == 2
+    assert snapshot.car_states[0].boost_amount == 42
+    assert snapshot.ball_states[0].physics.velocity.z == 500
+    assert snapshot.match_info.game_speed == 1.5
+    DesiredGameState.unpack(snapshot.pack())
+
+    boost_only = DesiredGameState.from_packet(snapshot_packet, include=["boost"])
+    assert boost_only.car_states[0].physics is None
+    assert boost_only.ball_states == [] and boost_only.match_info is None
+
+    moved_packet = snapshot_packet.replace(
+        players=[PlayerInfo(physics=Physics(Vector3(1, 2, 300)), boost=42)]
+    )
+    minimal = DesiredGameState.diff(snapshot_packet, moved_packet)
+    assert minimal.ball_states == [] and minimal.match_info is None
+    car_location = minimal.car_states[0].physics.location
+    assert (car_location.x, car_location.y, car_location.z) == (None, None, 300)
+    assert minimal.car_states[0].boost_amount is None
+    assert DesiredGameState.diff(snapshot_packet, snapshot).car_states == []
+
+    ball_mover = DesiredGameState(
+        [DesiredBallState(DesiredPhysics(location=Vector3Partial(z=93)))],
+        console_commands=[ConsoleCommand("a")],
+    )
+    refueler = DesiredGameState(
+        car_states=[DesiredCarState(boost_amount=100)],
+        console_commands=[ConsoleCommand("b")],
+    )
+    merged = DesiredGameState.merge(ball_mover, refueler, dgs)
+    assert merged.ball_states[0].physics.location.z == 93
+    assert merged.car_states[0].boost_amount == 100
+    assert merged.match_info.game_speed == 1
+    assert [c.command for c in merged.console_commands] == ["a", "b", "dump_items"]
+
+    try:
+        DesiredGameState.merge(refueler, boost_only, conflict="error")
+        assert False
+    except ValueError as e:
+        print(e)
+    print()
+
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        recording_path = os.path.join(tmp_dir, "match.rlbotrec")
+
+        match_config = MatchConfiguration(launcher_arg="test")
+        with recording.RecordingWriter(recording_path, match_config) as writer:
+            for frame in range(10):
+                game_packet = GamePacket(
+                    match_info=MatchInfo(frame_num=frame, seconds_elapsed=frame / 120)
+                )
+                writer.write(CorePacket(game_packet))
+                writer.write(InterfacePacket(PlayerInput(1)))
+        assert writer.closed and len(writer) == 20
+
+        reader = recording.RecordingReader(recording_path)
+        assert reader.schema_hash == recording.SCHEMA_HASH
+        assert reader.match_config.launcher_arg == "test"
+        assert len(reader) == 20
+        assert reader[-1].kind == "interface" and reader[-1].frame_num == 9
+        assert isinstance(reader[-1].unpack().message, PlayerInput)
+        assert reader[reader.seek_frame(5)].unpack().message.match_info.frame_num == 5
+        assert reader.seek_time(1.0) == len(reader)
+        assert [r.index for r in reader.records(4, 8)] == [4, 5, 6, 7]
+        assert sum(1 for r in reader if r.kind == "core") == 10
+        print(reader[3])
+
+        mapped = recording.MappedRecording(recording_path)
+        assert len(mapped) == len(reader)
+        assert mapped.match_config.launcher_arg == "test"
+        assert mapped[7].data == reader[7].data
+        assert [view.frame_num for view in mapped.views(3, 5)] == [3, 3, 4, 4]
+        unpacked = mapped.unpack_range(start_frame=8)
+        assert len(unpacked) == 4
+        assert unpacked[0].message.match_info.frame_num == 8
+        print(mapped[-1])
+
+        match_table = mapped.to_arrow(stop_frame=5)
+        assert match_table.num_rows == 5
+        assert match_table.column("match_info.frame_num") == [0, 1, 2, 3, 4]
+        del mapped
+
+        # a recording that wasn't closed is read up to the last complete record
+        with open(recording_path, "rb") as f:
+            data = f.read()
+        with open(recording_path, "wb") as f:
+            f.write(data[: len(data) // 2])
+        truncated = recording.RecordingReader(recording_path)
+        assert 0 < len(truncated) < 20
+        truncated[-1].unpack()
+
+        corrupted = bytearray(data)
+        corrupted[data.index(reader[0].data) + 8] ^= 0xFF
+        with open(recording_path, "wb") as f:
+            f.write(corrupted)
+        try:
+            list(recording.RecordingReader(recording_path))
+            assert False
+        except ValueError as e:
+            print(e)
+
+        # a corrupted length in the header fails without allocating it
+        corrupted = bytearray(data)
+        corrupted[20:24] = b"\xff\xff\xff\xff"
+        with open(recording_path, "wb") as f:
+            f.write(corrupted)
+        try:
+            recording.RecordingReader(recording_path)
+            assert False
+        except OSError as e:
+            print(e)
+
+        # the frame numbers start over when the match restarts
+        with recording.RecordingWriter(recording_path) as writer:
+            for frame in [*range(6), *range(6)]:
+                game_packet = GamePacket(
+                    match_info=MatchInfo(frame_num=frame, seconds_elapsed=frame / 120)
+                )
+                writer.write(CorePacket(game_packet))
+        restarted = recording.RecordingReader(recording_path)
+        assert restarted.seek_frame(4) == 4 and restarted.seek_frame(6) == 12
+        assert restarted.seek_time(2 / 120) == 2
+        mapped_restarted = recording.MappedRecording(recording_path)
+        assert mapped_restarted.seek_frame(5) == 5
+        assert [view.index for view in mapped_restarted.views(2, 4)] == [2, 3, 8, 9]
+        assert len(mapped_restarted.unpack_range(stop_frame=1)) == 2
+    print()
+
+    arrow_packets = [
+        GamePacket(
+            players=[
+                PlayerInfo(Physics(Vector3(i, frame, 17)), name=f"bot{i}")
+                for i in range(frame % 3)
+            ],
+            match_info=MatchInfo(frame_num=frame, match_phase=MatchPhase.Active),
+        )
+        for frame in range(4)
+    ]
+    batch = arrow.record_batch(arrow_packets)
+    assert len(batch) == 4
+    assert batch.column_names[0] == "players[0].physics.location.x"
+    assert batch.column("players[1].physics.location.y") == [None, None, 2.0, None]
+    assert batch.column("players[0].name") == [None, "bot0", "bot0", None]
+    assert batch.column("match_info.match_phase") == ["Active"] * 4
+    assert "players[0].latest_touch.game_seconds" not in batch.column_names
+    slices = BallPrediction([PredictionSlice(1)] * 3).slices
+    assert arrow.record_batch(slices).num_rows == 3
+
+    capsule_is_valid = ctypes.pythonapi.PyCapsule_IsValid
+    capsule_is_valid.argtypes = [ctypes.py_object, ctypes.c_char_p]
+    assert capsule_is_valid(batch.__arrow_c_schema__(), b"arrow_schema")
+    schema_capsule, array_capsule = batch.__arrow_c_array__()
+    assert capsule_is_valid(array_capsule, b"arrow_array")
+    assert capsule_is_valid(batch.__arrow_c_stream__(), b"arrow_array_stream")
+
+    try:
+        arrow.record_batch([GamePacket(), BallPrediction()])
+        assert False
+    except TypeError as e:
+        print(e)
+    print()
+
+    array_packet = arrow_packets[0].replace(
+        players=[
+            PlayerInfo(Physics(Vector3(i, 2, 3)), boost=i * 10, is_bot=True)
+            for i in range(3)
+        ]
+    )
+    players = array_packet.player_array()
+    assert players.shape == (3, 12) and players.format == "f"
+    assert players.tolist()[2][:3] == [2.0, 2.0, 3.0]
+    players = array_packet.player_array(["boost", "is_bot", "latest_touch.location"])
+    assert players.shape == (3, 5)
+    assert players.tolist()[1][:2] == [10.0, 1.0] and math.isnan(players.tolist()[1][2])
+    assert array_packet.ball_array().shape == (0, 12)
+    assert GamePacket().player_array(["boost"]).shape == (0, 1)
+    array_pred = BallPrediction([PredictionSlice(t / 120) for t in range(6 * 120)])
+    assert array_pred.to_array().shape == (720, 13)
+    assert array_pred.to_array(["game_seconds"]).tolist()[120] == [1.0]
+
+    for bad_fields in (["name"], []):
+        try:
+            array_packet.player_array(bad_fields)
+            assert False
+        except ValueError as e:
+            print(e)
+    print()
+
+    line_points = array.array("d", range(2048 * 3))
+    line = PolyLine3D.from_points(line_points, Color(a=255))
+    assert len(line.points) == 2048 and line.color.a == 255
+    assert (line.points[1].x, line.points[1].z) == (3, 5)
+    assert PolyLine3D.pack_points(line_points, Color(a=255)) == line.pack()
+    times = array_pred.to_array(["game_seconds"])
+    locations = array_pred.to_array(["physics.location"])
+    built_pred = BallPrediction.from_array(times, locations, velocities=locations)
+    packed_pred = BallPrediction.pack_array(times, locations, velocities=locations)
+    assert packed_pred == built_pred.pack()
+    assert built_pred.pack() == BallPrediction(
+        [
+            PredictionSlice(t / 120, Physics(velocity=Vector3()))
+            for t in range(6 * 120)
+        ]
+    ).pack()
+
+    try:
+        BallPrediction.from_array(array.array("f", [1, 2]), locations)
+        assert False
+    except ValueError as e:
+        print(e)
+    try:
+        PolyLine3D.from_points(array.array("i", [1, 2, 3]))
+        assert False
+    except TypeError as e:
+        print(e)
+    print()
+
+    rolling = array.array("f", [v for t in range(720) for v in (0, t * 20, 93)])
+    rolling_pred = BallPrediction.from_array(
+        array_pred.to_array(["game_seconds"]), rolling
+    )
+    state = rolling_pred.state_at(1 / 240)
+    assert state.game_seconds == 1 / 240
+    assert abs(state.physics.location.y - 10) < 1e-3
+    assert rolling_pred.state_at(-1) is None and rolling_pred.state_at(6) is None
+    assert rolling_pred.time_range(1, 2) == range(120, 240)
+    assert rolling_pred.time_range(2, 1) == range(240, 240)
+    orange_goal = FieldInfo.standard_soccar().goals[1]
+    scored_index = rolling_pred.find_first(goal=orange_goal)
+    assert scored_index == 261
+    assert rolling_pred.find_first(goal=orange_goal, stop_seconds=2) is None
+    assert rolling_pred.find_first(max_height=90) is None
+    corners = (Vector3(-100, 1000, 0), Vector3(100, 2000, 200))
+    assert rolling_pred.find_all(region=corners) == list(range(50, 101))
+
+    away = array.array("f", [v for t in range(720) for v in (0, 3000 + t * 4, 93)])
+    away_pred = BallPrediction.from_array(array_pred.to_array(["game_seconds"]), away)
+    car = PlayerInfo(Physics(Vector3(0, 0, 17)), boost=100)
+    fast = intercept.find_intercept(car, away_pred)
+    slow = intercept.find_intercept(car, away_pred, boost=0)
+    assert fast is not None and slow is not None
+    assert fast.index < slow.index and fast.required_speed > slow.required_speed
+    assert fast.slice.game_seconds == fast.time
+    ball_location = fast.slice.physics.location
+    assert abs(fast.arrival_location.distance(ball_location) - 150.2537) < 1e-3
+    no_boost = intercept.DriveModel(use_boost=False)
+    assert intercept.find_intercept(car, away_pred, no_boost).index == slow.index
+    assert intercept.find_intercept(car, away_pred, max_height=50) is None
+    close = intercept.find_intercept(Physics(Vector3(0, 3000, 17)), away_pred)
+    assert close.index == 0 and close.required_speed == 0
+    reversing = Physics(Vector3(0, 0, 17), velocity=Vector3(0, -2000, 0))
+    assert intercept.find_intercept(reversing, away_pred).index == fast.index
+    approaching = Physics(Vector3(0, 0, 17), velocity=Vector3(0, 2000, 0))
+    assert intercept.find_intercept(approaching, away_pred).index < fast.index
+
+    dropped = BallPrediction.simulate(
+        BallInfo(Physics(Vector3(0, 0, 1000)), SphereShape(200)), game_seconds=10
+    )
+    assert len(dropped.slices) == 720 and dropped.slices[0].game_seconds == 10
+    heights = [s.physics.location.z for s in dropped.slices]
+    assert min(heights) == 100 and 180 < heights.index(100) < 220
+    assert all(s.physics.location.x == 0 for s in dropped.slices)
+    resting = BallPrediction.simulate(Physics(Vector3(0, 0, 91.25)))
+    assert all(s.physics.location.z == 91.25 for s in resting.slices)
+    shot = Physics(Vector3(0, 4000, 93), velocity=Vector3(0, 3000, 0))
+    assert BallPrediction.simulate(shot).find_first(goal=orange_goal) is not None
+    wide = Physics(Vector3(2000, 4000, 93), velocity=Vector3(0, 3000, 0))
+    wide_pred = BallPrediction.simulate(wide)
+    assert wide_pred.find_first(goal=orange_goal) is None
+    assert max(s.physics.location.y for s in wide_pred.slices) == 5120 - 91.25
+    assert wide_pred.slices[-1].physics.velocity.y < 0
+    # a small ball can cross the back wall in a tick, but it's still outside the goal
+    marble = Physics(Vector3(2000, 5000, 500), velocity=Vector3(0, 6000, 0))
+    marble_pred = BallPrediction.simulate(BallInfo(marble, SphereShape(20)))
+    assert all(abs(s.physics.location.x) < 2001 for s in marble_pred.slices)
+    assert max(s.physics.location.y for s in marble_pred.slices) == 5120 - 10
+    puck = BallInfo(Physics(Vector3(0, 0, 500)), CylinderShape(300, 100))
+    assert BallPrediction.simulate(puck).slices[-1].physics.location.z == 150
+    for seconds in (math.inf, math.nan, 1e12):
+        try:
+            BallPrediction.simulate(Physics(), seconds=seconds)
+            assert False
+        except ValueError as e:
+            print(e)
+
+    octane = PlayerInfo(
+        Physics(Vector3(0, 0, 17), Rotator(0, math.pi / 2, 0)),
+        hitbox=BoxShape(118, 84, 36),
+        hitbox_offset=Vector3(14, 0, 21),
+        demolished_timeout=-1,
+    )
+    assert octane.can_jump() and not octane.can_dodge()
+    assert not octane.replace(demolished_timeout=2).can_jump()
+    assert not octane.replace(demolished_timeout=0).can_jump()
+    jumped = octane.replace(air_state=AirState.InAir, has_jumped=True)
+    assert jumped.replace(dodge_timeout=0.5).dodge_time_remaining() == 0.5
+    assert not jumped.replace(dodge_timeout=-1).can_dodge()
+    assert not jumped.replace(dodge_timeout=0.5, has_dodged=True).can_dodge()
+    flip_reset = octane.replace(air_state=AirState.InAir)
+    assert flip_reset.can_dodge() and flip_reset.dodge_time_remaining() == math.inf
+
+    corners = [tuple(round(c, 6) for c in v) for v in octane.hitbox_corners()]
+    assert corners[0] == (-42, 73, 56) and corners[-1] == (42, -45, 20)
+    sphere_ball = BallInfo(Physics(Vector3(0, 500, 56)), SphereShape(182.5))
+    assert abs(octane.distance_to(sphere_ball) - (500 - 73 - 91.25)) < 1e-6
+    assert abs(octane.closest_point(sphere_ball).y - 73) < 1e-6
+    cube_ball = BallInfo(Physics(Vector3(300, 0, 38)), BoxShape(100, 100, 100))
+    assert abs(octane.distance_to(cube_ball) - 208) < 1e-6
+    puck = BallInfo(Physics(Vector3(0, -300, 38)), CylinderShape(100, 40))
+    assert abs(octane.distance_to(puck) - 205) < 1e-6
+    overlapping = BallInfo(Physics(Vector3(0, 0, 50)), SphereShape(182.5))
+    assert octane.distance_to(overlapping) == 0
+
+    mirror_packet = GamePacket(
+        players=[
+            PlayerInfo(
+                Physics(Vector3(1, 2, 3), Rotator(0.1, 0.5, 0.2), Vector3(4, 5, 6)),
+                latest_touch=Touch(1, Vector3(7, 8, 9), Vector3(0, 1, 0)),
+            )
+        ],
+        boost_pads=[BoostPadState(True), BoostPadState(False, 2)],
+        balls=[BallInfo(Physics(Vector3(-100, 200, 93)))],
+    )
+    mirror = mirror_packet.mirrored()
+    mirrored_physics = mirror.players[0].physics
+    assert tuple(mirrored_physics.location) == (-1, -2, 3)
+    assert tuple(mirrored_physics.velocity) == (-4, -5, 6)
+    assert abs(mirrored_physics.rotation.yaw - (0.5 - math.pi)) < 1e-6
+    assert abs(mirrored_physics.rotation.pitch - 0.1) < 1e-6
+    assert tuple(mirror.players[0].latest_touch.location) == (-7, -8, 9)
+    assert [pad.is_active for pad in mirror.boost_pads] == [False, True]
+    assert tuple(mirror.balls[0].physics.location) == (100, -200, 93)
+    assert mirror.mirrored().pack() == mirror_packet.pack()
+    assert rolling_pred.mirrored().slices[120].physics.location.y == -2400
+
+    soccar = FieldInfo.standard_soccar()
+    mirrored_field = soccar.mirrored()
+    for pad, mirrored_pad in zip(soccar.boost_pads, mirrored_field.boost_pads):
+        assert pad.is_full_boost == mirrored_pad.is_full_boost
+        assert pad.location.distance(mirrored_pad.location) < 3
+    assert mirrored_field.goals[0].team_num == 0
+    assert mirrored_field.goals[0].location.y == 5120
+
+    desired = DesiredGameState(
+        car_states=[
+            DesiredCarState(DesiredPhysics(Vector3Partial(x=5), RotatorPartial(yaw=1)))
+        ]
+    )
+    desired_physics = desired.mirrored().car_states[0].physics
+    assert desired_physics.location.x == -5 and desired_physics.location.y is None
+    assert abs(desired_physics.rotation.yaw - (1 - math.pi)) < 1e-6
+    assert desired_physics.rotation.pitch is None and desired_physics.velocity is None
+    assert desired.mirrored().mirrored().pack() == desired.pack()
+    turned = DesiredGameState(
+        car_states=[DesiredCarState(DesiredPhysics(rotation=RotatorPartial(yaw=0.1)))]
+    )
+    turned_back = turned.mirrored().mirrored().car_states[0].physics.rotation
+    assert abs(turned_back.yaw - 0.1) < 1e-6
+
+    encoder = delta.GamePacketEncoder(keyframe_interval=60)
+    decoder = delta.GamePacketDecoder()
+    delta_players = [PlayerInfo(name=f"bot{i}", team=i % 2) for i in range(6)]
+    packed_size = encoded_size = 0
+    for frame in range(180):
+        if frame == 90:
+            delta_players.append(PlayerInfo(name="late joiner"))
+        game_packet = GamePacket(
+            players=[
+                player.replace(
+                    physics=Physics(Vector3(i, frame, 17)), boost=frame % 100
+                )
+                for i, player in enumerate(delta_players)
+            ],
+            boost_pads=[BoostPadState(i % 3 == 0) for i in range(34)],
+            match_info=MatchInfo(frame_num=frame, seconds_elapsed=frame / 120),
+        )
+        encoded = encoder.encode(game_packet)
+        assert delta.is_keyframe(encoded) == (frame % 60 == 0)
+        assert decoder.decode(encoded).pack() == game_packet.pack()
+        packed_size += len(game_packet.pack())
+        encoded_size += len(encoded)
+    assert encoded_size * 5 < packed_size
+    huge_len = bytes([1, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F])
+    truncating_encoder = delta.GamePacketEncoder()
+    truncating_encoder.encode(game_packet)
+    truncated = truncating_encoder.encode(game_packet.replace(players=[]))[:-1]
+    for garbage in (huge_len, truncated):
+        try:
+            decoder.decode(garbage)
+            assert False
+        except ValueError as e:
+            print(e)
+
+    assert delta.is_keyframe(encoder.encode(game_packet))
+    try:
+        delta.GamePacketDecoder().decode(encoder.encode(game_packet))
+        assert False
+    except ValueError as e:
+        print(e)
+    print()
+
     print(repr(RenderMessage()))
 
     render_type = RenderMessage(
@@ -100,6 +657,14 @@ if __name__ == "__main__":
     else:
         raise ValueError("Expected Line3D")
 
+    assert isinstance(render_type.variety, RenderType)
+    assert isinstance(render_type.variety, Table)
+    assert Line3D in RenderType.variants
+    assert isinstance(Color(), Struct) and not isinstance(Color(), Table)
+    assert isinstance(Color(), Packable) and isinstance(GamePacket(), Packable)
+    assert isinstance(PhysicsBuilder(), Packable) and not isinstance(Physics(), Table)
+    assert not isinstance(GamePacket(), InterfaceMessage)
+
     print(hash(render_type))
     print(render_type)
     eval(repr(render_type))
@@ -127,6 +692,28 @@ if __name__ == "__main__":
         print(e)
     print()
 
+    assert list(AirState.__members__.values())[3] is AirState.Dodging
+    assert AirState.from_name("double_jumping") == AirState.DoubleJumping
+    assert AirState.Dodging.name == "Dodging" and AirState.Dodging.value == 3
+    assert AirState.OnGround < AirState.Dodging
+    assert AirState.Dodging == 3 and AirState.Dodging > 2
+    assert [0, 1, 2, 3][AirState.Dodging] == 3
+    assert AirState.members() == list(AirState.__members__.values())
+    assert [int(phase) for phase in MatchPhase.members()] == list(range(8))
+
+    class IntAirState(IntEnum):
+        Dodging = 3
+
+    assert AirState.Dodging == IntAirState.Dodging
+    assert IntAirState(AirState.Dodging) is IntAirState.Dodging
+
+    try:
+        AirState.from_name("Flying")
+        assert False
+    except ValueError as e:
+        print(e)
+    print()
+
     invalid_data = comm.pack()
 
     try:
@@ -0,0 +1,6 @@
+from .rlbot_flatbuffers import *
+
+__doc__ = rlbot_flatbuffers.__doc__
+__version__ = rlbot_flatbuffers.__version__
+if hasattr(rlbot_flatbuffers, "__all__"):
+    __all__ = rlbot_flatbuffers.__all__
@@ -1,12 +1,83 @@
 from __future__ import annotations
 
-from typing import Sequence
+from abc import ABC
+from typing import Any, Iterable, Iterator, Literal, Mapping, Sequence
+
+from . import arena as arena
+from . import arrow as arrow
+from . import boost as boost
+from . import delta as delta
+from . import events as events
+from . import intercept as intercept
+from . import path as path
+from . import recording as recording
+from . import schema as schema
 
 __doc__: str
 __version__: str
 
 class InvalidFlatbuffer(ValueError): ...
 
+class Packable(ABC):
+    """
+    Base class of everything that can be packed into a flatbuffer with `pack()`:
+    the generated tables and structs, and their builders.
+    """
+
+    def pack(self) -> bytes: ...
+
+class Table(Packable):
+    """
+    Base class of all generated flatbuffer tables.
+    """
+
+class Struct(Packable):
+    """
+    Base class of all generated flatbuffer structs.
+    """
+
+class CollisionShape(ABC):
+    """
+    Marker class of the `CollisionShape` union.
+    """
+
+    variants: tuple[type[BoxShape], type[SphereShape], type[CylinderShape]]
+
+class CoreMessage(ABC):
+    """
+    Messages sent from core to interfaces
+    """
+
+    variants: tuple[type[DisconnectSignal], type[GamePacket], type[FieldInfo], type[MatchConfiguration], type[MatchComm], type[BallPrediction], type[ControllableTeamInfo], type[RenderingStatus], type[PingRequest], type[PingResponse]]
+
+class InterfaceMessage(ABC):
+    """
+    Messages sent from interfaces to core
+    """
+
+    variants: tuple[type[DisconnectSignal], type[StartCommand], type[MatchConfiguration], type[PlayerInput], type[DesiredGameState], type[RenderGroup], type[RemoveRenderGroup], type[MatchComm], type[ConnectionSettings], type[StopCommand], type[SetLoadout], type[InitComplete], type[RenderingStatus], type[PingRequest], type[PingResponse]]
+
+class PlayerClass(ABC):
+    """
+    Possible player types.
+    """
+
+    variants: tuple[type[Human], type[CustomBot], type[PsyonixBot]]
+
+class RelativeAnchor(ABC):
+    """
+    Anchors attached to objects.
+    """
+
+    variants: tuple[type[BallAnchor], type[CarAnchor]]
+
+class RenderType(ABC):
+    """
+    The different types of RenderMessages.
+    """
+
+    variants: tuple[type[Line3D], type[PolyLine3D], type[String2D], type[String3D], type[Rect2D], type[Rect3D]]
+
 class AerialGoalScoreMutator:
     One: AerialGoalScoreMutator
     """
@@ -33,13 +104,44 @@ class AerialGoalScoreMutator:
     `assert int(AerialGoalScoreMutator.Ten) == 5`
     """
 
+    __members__: Mapping[str, AerialGoalScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> AerialGoalScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> AerialGoalScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[AerialGoalScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in AerialGoalScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -87,13 +189,44 @@ class AirState:
     The car is free falling.
     """
 
+    __members__: Mapping[str, AirState]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> AirState: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> AirState:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[AirState]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in AirState.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -116,13 +249,44 @@ class AssistGoalScoreMutator:
     `assert int(AssistGoalScoreMutator.Three) == 3`
     """
 
+    __members__: Mapping[str, AssistGoalScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> AssistGoalScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> AssistGoalScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[AssistGoalScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in AssistGoalScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -141,13 +305,44 @@ class AudioMutator:
     `assert int(AudioMutator.Haunted) == 1`
     """
 
+    __members__: Mapping[str, AudioMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> AudioMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> AudioMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[AudioMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in AudioMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -178,13 +373,44 @@ class BallBouncinessMutator:
     `assert int(BallBouncinessMutator.Lowish) == 4`
     """
 
+    __members__: Mapping[str, BallBouncinessMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallBouncinessMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallBouncinessMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallBouncinessMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallBouncinessMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -211,13 +437,44 @@ class BallGravityMutator:
     `assert int(BallGravityMutator.SuperHigh) == 3`
     """
 
+    __members__: Mapping[str, BallGravityMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallGravityMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallGravityMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallGravityMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallGravityMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -244,13 +501,44 @@ class BallMaxSpeedMutator:
     `assert int(BallMaxSpeedMutator.SuperFast) == 3`
     """
 
+    __members__: Mapping[str, BallMaxSpeedMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallMaxSpeedMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallMaxSpeedMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallMaxSpeedMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallMaxSpeedMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -281,13 +569,44 @@ class BallSizeMutator:
     `assert int(BallSizeMutator.Gigantic) == 4`
     """
 
+    __members__: Mapping[str, BallSizeMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallSizeMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallSizeMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallSizeMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallSizeMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -358,13 +677,44 @@ class BallTypeMutator:
     `assert int(BallTypeMutator.PizzaPuck) == 14`
     """
 
+    __members__: Mapping[str, BallTypeMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallTypeMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallTypeMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallTypeMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallTypeMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -407,13 +757,44 @@ class BallWeightMutator:
     `assert int(BallWeightMutator.MagnusFutballLess) == 7`
     """
 
+    __members__: Mapping[str, BallWeightMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BallWeightMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BallWeightMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BallWeightMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BallWeightMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -444,13 +825,44 @@ class BoostAmountMutator:
     `assert int(BoostAmountMutator.NoBoost) == 4`
     """
 
+    __members__: Mapping[str, BoostAmountMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BoostAmountMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BoostAmountMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BoostAmountMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BoostAmountMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -481,13 +893,44 @@ class BoostStrengthMutator:
     `assert int(BoostStrengthMutator.Ten) == 4`
     """
 
+    __members__: Mapping[str, BoostStrengthMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> BoostStrengthMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> BoostStrengthMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[BoostStrengthMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in BoostStrengthMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -512,13 +955,44 @@ class DebugRendering:
     Ignore all render attempts at all times
     """
 
+    __members__: Mapping[str, DebugRendering]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> DebugRendering: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> DebugRendering:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[DebugRendering]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in DebugRendering.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -565,13 +1039,44 @@ class DemolishMutator:
     `assert int(DemolishMutator.OnBallContactFfSilent) == 8`
     """
 
+    __members__: Mapping[str, DemolishMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> DemolishMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> DemolishMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[DemolishMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in DemolishMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -594,13 +1099,44 @@ class DemolishScoreMutator:
     `assert int(DemolishScoreMutator.Three) == 3`
     """
 
+    __members__: Mapping[str, DemolishScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> DemolishScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> DemolishScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[DemolishScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in DemolishScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -623,13 +1159,44 @@ class DodgeTimerMutator:
     `assert int(DodgeTimerMutator.Unlimited) == 3`
     """
 
+    __members__: Mapping[str, DodgeTimerMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> DodgeTimerMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> DodgeTimerMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[DodgeTimerMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in DodgeTimerMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -660,13 +1227,44 @@ class ExistingMatchBehavior:
     No other otherwise.
     """
 
+    __members__: Mapping[str, ExistingMatchBehavior]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> ExistingMatchBehavior: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> ExistingMatchBehavior:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[ExistingMatchBehavior]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in ExistingMatchBehavior.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -689,13 +1287,44 @@ class GameEventMutator:
     `assert int(GameEventMutator.Rugby) == 2`
     """
 
+    __members__: Mapping[str, GameEventMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> GameEventMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> GameEventMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[GameEventMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in GameEventMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -738,13 +1367,44 @@ class GameMode:
     `assert int(GameMode.Knockout) == 7`
     """
 
+    __members__: Mapping[str, GameMode]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> GameMode: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> GameMode:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[GameMode]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in GameMode.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -767,13 +1427,44 @@ class GameSpeedMutator:
     `assert int(GameSpeedMutator.TimeWarp) == 2`
     """
 
+    __members__: Mapping[str, GameSpeedMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> GameSpeedMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> GameSpeedMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[GameSpeedMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in GameSpeedMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -804,13 +1495,44 @@ class GravityMutator:
     `assert int(GravityMutator.Reverse) == 4`
     """
 
-    def __new__(cls, value: int = 0) -> GravityMutator: ...
-    def __init__(self, value: int = 0) -> None:
+    __members__: Mapping[str, GravityMutator]
+    """
+    All members of this enum, by name
+    """
+
+    def __new__(cls, value: int = 0) -> GravityMutator: ...
+    def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> GravityMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[GravityMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in GravityMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -825,13 +1547,44 @@ class InputRestrictionMutator:
     `assert int(InputRestrictionMutator.Backwards) == 1`
     """
 
+    __members__: Mapping[str, InputRestrictionMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> InputRestrictionMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> InputRestrictionMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[InputRestrictionMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in InputRestrictionMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -866,13 +1619,44 @@ class JumpMutator:
     `assert int(JumpMutator.NoJumps) == 6`
     """
 
+    __members__: Mapping[str, JumpMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> JumpMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> JumpMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[JumpMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in JumpMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -902,13 +1686,44 @@ class Launcher:
     `assert int(Launcher.NoLaunch) == 3`
     """
 
+    __members__: Mapping[str, Launcher]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> Launcher: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> Launcher:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[Launcher]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in Launcher.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -935,13 +1750,44 @@ class MatchLengthMutator:
     `assert int(MatchLengthMutator.Unlimited) == 3`
     """
 
+    __members__: Mapping[str, MatchLengthMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> MatchLengthMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> MatchLengthMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[MatchLengthMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in MatchLengthMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1001,13 +1847,44 @@ class MatchPhase:
     The match has ended.
     """
 
+    __members__: Mapping[str, MatchPhase]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> MatchPhase: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> MatchPhase:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[MatchPhase]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in MatchPhase.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1078,13 +1955,44 @@ class MaxScoreMutator:
     `assert int(MaxScoreMutator.HundredGoals) == 14`
     """
 
+    __members__: Mapping[str, MaxScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> MaxScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> MaxScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[MaxScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in MaxScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1103,13 +2011,44 @@ class MaxTimeMutator:
     `assert int(MaxTimeMutator.ElevenMinutes) == 1`
     """
 
+    __members__: Mapping[str, MaxTimeMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> MaxTimeMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> MaxTimeMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[MaxTimeMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in MaxTimeMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1136,13 +2075,44 @@ class MultiBallMutator:
     `assert int(MultiBallMutator.Six) == 3`
     """
 
+    __members__: Mapping[str, MultiBallMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> MultiBallMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> MultiBallMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[MultiBallMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in MultiBallMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1173,13 +2143,44 @@ class NormalGoalScoreMutator:
     `assert int(NormalGoalScoreMutator.Ten) == 5`
     """
 
+    __members__: Mapping[str, NormalGoalScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> NormalGoalScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> NormalGoalScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[NormalGoalScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in NormalGoalScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1202,13 +2203,44 @@ class OvertimeMutator:
     `assert int(OvertimeMutator.FiveMaxRandomTeam) == 2`
     """
 
+    __members__: Mapping[str, OvertimeMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> OvertimeMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> OvertimeMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[OvertimeMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in OvertimeMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1231,13 +2263,44 @@ class PossessionScoreMutator:
     `assert int(PossessionScoreMutator.ThreeSeconds) == 3`
     """
 
+    __members__: Mapping[str, PossessionScoreMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> PossessionScoreMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> PossessionScoreMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[PossessionScoreMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in PossessionScoreMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1264,13 +2327,44 @@ class PsyonixSkill:
     `assert int(PsyonixSkill.AllStar) == 3`
     """
 
+    __members__: Mapping[str, PsyonixSkill]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> PsyonixSkill: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> PsyonixSkill:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[PsyonixSkill]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in PsyonixSkill.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1297,13 +2391,44 @@ class RespawnTimeMutator:
     `assert int(RespawnTimeMutator.DisableGoalReset) == 3`
     """
 
+    __members__: Mapping[str, RespawnTimeMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> RespawnTimeMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> RespawnTimeMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[RespawnTimeMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in RespawnTimeMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1374,13 +2499,44 @@ class RumbleMutator:
     `assert int(RumbleMutator.Rps) == 14`
     """
 
+    __members__: Mapping[str, RumbleMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> RumbleMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> RumbleMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[RumbleMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in RumbleMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1395,13 +2551,44 @@ class ScoringRuleMutator:
     `assert int(ScoringRuleMutator.Disabled) == 1`
     """
 
+    __members__: Mapping[str, ScoringRuleMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> ScoringRuleMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> ScoringRuleMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[ScoringRuleMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in ScoringRuleMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1428,13 +2615,44 @@ class SeriesLengthMutator:
     `assert int(SeriesLengthMutator.SevenGames) == 3`
     """
 
+    __members__: Mapping[str, SeriesLengthMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> SeriesLengthMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> SeriesLengthMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[SeriesLengthMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in SeriesLengthMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1449,13 +2667,44 @@ class StaleBallMutator:
     `assert int(StaleBallMutator.ThirtySeconds) == 1`
     """
 
+    __members__: Mapping[str, StaleBallMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> StaleBallMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> StaleBallMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[StaleBallMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in StaleBallMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1470,13 +2719,44 @@ class TerritoryMutator:
     `assert int(TerritoryMutator.Territory) == 1`
     """
 
+    __members__: Mapping[str, TerritoryMutator]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> TerritoryMutator: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> TerritoryMutator:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[TerritoryMutator]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in TerritoryMutator.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1499,13 +2779,44 @@ class TextHAlign:
     `assert int(TextHAlign.Right) == 2`
     """
 
+    __members__: Mapping[str, TextHAlign]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> TextHAlign: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> TextHAlign:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[TextHAlign]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in TextHAlign.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -1528,18 +2839,49 @@ class TextVAlign:
     `assert int(TextVAlign.Bottom) == 2`
     """
 
+    __members__: Mapping[str, TextVAlign]
+    """
+    All members of this enum, by name
+    """
+
     def __new__(cls, value: int = 0) -> TextVAlign: ...
     def __init__(self, value: int = 0) -> None:
         """
         :raises ValueError: If the `value` is not a valid enum value
         """
+    @staticmethod
+    def from_name(name: str) -> TextVAlign:
+        """
+        Gets the member with the given name, ignoring case and underscores
+
+        :raises ValueError: If there is no member with the given `name`
+        """
+    @classmethod
+    def members(cls) -> list[TextVAlign]:
+        """
+        All members of this enum, in the order of their values.
+        The class itself isn't iterable like an `enum.IntEnum`, so use `for member in TextVAlign.members():`
+        """
+    @property
+    def name(self) -> str: ...
+    @property
+    def value(self) -> int: ...
     def __int__(self) -> int: ...
-    def __eq__(self, other) -> bool: ...
+    def __index__(self) -> int:
+        """
+        Lets enums be used in place of an int, e.g. as a list index,
+        but they aren't subclasses of `int` or `numbers.Integral`, so `isinstance(x, int)` is `False`
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: int) -> bool: ...
+    def __le__(self, other: int) -> bool: ...
+    def __gt__(self, other: int) -> bool: ...
+    def __ge__(self, other: int) -> bool: ...
     def __hash__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BoostPadState:
+class BoostPadState(Struct):
     """
     The state of a boost pad.
     Note, static properties of boost pads, such as their location and size, are found in the field info.
@@ -1587,21 +2929,81 @@ class BoostPadState:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BoostPadState:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> BoostPadStateBuilder:
+        """
+        Creates a mutable `BoostPadStateBuilder` with the values of this instance
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Color:
+class BoostPadStateBuilder(Packable):
     """
-    An RGBA color.
+    A mutable builder for `BoostPadState`.
     """
 
-    r: int
-    g: int
-    b: int
-    a: int
+    is_active: bool
+    """
+    True if the boost can be picked up right now.
+    """
+    timer: float
+    """
+    The number of seconds since the boost has been picked up, or 0 if the boost is active.
+    A big boost pad becomes active again after 10 seconds.
+    A small boost pad becomes active again after 4 seconds.
+    """
 
     __match_args__ = (
-        "r",
+        "is_active",
+        "timer",
+    )
+
+    def __new__(
+        cls,
+        is_active: bool = False,
+        timer: float = 0.0,
+    ) -> BoostPadStateBuilder: ...
+    def __init__(
+        self,
+        is_active: bool = False,
+        timer: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> BoostPadState:
+        """
+        Creates a new `BoostPadState` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `BoostPadState` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class Color(Struct):
+    """
+    An RGBA color.
+    """
+
+    r: int
+    g: int
+    b: int
+    a: int
+
+    __match_args__ = (
+        "r",
         "g",
         "b",
         "a",
@@ -1637,10 +3039,18 @@ class Color:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Color:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ControllerState:
+class ControllerState(Struct):
     """
     A combination of button presses and analog steering values like those produced by a physical controller or keyboard.
     This is sent by bots each tick to RLBot to indicate what they want to do that tick.
@@ -1737,10 +3147,18 @@ class ControllerState:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ControllerState:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Float:
+class Float(Struct):
     """
     A floating point value located in a separate struct allowing for optional floats elsewhere.
     """
@@ -1775,10 +3193,18 @@ class Float:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Float:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Rotator:
+class Rotator(Struct):
     """
     Expresses the rotation state of an object in Euler angles. Values are in radians.
     """
@@ -1830,10 +3256,41 @@ class Rotator:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Rotator:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def forward(self) -> Vector3:
+        """
+        The direction the nose of the object is pointing
+        """
+    def right(self) -> Vector3: ...
+    def up(self) -> Vector3: ...
+    def to_matrix(self) -> tuple[Vector3, Vector3, Vector3]:
+        """
+        Returns the forward, right and up vectors,
+        which are the columns of the matrix that rotates local coordinates into world coordinates
+        """
+    def to_quaternion(self) -> tuple[float, float, float, float]:
+        """
+        Returns the rotation as a unit quaternion in `(w, x, y, z)` order
+        """
+    @staticmethod
+    def from_quaternion(w: float, x: float, y: float, z: float) -> Rotator:
+        """
+        Creates a rotator from a quaternion in `(w, x, y, z)` order,
+        which doesn't need to be normalized
+
+        :raises ValueError: If the quaternion doesn't have a finite, non-zero length
+        """
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ScoreInfo:
+class ScoreInfo(Struct):
     """
     A collection of values shown on the scoreboard (and a few more).
     """
@@ -1913,10 +3370,103 @@ class ScoreInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ScoreInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> ScoreInfoBuilder:
+        """
+        Creates a mutable `ScoreInfoBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class ScoreInfoBuilder(Packable):
+    """
+    A mutable builder for `ScoreInfo`.
+    """
+
+    score: int
+    """
+    The accumulated score, roughly indicating how well a player performs.
+    """
+    goals: int
+    """
+    Number of goals scored.
+    """
+    own_goals: int
+    """
+    Number of own-goals scored.
+    """
+    assists: int
+    """
+    Number of goals assisted.
+    """
+    saves: int
+    """
+    Number of shots saved.
+    """
+    shots: int
+    """
+    Number of shots on opponent goal.
+    """
+    demolitions: int
+    """
+    Number of demolitions made.
+    """
+
+    __match_args__ = (
+        "score",
+        "goals",
+        "own_goals",
+        "assists",
+        "saves",
+        "shots",
+        "demolitions",
+    )
+
+    def __new__(
+        cls,
+        score: int = 0,
+        goals: int = 0,
+        own_goals: int = 0,
+        assists: int = 0,
+        saves: int = 0,
+        shots: int = 0,
+        demolitions: int = 0,
+    ) -> ScoreInfoBuilder: ...
+    def __init__(
+        self,
+        score: int = 0,
+        goals: int = 0,
+        own_goals: int = 0,
+        assists: int = 0,
+        saves: int = 0,
+        shots: int = 0,
+        demolitions: int = 0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> ScoreInfo:
+        """
+        Creates a new `ScoreInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `ScoreInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class TeamInfo:
+class TeamInfo(Struct):
     """
     Information about teams. Currently only the number of goals scored.
     """
@@ -1963,10 +3513,70 @@ class TeamInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> TeamInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> TeamInfoBuilder:
+        """
+        Creates a mutable `TeamInfoBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class TeamInfoBuilder(Packable):
+    """
+    A mutable builder for `TeamInfo`.
+    """
+
+    team_index: int
+    """
+    The index of the team. Blue is 0, orange is 1.
+    """
+    score: int
+    """
+    Number of goals scored.
+    Note, this value may be different than the sum of the goals scored by the current players on the team as player may join/leave the game or switch teams.
+    This value is what is shown on the heads-up display.
+    """
+
+    __match_args__ = (
+        "team_index",
+        "score",
+    )
+
+    def __new__(
+        cls,
+        team_index: int = 0,
+        score: int = 0,
+    ) -> TeamInfoBuilder: ...
+    def __init__(
+        self,
+        team_index: int = 0,
+        score: int = 0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> TeamInfo:
+        """
+        Creates a new `TeamInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `TeamInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Vector2:
+class Vector2(Struct):
     """
     A vector with an x and y component.
     """
@@ -2005,10 +3615,85 @@ class Vector2:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Vector2:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> Vector2Builder:
+        """
+        Creates a mutable `Vector2Builder` with the values of this instance
+        """
+
+    def __add__(self, other: Vector2) -> Vector2: ...
+    def __sub__(self, other: Vector2) -> Vector2: ...
+    def __mul__(self, scalar: float) -> Vector2: ...
+    def __rmul__(self, scalar: float) -> Vector2: ...
+    def __truediv__(self, scalar: float) -> Vector2: ...
+    def __neg__(self) -> Vector2: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float: ...
+    def __iter__(self) -> Iterator[float]: ...
+    def dot(self, other: Vector2) -> float: ...
+    def length(self) -> float: ...
+    def normalized(self) -> Vector2:
+        """
+        Returns a unit vector in the same direction,
+        or a zero vector if this vector has no length
+        """
+    def distance(self, other: Vector2) -> float: ...
+    def lerp(self, other: Vector2, t: float) -> Vector2: ...
+    def angle_to(self, other: Vector2) -> float:
+        """
+        The angle between the two vectors in radians,
+        or 0 if either vector has no length
+        """
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class Vector2Builder(Packable):
+    """
+    A mutable builder for `Vector2`.
+    """
+
+    x: float
+    y: float
+
+    __match_args__ = (
+        "x",
+        "y",
+    )
+
+    def __new__(
+        cls,
+        x: float = 0.0,
+        y: float = 0.0,
+    ) -> Vector2Builder: ...
+    def __init__(
+        self,
+        x: float = 0.0,
+        y: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> Vector2:
+        """
+        Creates a new `Vector2` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `Vector2` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Vector3:
+class Vector3(Struct):
     """
     A vector with an x, y, z component.
     Note that Rocket League uses a left-handed coordinate system.
@@ -2052,10 +3737,42 @@ class Vector3:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Vector3:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def __add__(self, other: Vector3) -> Vector3: ...
+    def __sub__(self, other: Vector3) -> Vector3: ...
+    def __mul__(self, scalar: float) -> Vector3: ...
+    def __rmul__(self, scalar: float) -> Vector3: ...
+    def __truediv__(self, scalar: float) -> Vector3: ...
+    def __neg__(self) -> Vector3: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float: ...
+    def __iter__(self) -> Iterator[float]: ...
+    def dot(self, other: Vector3) -> float: ...
+    def cross(self, other: Vector3) -> Vector3: ...
+    def length(self) -> float: ...
+    def normalized(self) -> Vector3:
+        """
+        Returns a unit vector in the same direction,
+        or a zero vector if this vector has no length
+        """
+    def distance(self, other: Vector3) -> float: ...
+    def lerp(self, other: Vector3, t: float) -> Vector3: ...
+    def angle_to(self, other: Vector3) -> float:
+        """
+        The angle between the two vectors in radians,
+        or 0 if either vector has no length
+        """
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Physics:
+class Physics(Struct):
     """
     The physical state of an object.
     """
@@ -2102,12 +3819,82 @@ class Physics:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Physics:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> PhysicsBuilder:
+        """
+        Creates a mutable `PhysicsBuilder` with the values of this instance
+        """
+
+    def to_local(self, point: Vector3) -> Vector3:
+        """
+        Converts a point in world coordinates into coordinates relative to this object,
+        where +x is forward, +y is right and +z is up
+        """
+    def to_world(self, local: Vector3) -> Vector3:
+        """
+        Converts a point relative to this object into world coordinates,
+        where +x is forward, +y is right and +z is up
+        """
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PredictionSlice:
+class PhysicsBuilder(Packable):
     """
-    An entry in the ball prediction describing where a ball will be at some future time.
+    A mutable builder for `Physics`.
+    """
+
+    location: Vector3
+    rotation: Rotator
+    velocity: Vector3
+    angular_velocity: Vector3
+
+    __match_args__ = (
+        "location",
+        "rotation",
+        "velocity",
+        "angular_velocity",
+    )
+
+    def __new__(
+        cls,
+        location: Vector3 = Vector3(),
+        rotation: Rotator = Rotator(),
+        velocity: Vector3 = Vector3(),
+        angular_velocity: Vector3 = Vector3(),
+    ) -> PhysicsBuilder: ...
+    def __init__(
+        self,
+        location: Vector3 = Vector3(),
+        rotation: Rotator = Rotator(),
+        velocity: Vector3 = Vector3(),
+        angular_velocity: Vector3 = Vector3(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> Physics:
+        """
+        Creates a new `Physics` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `Physics` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class PredictionSlice(Struct):
+    """
+    An entry in the ball prediction describing where a ball will be at some future time.
     """
 
     game_seconds: float
@@ -2151,10 +3938,69 @@ class PredictionSlice:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PredictionSlice:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> PredictionSliceBuilder:
+        """
+        Creates a mutable `PredictionSliceBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class PredictionSliceBuilder(Packable):
+    """
+    A mutable builder for `PredictionSlice`.
+    """
+
+    game_seconds: float
+    """
+    The moment in game time that this prediction corresponds to.
+    This corresponds to 'seconds_elapsed' in the MatchInfo.
+    """
+    physics: Physics
+    """
+    The predicted location and motion of the object.
+    """
+
+    __match_args__ = (
+        "game_seconds",
+        "physics",
+    )
+
+    def __new__(
+        cls,
+        game_seconds: float = 0.0,
+        physics: Physics = Physics(),
+    ) -> PredictionSliceBuilder: ...
+    def __init__(
+        self,
+        game_seconds: float = 0.0,
+        physics: Physics = Physics(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> PredictionSlice:
+        """
+        Creates a new `PredictionSlice` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `PredictionSlice` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PlayerLoadout:
+class PlayerLoadout(Table):
     """
     Defines the car type, color, and other aspects of the player's appearance.
     See https://wiki.rlbot.org/botmaking/bot-customization/
@@ -2258,10 +4104,18 @@ class PlayerLoadout:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PlayerLoadout:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RenderAnchor:
+class RenderAnchor(Table):
     """
     A RenderAnchor is a point in space consisting of a world component and optionally a relative component.
     The relative component is given by a car or ball and includes a local offset that takes the orientation of the object into account.
@@ -2310,10 +4164,18 @@ class RenderAnchor:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RenderAnchor:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BoxShape:
+class BoxShape(Table, CollisionShape):
     """
     A box with dimensions.
     Used for cars and balls with a box shapes.
@@ -2357,10 +4219,66 @@ class BoxShape:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BoxShape:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> BoxShapeBuilder:
+        """
+        Creates a mutable `BoxShapeBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class BoxShapeBuilder(Packable):
+    """
+    A mutable builder for `BoxShape`.
+    """
+
+    length: float
+    width: float
+    height: float
+
+    __match_args__ = (
+        "length",
+        "width",
+        "height",
+    )
+
+    def __new__(
+        cls,
+        length: float = 0.0,
+        width: float = 0.0,
+        height: float = 0.0,
+    ) -> BoxShapeBuilder: ...
+    def __init__(
+        self,
+        length: float = 0.0,
+        width: float = 0.0,
+        height: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> BoxShape:
+        """
+        Creates a new `BoxShape` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `BoxShape` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class DisconnectSignal:
+class DisconnectSignal(Table, CoreMessage, InterfaceMessage):
     """
     Sent to core to indicate that you want to disconnect.
     Sent from core to indicate that you should exit.
@@ -2369,7 +4287,7 @@ class DisconnectSignal:
     def __new__(cls) -> DisconnectSignal: ...
     def __init__(self) -> None: ...
 
-class MatchComm:
+class MatchComm(Table, CoreMessage, InterfaceMessage):
     """
     A message sent to other bots and scripts through RLBot.
     Use the team_only field if the message should only be received by team mates.
@@ -2442,10 +4360,18 @@ class MatchComm:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> MatchComm:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PingRequest:
+class PingRequest(Table, CoreMessage, InterfaceMessage):
     """
     Ping request message
     """
@@ -2480,10 +4406,18 @@ class PingRequest:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PingRequest:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PingResponse:
+class PingResponse(Table, CoreMessage, InterfaceMessage):
     """
     Ping response message
     """
@@ -2518,10 +4452,18 @@ class PingResponse:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PingResponse:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RenderingStatus:
+class RenderingStatus(Table, CoreMessage, InterfaceMessage):
     """
     As an interface message, this requests for a specificed agent to have its ability to render changed.
     This changed will then be broadcasted to all current connections as a core message.
@@ -2576,10 +4518,18 @@ class RenderingStatus:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RenderingStatus:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class DesiredPhysics:
+class DesiredPhysics(Table):
     """
     A physical state of an object, with nullable components.
     Used for game state setting to define which part of a physics body should change.
@@ -2628,10 +4578,18 @@ class DesiredPhysics:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> DesiredPhysics:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class MatchConfiguration:
+class MatchConfiguration(Table, CoreMessage, InterfaceMessage):
     """
     Definition of a match.
     Can be sent to RLBot to request the start of a match.
@@ -2785,10 +4743,18 @@ class MatchConfiguration:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> MatchConfiguration:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ConnectionSettings:
+class ConnectionSettings(Table, InterfaceMessage):
     """
     Sent by clients when connecting to RLBot to indicate what type of messages are desired.
     This could be sent by a bot, or a bot manager governing several bots, an
@@ -2850,10 +4816,18 @@ class ConnectionSettings:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ConnectionSettings:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ConsoleCommand:
+class ConsoleCommand(Table):
     """
     A console command which will be executed inside Rocket League.
     See https://wiki.rlbot.org/framework/console-commands/ for a list of known commands.
@@ -2889,10 +4863,18 @@ class ConsoleCommand:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ConsoleCommand:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ControllableInfo:
+class ControllableInfo(Table):
     """
     Information about a car that the client can control.
     """
@@ -2939,10 +4921,70 @@ class ControllableInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ControllableInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> ControllableInfoBuilder:
+        """
+        Creates a mutable `ControllableInfoBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class ControllableInfoBuilder(Packable):
+    """
+    A mutable builder for `ControllableInfo`.
+    """
+
+    index: int
+    """
+    The index of the bot/script.
+    """
+    identifier: int
+    """
+    The id of the bot/script.
+    This value is mostly used internally to keep track of participants in the match.
+    The id can be used to find the corresponding PlayerConfiguration in the MatchConfiguration.
+    """
+
+    __match_args__ = (
+        "index",
+        "identifier",
+    )
+
+    def __new__(
+        cls,
+        index: int = 0,
+        identifier: int = 0,
+    ) -> ControllableInfoBuilder: ...
+    def __init__(
+        self,
+        index: int = 0,
+        identifier: int = 0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> ControllableInfo:
+        """
+        Creates a new `ControllableInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `ControllableInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class CylinderShape:
+class CylinderShape(Table, CollisionShape):
     """
     A cylinder with diameter and height.
     Used for balls with a cylindrical shape like the puck.
@@ -2982,45 +5024,97 @@ class CylinderShape:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
-
-class Human:
-    """
-    A normal human player.
-    """
+    def replace(self, **changes: Any) -> CylinderShape:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
 
-    def __new__(cls) -> Human: ...
-    def __init__(self) -> None: ...
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
 
-class InitComplete:
-    """
-    Indicates that the session has finished all initialization and is ready to start receiving
-    game messages without delay.
-    """
+    def to_builder(self) -> CylinderShapeBuilder:
+        """
+        Creates a mutable `CylinderShapeBuilder` with the values of this instance
+        """
 
-    def __new__(cls) -> InitComplete: ...
-    def __init__(self) -> None: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
 
-class LoadoutPaint:
+class CylinderShapeBuilder(Packable):
     """
-    Specification for 'painted' items. See https://wiki.rlbot.org/botmaking/bot-customization/
+    A mutable builder for `CylinderShape`.
     """
 
-    car_paint_id: int
-    decal_paint_id: int
-    wheels_paint_id: int
-    boost_paint_id: int
-    antenna_paint_id: int
-    hat_paint_id: int
-    trails_paint_id: int
-    goal_explosion_paint_id: int
+    diameter: float
+    height: float
 
     __match_args__ = (
-        "car_paint_id",
-        "decal_paint_id",
-        "wheels_paint_id",
-        "boost_paint_id",
+        "diameter",
+        "height",
+    )
+
+    def __new__(
+        cls,
+        diameter: float = 0.0,
+        height: float = 0.0,
+    ) -> CylinderShapeBuilder: ...
+    def __init__(
+        self,
+        diameter: float = 0.0,
+        height: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> CylinderShape:
+        """
+        Creates a new `CylinderShape` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `CylinderShape` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class Human(Table, PlayerClass):
+    """
+    A normal human player.
+    """
+
+    def __new__(cls) -> Human: ...
+    def __init__(self) -> None: ...
+
+class InitComplete(Table, InterfaceMessage):
+    """
+    Indicates that the session has finished all initialization and is ready to start receiving
+    game messages without delay.
+    """
+
+    def __new__(cls) -> InitComplete: ...
+    def __init__(self) -> None: ...
+
+class LoadoutPaint(Table):
+    """
+    Specification for 'painted' items. See https://wiki.rlbot.org/botmaking/bot-customization/
+    """
+
+    car_paint_id: int
+    decal_paint_id: int
+    wheels_paint_id: int
+    boost_paint_id: int
+    antenna_paint_id: int
+    hat_paint_id: int
+    trails_paint_id: int
+    goal_explosion_paint_id: int
+
+    __match_args__ = (
+        "car_paint_id",
+        "decal_paint_id",
+        "wheels_paint_id",
+        "boost_paint_id",
         "antenna_paint_id",
         "hat_paint_id",
         "trails_paint_id",
@@ -3065,10 +5159,18 @@ class LoadoutPaint:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> LoadoutPaint:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RemoveRenderGroup:
+class RemoveRenderGroup(Table, InterfaceMessage):
     """
     A client message request removal of a RenderGroup.
     A client can only clear its own RenderGroups.
@@ -3104,10 +5206,18 @@ class RemoveRenderGroup:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RemoveRenderGroup:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ScriptConfiguration:
+class ScriptConfiguration(Table):
     """
     A ScriptConfiguration defines a script of a match.
     """
@@ -3177,10 +5287,18 @@ class ScriptConfiguration:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ScriptConfiguration:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class SphereShape:
+class SphereShape(Table, CollisionShape):
     """
     A sphere with diameter.
     Used for balls with a spherical shapes.
@@ -3216,10 +5334,58 @@ class SphereShape:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> SphereShape:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> SphereShapeBuilder:
+        """
+        Creates a mutable `SphereShapeBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class SphereShapeBuilder(Packable):
+    """
+    A mutable builder for `SphereShape`.
+    """
+
+    diameter: float
+
+    __match_args__ = (
+        "diameter",
+    )
+
+    def __new__(
+        cls,
+        diameter: float = 0.0,
+    ) -> SphereShapeBuilder: ...
+    def __init__(
+        self,
+        diameter: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> SphereShape:
+        """
+        Creates a new `SphereShape` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `SphereShape` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class StartCommand:
+class StartCommand(Table, InterfaceMessage):
     """
     A client message to start a match using a path to a match config file.
     """
@@ -3254,10 +5420,18 @@ class StartCommand:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> StartCommand:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class StopCommand:
+class StopCommand(Table, InterfaceMessage):
     """
     A client message to stop a match and optionally the RLBot server too.
     """
@@ -3292,10 +5466,18 @@ class StopCommand:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> StopCommand:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BallAnchor:
+class BallAnchor(Table, RelativeAnchor):
     """
     A RenderAnchor attached to a ball.
     The local field allows for an offset in local coordinates taking the ball's orientation into account.
@@ -3342,10 +5524,18 @@ class BallAnchor:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BallAnchor:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BallPrediction:
+class BallPrediction(Table, CoreMessage):
     """
     A prediction of a ball's trajectory, assuming no collision with cars.
     """
@@ -3385,10 +5575,170 @@ class BallPrediction:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BallPrediction:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> BallPredictionBuilder:
+        """
+        Creates a mutable `BallPredictionBuilder` with the values of this instance
+        """
+
+    def to_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every slice as an N×K float32 array (a `memoryview`),
+        which `numpy.asarray` can wrap without copying.
+        By default, `game_seconds` and then the 12 numbers of `physics` are exported.
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    @staticmethod
+    def from_array(
+        times: Any,
+        locations: Any,
+        velocities: Any | None = None,
+        rotations: Any | None = None,
+        angular_velocities: Any | None = None,
+    ) -> BallPrediction:
+        """
+        Creates a ball prediction from a 1-dimensional array of `times`
+        and N×3 arrays of the physics at each of them, all float32s or float64s
+        (anything that implements the buffer protocol, like a numpy array).
+        `rotations` are in pitch, yaw and roll, and any array that isn't given is all zeros.
+        This still creates a `PredictionSlice` for every row, so `pack_array` is faster.
+
+        :raises TypeError: If an array isn't an array of float32s or float64s
+        :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+        """
+    @staticmethod
+    def pack_array(
+        times: Any,
+        locations: Any,
+        velocities: Any | None = None,
+        rotations: Any | None = None,
+        angular_velocities: Any | None = None,
+    ) -> bytes:
+        """
+        Packs a ball prediction from the same arrays as `from_array`,
+        which are read straight into the flat representation without creating a `PredictionSlice` per row.
+        The bytes are the same as `BallPrediction.from_array(...).pack()`.
+
+        :raises TypeError: If an array isn't an array of float32s or float64s
+        :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+        """
+    def state_at(self, game_seconds: float) -> PredictionSlice | None:
+        """
+        The predicted state of the ball at `game_seconds`, interpolated between the two closest slices,
+        or `None` if `game_seconds` isn't within the prediction
+        """
+    def time_range(
+        self, start_seconds: float | None = None, stop_seconds: float | None = None
+    ) -> range:
+        """
+        The indices of the slices from `start_seconds` up to (but not including) `stop_seconds`,
+        found with a binary search
+        """
+    def find_first(
+        self,
+        *,
+        min_height: float | None = None,
+        max_height: float | None = None,
+        region: tuple[Vector3, Vector3] | None = None,
+        goal: GoalInfo | None = None,
+        start_seconds: float | None = None,
+        stop_seconds: float | None = None,
+    ) -> int | None:
+        """
+        The index of the first slice where the ball meets all of the given conditions,
+        or `None` if there isn't one.
+        The ball's center must be between `min_height` and `max_height`,
+        inside the box between the two corners of `region`,
+        and entirely inside `goal` (past the goal line, between the posts and under the crossbar).
+        The search can be limited to the slices from `start_seconds` up to (but not including) `stop_seconds`
+        """
+    def find_all(
+        self,
+        *,
+        min_height: float | None = None,
+        max_height: float | None = None,
+        region: tuple[Vector3, Vector3] | None = None,
+        goal: GoalInfo | None = None,
+        start_seconds: float | None = None,
+        stop_seconds: float | None = None,
+    ) -> list[int]:
+        """
+        The indices of every slice where the ball meets all of the given conditions,
+        which are the same as in `find_first`
+        """
+    @staticmethod
+    def simulate(
+        ball: BallInfo | Physics,
+        gravity: float = -650.0,
+        game_seconds: float = 0.0,
+        seconds: float = 6.0,
+    ) -> BallPrediction:
+        """
+        Simulates a prediction of `ball` at 120Hz for `seconds`,
+        without collisions with cars, for testing without RLBot's core.
+        The first slice is at `game_seconds`, and `gravity` is usually `MatchInfo.world_gravity_z`.
+        The ball bounces off the floor, walls and ceiling of the standard soccar arena (without its rounded corners),
+        and its radius is that of its `SphereShape` or `CylinderShape`, or the standard ball's otherwise
+
+        :raises TypeError: If `ball` isn't a `BallInfo` or `Physics`
+        :raises ValueError: If `seconds` isn't finite or is over 60
+        """
+    def mirrored(self) -> BallPrediction:
+        """
+        A copy of the prediction from the other team's perspective, like `GamePacket.mirrored`
+        """
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class BallPredictionBuilder(Packable):
+    """
+    A mutable builder for `BallPrediction`.
+    """
+
+    slices: Sequence[PredictionSlice]
+    """
+    A list of predicted states of the ball at specific times in the future, assuming no collision with cars.
+    The beginning of the list is now, and the end is 6 seconds into the future.
+    The prediction is made at 120 Hz, resulting in 720 entries.
+    """
+
+    __match_args__ = (
+        "slices",
+    )
+
+    def __new__(
+        cls,
+        slices: Sequence[PredictionSlice] = [],
+    ) -> BallPredictionBuilder: ...
+    def __init__(
+        self,
+        slices: Sequence[PredictionSlice] = [],
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> BallPrediction:
+        """
+        Creates a new `BallPrediction` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `BallPrediction` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BoostPad:
+class BoostPad(Table):
     """
     Static information about a boost pad such as location and size.
     """
@@ -3435,10 +5785,70 @@ class BoostPad:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BoostPad:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> BoostPadBuilder:
+        """
+        Creates a mutable `BoostPadBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class BoostPadBuilder(Packable):
+    """
+    A mutable builder for `BoostPad`.
+    """
+
+    location: Vector3
+    """
+    The location of the boost pad.
+    """
+    is_full_boost: bool
+    """
+    Whether the boost pad provides a full tank of boost.
+    A big boost pad provides 100 boost and respawns in 10 seconds.
+    A small boost pad provides 12 boost and respawns in 4 seconds.
+    """
+
+    __match_args__ = (
+        "location",
+        "is_full_boost",
+    )
+
+    def __new__(
+        cls,
+        location: Vector3 = Vector3(),
+        is_full_boost: bool = False,
+    ) -> BoostPadBuilder: ...
+    def __init__(
+        self,
+        location: Vector3 = Vector3(),
+        is_full_boost: bool = False,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> BoostPad:
+        """
+        Creates a new `BoostPad` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `BoostPad` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class CarAnchor:
+class CarAnchor(Table, RelativeAnchor):
     """
     A RenderAnchor attached to a car.
     The local field allows for an offset in local coordinates taking the car's orientation into account.
@@ -3485,10 +5895,18 @@ class CarAnchor:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> CarAnchor:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class ControllableTeamInfo:
+class ControllableTeamInfo(Table, CoreMessage):
     """
     Server message with information about the cars that the client can control.
     Sent to bot clients as a response to ConnectionSettings.
@@ -3535,26 +5953,84 @@ class ControllableTeamInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> ControllableTeamInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> ControllableTeamInfoBuilder:
+        """
+        Creates a mutable `ControllableTeamInfoBuilder` with the values of this instance
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class CustomBot:
+class ControllableTeamInfoBuilder(Packable):
     """
-    A bot controlled by the RLBot framework.
+    A mutable builder for `ControllableTeamInfo`.
     """
 
-    name: str
-    """
-    Requested bot name. When match start, RLBot will ensure each bot has a unique name; bots with
-    duplicate names will be renamed with a suffix like `(2)`
-    """
-    root_dir: str
+    team: int
     """
-    The root directory of the bot and the working directory for the run command.
+    The assigned team for this client.
     """
-    run_command: str
+    controllables: Sequence[ControllableInfo]
     """
-    The console command that starts this bot.
+    The bots that RLBot will allow this client to control.
+    """
+
+    __match_args__ = (
+        "team",
+        "controllables",
+    )
+
+    def __new__(
+        cls,
+        team: int = 0,
+        controllables: Sequence[ControllableInfo] = [],
+    ) -> ControllableTeamInfoBuilder: ...
+    def __init__(
+        self,
+        team: int = 0,
+        controllables: Sequence[ControllableInfo] = [],
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> ControllableTeamInfo:
+        """
+        Creates a new `ControllableTeamInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `ControllableTeamInfo` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class CustomBot(Table, PlayerClass):
+    """
+    A bot controlled by the RLBot framework.
+    """
+
+    name: str
+    """
+    Requested bot name. When match start, RLBot will ensure each bot has a unique name; bots with
+    duplicate names will be renamed with a suffix like `(2)`
+    """
+    root_dir: str
+    """
+    The root directory of the bot and the working directory for the run command.
+    """
+    run_command: str
+    """
+    The console command that starts this bot.
     """
     loadout: PlayerLoadout | None
     """
@@ -3616,10 +6092,18 @@ class CustomBot:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> CustomBot:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class DesiredBallState:
+class DesiredBallState(Table):
     """
     A ball state with nullable components.
     Used for game state setting to define which part of a ball's state should change.
@@ -3655,10 +6139,18 @@ class DesiredBallState:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> DesiredBallState:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class DesiredMatchInfo:
+class DesiredMatchInfo(Table):
     """
     Match info with nullable components.
     Used for game state setting to define which part of the match info should change.
@@ -3705,10 +6197,18 @@ class DesiredMatchInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> DesiredMatchInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class GoalInfo:
+class GoalInfo(Table):
     """
     Static information about a goal on the field such as dimensions and location.
     More values can be found on https://wiki.rlbot.org/botmaking/useful-game-values/
@@ -3775,10 +6275,89 @@ class GoalInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> GoalInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> GoalInfoBuilder:
+        """
+        Creates a mutable `GoalInfoBuilder` with the values of this instance
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class MatchInfo:
+class GoalInfoBuilder(Packable):
+    """
+    A mutable builder for `GoalInfo`.
+    """
+
+    team_num: int
+    """
+    The index of the team that this goal belongs to.
+    """
+    location: Vector3
+    """
+    The center location of the goal.
+    """
+    direction: Vector3
+    """
+    The unit direction point away from the opening of the goal.
+    """
+    width: float
+    """
+    The width of the goal. 1785 uu wide on a standard field.
+    """
+    height: float
+    """
+    The height of the goal. 643 uu tall on a standard field.
+    """
+
+    __match_args__ = (
+        "team_num",
+        "location",
+        "direction",
+        "width",
+        "height",
+    )
+
+    def __new__(
+        cls,
+        team_num: int = 0,
+        location: Vector3 = Vector3(),
+        direction: Vector3 = Vector3(),
+        width: float = 0.0,
+        height: float = 0.0,
+    ) -> GoalInfoBuilder: ...
+    def __init__(
+        self,
+        team_num: int = 0,
+        location: Vector3 = Vector3(),
+        direction: Vector3 = Vector3(),
+        width: float = 0.0,
+        height: float = 0.0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> GoalInfo:
+        """
+        Creates a new `GoalInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `GoalInfo` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class MatchInfo(Table):
     """
     Information about the current match such as time and gravity.
     """
@@ -3877,10 +6456,122 @@ class MatchInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> MatchInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> MatchInfoBuilder:
+        """
+        Creates a mutable `MatchInfoBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class MatchInfoBuilder(Packable):
+    """
+    A mutable builder for `MatchInfo`.
+    """
+
+    seconds_elapsed: float
+    """
+    How many seconds have elapsed since the first game packet of the match.
+    This value ticks up even during kickoffs, replays, pause, etc.
+    """
+    game_time_remaining: float
+    """
+    Seconds remaining of the match.
+    This value ticks up instead of down during overtime or when the game duration mutator is set to Unlimited.
+    I.e. it matches the in-game timer at the top.
+    """
+    is_overtime: bool
+    """
+    True if the game is in overtime.
+    """
+    is_unlimited_time: bool
+    """
+    True if the game duration is set to Unlimited.
+    """
+    match_phase: MatchPhase
+    """
+    The current phase of the match, i.e. kickoff, replay, active, etc.
+    """
+    world_gravity_z: float
+    """
+    The current strength of gravity. Default is -650.
+    """
+    game_speed: float
+    """
+    Game speed multiplier. Regular game speed is 1.0.
+    """
+    last_spectated: int
+    """
+    Index of the player who was most recently a spectated by the host.
+    """
+    frame_num: int
+    """
+    Tracks the number of physics frames the game has computed.
+    May increase by more than one across consecutive packets.
+    Data type will roll over after 414 days at 120Hz.
+    """
+
+    __match_args__ = (
+        "seconds_elapsed",
+        "game_time_remaining",
+        "is_overtime",
+        "is_unlimited_time",
+        "match_phase",
+        "world_gravity_z",
+        "game_speed",
+        "last_spectated",
+        "frame_num",
+    )
+
+    def __new__(
+        cls,
+        seconds_elapsed: float = 0.0,
+        game_time_remaining: float = 0.0,
+        is_overtime: bool = False,
+        is_unlimited_time: bool = False,
+        match_phase: MatchPhase = MatchPhase(),
+        world_gravity_z: float = 0.0,
+        game_speed: float = 0.0,
+        last_spectated: int = 0,
+        frame_num: int = 0,
+    ) -> MatchInfoBuilder: ...
+    def __init__(
+        self,
+        seconds_elapsed: float = 0.0,
+        game_time_remaining: float = 0.0,
+        is_overtime: bool = False,
+        is_unlimited_time: bool = False,
+        match_phase: MatchPhase = MatchPhase(),
+        world_gravity_z: float = 0.0,
+        game_speed: float = 0.0,
+        last_spectated: int = 0,
+        frame_num: int = 0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> MatchInfo:
+        """
+        Creates a new `MatchInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `MatchInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PlayerConfiguration:
+class PlayerConfiguration(Table):
     """
     A PlayerConfiguration defines a player of a match.
     """
@@ -3934,10 +6625,18 @@ class PlayerConfiguration:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PlayerConfiguration:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PlayerInput:
+class PlayerInput(Table, InterfaceMessage):
     """
     A player index and the controller state of that player.
     Used to indicate what the player is doing this tick.
@@ -3977,36 +6676,88 @@ class PlayerInput:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PlayerInput:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> PlayerInputBuilder:
+        """
+        Creates a mutable `PlayerInputBuilder` with the values of this instance
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RenderGroup:
+class PlayerInputBuilder(Packable):
     """
-    A group of RenderMessages that are drawn and cleared together.
-    A RenderGroup will stay rendered until it is overriden or cleared.
-    The group is identified by a unique id.
-    A client can only clear its own RenderGroups.
+    A mutable builder for `PlayerInput`.
     """
 
-    render_messages: Sequence[RenderMessage]
-    """
-    The content of the RenderGroup.
-    """
-    id: int
-    """
-    The id of the RenderGroup.
-    """
+    player_index: int
+    controller_state: ControllerState
 
     __match_args__ = (
-        "render_messages",
-        "id",
+        "player_index",
+        "controller_state",
     )
 
     def __new__(
         cls,
-        render_messages: Sequence[RenderMessage] = [],
-        id: int = 0,
-    ) -> RenderGroup: ...
+        player_index: int = 0,
+        controller_state: ControllerState = ControllerState(),
+    ) -> PlayerInputBuilder: ...
+    def __init__(
+        self,
+        player_index: int = 0,
+        controller_state: ControllerState = ControllerState(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> PlayerInput:
+        """
+        Creates a new `PlayerInput` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `PlayerInput` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class RenderGroup(Table, InterfaceMessage):
+    """
+    A group of RenderMessages that are drawn and cleared together.
+    A RenderGroup will stay rendered until it is overriden or cleared.
+    The group is identified by a unique id.
+    A client can only clear its own RenderGroups.
+    """
+
+    render_messages: Sequence[RenderMessage]
+    """
+    The content of the RenderGroup.
+    """
+    id: int
+    """
+    The id of the RenderGroup.
+    """
+
+    __match_args__ = (
+        "render_messages",
+        "id",
+    )
+
+    def __new__(
+        cls,
+        render_messages: Sequence[RenderMessage] = [],
+        id: int = 0,
+    ) -> RenderGroup: ...
     def __init__(
         self,
         render_messages: Sequence[RenderMessage] = [],
@@ -4028,10 +6779,18 @@ class RenderGroup:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RenderGroup:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RenderMessage:
+class RenderMessage(Table):
     """
     A RenderMessage, describing a piece of debug rendering.
     """
@@ -4066,10 +6825,18 @@ class RenderMessage:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RenderMessage:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class RotatorPartial:
+class RotatorPartial(Table):
     """
     A rotator describing a rotation with nullable pith, yaw, and roll.
     Used for game state setting to define which part of a rotator should change.
@@ -4124,10 +6891,18 @@ class RotatorPartial:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> RotatorPartial:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class SetLoadout:
+class SetLoadout(Table, InterfaceMessage):
     """
     A client message to change the loadout of a car.
     If sent before the ready message, this simply sets the loadout of the car.
@@ -4175,10 +6950,18 @@ class SetLoadout:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> SetLoadout:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Touch:
+class Touch(Table):
     """
     Information about a ball touch.
     """
@@ -4237,10 +7020,82 @@ class Touch:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Touch:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> TouchBuilder:
+        """
+        Creates a mutable `TouchBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class TouchBuilder(Packable):
+    """
+    A mutable builder for `Touch`.
+    """
+
+    game_seconds: float
+    """
+    Seconds that had elapsed in the game when the touch occurred.
+    """
+    location: Vector3
+    """
+    The point of contact for the touch.
+    """
+    normal: Vector3
+    """
+    The direction of the touch as a unit vector pointing from the point of contact towards the center of the ball.
+    """
+    ball_index: int
+    """
+    The index of the ball that was touched (in case there are multiple balls).
+    """
+
+    __match_args__ = (
+        "game_seconds",
+        "location",
+        "normal",
+        "ball_index",
+    )
+
+    def __new__(
+        cls,
+        game_seconds: float = 0.0,
+        location: Vector3 = Vector3(),
+        normal: Vector3 = Vector3(),
+        ball_index: int = 0,
+    ) -> TouchBuilder: ...
+    def __init__(
+        self,
+        game_seconds: float = 0.0,
+        location: Vector3 = Vector3(),
+        normal: Vector3 = Vector3(),
+        ball_index: int = 0,
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> Touch:
+        """
+        Creates a new `Touch` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `Touch` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Vector3Partial:
+class Vector3Partial(Table):
     """
     A 3D vector where x, y, and z can be null.
     Used for game state setting to define which part of a vector should change.
@@ -4285,10 +7140,25 @@ class Vector3Partial:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Vector3Partial:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def __mul__(self, scalar: float) -> Vector3Partial: ...
+    def __rmul__(self, scalar: float) -> Vector3Partial: ...
+    def __truediv__(self, scalar: float) -> Vector3Partial: ...
+    def __neg__(self) -> Vector3Partial: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> float | None: ...
+    def __iter__(self) -> Iterator[float | None]: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class BallInfo:
+class BallInfo(Table):
     """
     Information about a ball.
     """
@@ -4333,10 +7203,68 @@ class BallInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> BallInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> BallInfoBuilder:
+        """
+        Creates a mutable `BallInfoBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class BallInfoBuilder(Packable):
+    """
+    A mutable builder for `BallInfo`.
+    """
+
+    physics: Physics
+    """
+    The physical state of the ball.
+    """
+    shape: BoxShape | CylinderShape | SphereShape
+    """
+    The collision shape of the ball.
+    """
+
+    __match_args__ = (
+        "physics",
+        "shape",
+    )
+
+    def __new__(
+        cls,
+        physics: Physics = Physics(),
+        shape: BoxShape | CylinderShape | SphereShape = BoxShape(),
+    ) -> BallInfoBuilder: ...
+    def __init__(
+        self,
+        physics: Physics = Physics(),
+        shape: BoxShape | CylinderShape | SphereShape = BoxShape(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> BallInfo:
+        """
+        Creates a new `BallInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `BallInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class DesiredCarState:
+class DesiredCarState(Table):
     """
     A car state with nullable components.
     Used for game state setting to define which part of a car's state should change.
@@ -4376,10 +7304,18 @@ class DesiredCarState:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> DesiredCarState:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class FieldInfo:
+class FieldInfo(Table, CoreMessage):
     """
     Static information about the field.
     Sent to bots, scripts, etc. upon connecting.
@@ -4428,10 +7364,81 @@ class FieldInfo:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> FieldInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> FieldInfoBuilder:
+        """
+        Creates a mutable `FieldInfoBuilder` with the values of this instance
+        """
+
+    @staticmethod
+    def standard_soccar() -> FieldInfo:
+        """
+        The field info of the standard soccar arena, like DFH Stadium,
+        with its 34 boost pads and the two goals
+        """
+    def mirrored(self) -> FieldInfo:
+        """
+        A copy of the field info from the other team's perspective, like `GamePacket.mirrored`.
+        The boost pads are in reverse order, so they stay in the same order as the mirrored `GamePacket.boost_pads`
+        """
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class FieldInfoBuilder(Packable):
+    """
+    A mutable builder for `FieldInfo`.
+    """
+
+    boost_pads: Sequence[BoostPad]
+    """
+    Static information about boost pads on the field.
+    The dynamic information is found in the GamePacket
+    The boost pads are ordered by y-coordinate and then x-coordinate.
+    """
+    goals: Sequence[GoalInfo]
+    """
+    Information about the goals on the field.
+    """
+
+    __match_args__ = (
+        "boost_pads",
+        "goals",
+    )
+
+    def __new__(
+        cls,
+        boost_pads: Sequence[BoostPad] = [],
+        goals: Sequence[GoalInfo] = [],
+    ) -> FieldInfoBuilder: ...
+    def __init__(
+        self,
+        boost_pads: Sequence[BoostPad] = [],
+        goals: Sequence[GoalInfo] = [],
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> FieldInfo:
+        """
+        Creates a new `FieldInfo` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `FieldInfo` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Line3D:
+class Line3D(Table, RenderType):
     """
     A RenderMessage for a line in 3D space between two RenderAnchors.
     """
@@ -4474,10 +7481,18 @@ class Line3D:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Line3D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PolyLine3D:
+class PolyLine3D(Table, RenderType):
     """
     A RenderMessage for a line in 3D space going through a series of points.
     """
@@ -4516,10 +7531,38 @@ class PolyLine3D:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PolyLine3D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    @staticmethod
+    def from_points(points: Any, color: Color | None = None) -> PolyLine3D:
+        """
+        Creates a poly line from an N×3 array (or a flat array) of float32s or float64s
+        (anything that implements the buffer protocol, like a numpy array).
+        This still creates a `Vector3` for every point, so `pack_points` is faster for long lines.
+
+        :raises TypeError: If `points` isn't an array of float32s or float64s
+        :raises ValueError: If `points` doesn't have 3 numbers per row
+        """
+    @staticmethod
+    def pack_points(points: Any, color: Color | None = None) -> bytes:
+        """
+        Packs a poly line from an N×3 array (or a flat array) of float32s or float64s,
+        which is read straight into the flat representation without creating a `Vector3` per point.
+        The bytes are the same as `PolyLine3D.from_points(points, color).pack()`.
+
+        :raises TypeError: If `points` isn't an array of float32s or float64s
+        :raises ValueError: If `points` doesn't have 3 numbers per row
+        """
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PsyonixBot:
+class PsyonixBot(Table, PlayerClass):
     """
     A Psyonix bot, e.g. All Star bot.
     """
@@ -4570,10 +7613,18 @@ class PsyonixBot:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> PsyonixBot:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Rect2D:
+class Rect2D(Table, RenderType):
     """
     A RenderMessage for a rectangle in 2D space.
     Note that the position and size is given in screen-space coordinates.
@@ -4654,10 +7705,18 @@ class Rect2D:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Rect2D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class String2D:
+class String2D(Table, RenderType):
     """
     A RenderMessage for text in 2D space.
     Note that the position is given in screen-space coordinates.
@@ -4746,10 +7805,18 @@ class String2D:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
+    def replace(self, **changes: Any) -> String2D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
 
-class DesiredGameState:
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class DesiredGameState(Table, InterfaceMessage):
     """
     A game state with nullable subcomponents.
     Used for game state setting to define which part of the game should change.
@@ -4811,10 +7878,66 @@ class DesiredGameState:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> DesiredGameState:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    @staticmethod
+    def from_packet(
+        packet: GamePacket,
+        include: (
+            Literal["balls", "cars", "boost", "match_info"]
+            | Iterable[Literal["balls", "cars", "boost", "match_info"]]
+            | None
+        ) = None,
+    ) -> DesiredGameState:
+        """
+        Creates a desired game state that restores everything in `packet`.
+        `include` limits it to some of `"balls"`, `"cars"`, `"boost"` and `"match_info"`
+
+        :raises ValueError: If `include` contains an unknown option
+        """
+    @staticmethod
+    def diff(
+        current: GamePacket,
+        target: GamePacket | DesiredGameState,
+        tolerance: float = 0.01,
+    ) -> DesiredGameState:
+        """
+        Creates the smallest desired game state that turns `current` into `target`.
+        Only values that differ by more than `tolerance` are set,
+        and balls and cars that don't need to change are left empty.
+        `tolerance` is intentionally shared by every value regardless of its unit,
+        since it's meant to skip float rounding rather than small but real changes
+
+        :raises TypeError: If `target` isn't a `GamePacket` or `DesiredGameState`
+        """
+    @staticmethod
+    def merge(
+        *states: DesiredGameState,
+        conflict: Literal["last", "error"] = "last",
+    ) -> DesiredGameState:
+        """
+        Combines `states` into one, where later states take priority.
+        Balls and cars are merged by index, and console commands are concatenated.
+        With `conflict="error"`, setting a value to two different values raises a `ValueError`
+
+        :raises ValueError: If `conflict` is `"error"` and two states conflict
+        """
+    def mirrored(self) -> DesiredGameState:
+        """
+        A copy of the state from the other team's perspective, like `GamePacket.mirrored`.
+        Mirroring is its own inverse up to the float32 rounding of yaws, so this also turns a state made in the mirrored frame back into the real one.
+        Only the values that are set are changed
+        """
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class Rect3D:
+class Rect3D(Table, RenderType):
     """
     A RenderMessage for a rectangle in 3D space.
     Note that the size is given in screen-space sizes.
@@ -4888,10 +8011,18 @@ class Rect3D:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> Rect3D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class String3D:
+class String3D(Table, RenderType):
     """
     A RenderMessage for text in 3D space.
     """
@@ -4919,42 +8050,439 @@ class String3D:
     """
     h_align: TextHAlign
     """
-    The horizontal alignment of the text.
+    The horizontal alignment of the text.
+    """
+    v_align: TextVAlign
+    """
+    The vertical alignment of the text.
+    """
+
+    __match_args__ = (
+        "text",
+        "anchor",
+        "scale",
+        "foreground",
+        "background",
+        "h_align",
+        "v_align",
+    )
+
+    def __new__(
+        cls,
+        text: str = "",
+        anchor: RenderAnchor = RenderAnchor(),
+        scale: float = 0.0,
+        foreground: Color = Color(),
+        background: Color = Color(),
+        h_align: TextHAlign = TextHAlign(),
+        v_align: TextVAlign = TextVAlign(),
+    ) -> String3D: ...
+    def __init__(
+        self,
+        text: str = "",
+        anchor: RenderAnchor = RenderAnchor(),
+        scale: float = 0.0,
+        foreground: Color = Color(),
+        background: Color = Color(),
+        h_align: TextHAlign = TextHAlign(),
+        v_align: TextVAlign = TextVAlign(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def pack(self) -> bytes:
+        """
+        Serializes this instance into a byte array
+        """
+
+    @staticmethod
+    def unpack(data: bytes) -> String3D:
+        """
+        Deserializes the data into a new instance
+
+        :raises InvalidFlatbuffer: If the `data` is invalid for this type
+        """
+
+    def replace(self, **changes: Any) -> String3D:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class GamePacket(Table, CoreMessage):
+    """
+    A packet of data from the game.
+    Is is sent every tick to bots, scripts, etc.
+    Static data is found in the FieldInfo.
+    """
+
+    players: Sequence[PlayerInfo]
+    """
+    The current state of all players and their cars.
+    """
+    boost_pads: Sequence[BoostPadState]
+    """
+    The current state of all boost pads.
+    The static information about boost pads are found in the FieldInfo.
+    The boost pads are ordered by y-coordinate and then x-coordinate.
+    """
+    balls: Sequence[BallInfo]
+    """
+    The current state of all balls.
+    """
+    match_info: MatchInfo
+    """
+    The current state of the match such as timers and gravity.
+    """
+    teams: Sequence[TeamInfo]
+    """
+    The current state of teams, i.e. the team scores.
+    """
+
+    __match_args__ = (
+        "players",
+        "boost_pads",
+        "balls",
+        "match_info",
+        "teams",
+    )
+
+    def __new__(
+        cls,
+        players: Sequence[PlayerInfo] = [],
+        boost_pads: Sequence[BoostPadState] = [],
+        balls: Sequence[BallInfo] = [],
+        match_info: MatchInfo = MatchInfo(),
+        teams: Sequence[TeamInfo] = [],
+    ) -> GamePacket: ...
+    def __init__(
+        self,
+        players: Sequence[PlayerInfo] = [],
+        boost_pads: Sequence[BoostPadState] = [],
+        balls: Sequence[BallInfo] = [],
+        match_info: MatchInfo = MatchInfo(),
+        teams: Sequence[TeamInfo] = [],
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def pack(self) -> bytes:
+        """
+        Serializes this instance into a byte array
+        """
+
+    @staticmethod
+    def unpack(data: bytes) -> GamePacket:
+        """
+        Deserializes the data into a new instance
+
+        :raises InvalidFlatbuffer: If the `data` is invalid for this type
+        """
+
+    def replace(self, **changes: Any) -> GamePacket:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> GamePacketBuilder:
+        """
+        Creates a mutable `GamePacketBuilder` with the values of this instance
+        """
+
+    def player_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every player as an N×K float32 array (a `memoryview`),
+        which `numpy.asarray` can wrap without copying.
+        Fields are paths in `PlayerInfo` like `"boost"` or `"physics.location"`,
+        where structs are expanded into their numbers (e.g. x, y and z).
+        By default, the 12 numbers of `physics` are exported.
+        Booleans become 0 or 1, and fields of a `latest_touch` that isn't set become NaN.
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    def ball_array(self, fields: Sequence[str] | None = None) -> memoryview:
+        """
+        Exports the `fields` of every ball as an N×K float32 array (a `memoryview`),
+        like `player_array` does for the players
+
+        :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+        """
+    def mirrored(self) -> GamePacket:
+        """
+        A copy of the packet from the other team's perspective, with the field rotated 180° around the z axis.
+        Locations, velocities and angular velocities have their x and y negated,
+        yaws are turned around, and the boost pads are in reverse order
+        """
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class GamePacketBuilder(Packable):
+    """
+    A mutable builder for `GamePacket`.
+    """
+
+    players: Sequence[PlayerInfo]
+    """
+    The current state of all players and their cars.
+    """
+    boost_pads: Sequence[BoostPadState]
+    """
+    The current state of all boost pads.
+    The static information about boost pads are found in the FieldInfo.
+    The boost pads are ordered by y-coordinate and then x-coordinate.
+    """
+    balls: Sequence[BallInfo]
+    """
+    The current state of all balls.
+    """
+    match_info: MatchInfo
+    """
+    The current state of the match such as timers and gravity.
+    """
+    teams: Sequence[TeamInfo]
+    """
+    The current state of teams, i.e. the team scores.
+    """
+
+    __match_args__ = (
+        "players",
+        "boost_pads",
+        "balls",
+        "match_info",
+        "teams",
+    )
+
+    def __new__(
+        cls,
+        players: Sequence[PlayerInfo] = [],
+        boost_pads: Sequence[BoostPadState] = [],
+        balls: Sequence[BallInfo] = [],
+        match_info: MatchInfo = MatchInfo(),
+        teams: Sequence[TeamInfo] = [],
+    ) -> GamePacketBuilder: ...
+    def __init__(
+        self,
+        players: Sequence[PlayerInfo] = [],
+        boost_pads: Sequence[BoostPadState] = [],
+        balls: Sequence[BallInfo] = [],
+        match_info: MatchInfo = MatchInfo(),
+        teams: Sequence[TeamInfo] = [],
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> GamePacket:
+        """
+        Creates a new `GamePacket` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `GamePacket` into a byte array
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class PlayerInfo(Table):
+    """
+    A collection of information about a player and their car.
+    """
+
+    physics: Physics
+    """
+    The physical state of the player's car.
+    """
+    score_info: ScoreInfo
+    """
+    The various scores of this player, e.g. those on the leaderboard.
+    """
+    hitbox: BoxShape
+    """
+    The hitbox of the player's car.
+    Note that the hitbox is not centered at the cars location.
+    See the hitbox offset.
+    """
+    hitbox_offset: Vector3
+    """
+    The center of the hitbox in local coordinates.
+    """
+    latest_touch: Touch | None
+    """
+    Information about the latest touch with a ball.
+    Is null if the player has yet to touch the ball.
+    """
+    air_state: AirState
+    """
+    Whether the player's car is on the ground or in the air, and what jump/dodging forces currently affects the car.
+    """
+    dodge_timeout: float
+    """
+    How long until the player cannot dodge/double jump anymore.
+    The value is -1 while on ground or when airborne for too long after jumping.
+    A dodge/double jump is possible for 1.25 seconds after the first jump plus
+    up to an additional 0.2 seconds depending how long the jump button was pressed for the first jump.
+    Note that falling off a surface instead of jumping, does not activate the dodge timeout, and making a dodge/double jump possible indefinitely.
+    This is commonly known as a flip reset.
+    The car is holding a flip reset if `air_state == InAir && !has_jumped && !has_double_jumped && !has_dodged`.
+    """
+    demolished_timeout: float
+    """
+    How long until the player is not demolished anymore.
+    The value is -1 if while not demolished.
+    """
+    is_supersonic: bool
+    """
+    Whether the player's car is moving at supersonic speed and can demolish.
+    """
+    is_bot: bool
+    """
+    Whether the player is a bot or a human.
+    """
+    name: str
+    """
+    The name of the player as it appears in game, i.e. possibly appended with "(2)".
+    The original name can be found in the match configuration.
+    """
+    team: int
+    """
+    The team of the player.
+    """
+    boost: float
+    """
+    The current boost, from 0 to 100.
+    """
+    player_id: int
+    """
+    The id of the player.
+    This value is mostly used internally to keep track of participants in the match.
+    The id can be used to find the corresponding PlayerConfiguration in the MatchConfiguration.
+    """
+    accolades: Sequence[str]
+    """
+    Events from the latest tick involving this player. Possible values include:
+    Win, Loss, TimePlayed;
+    Shot, Assist, Center, Clear, PoolShot;
+    Goal, AerialGoal, BicycleGoal, BulletGoal, BackwardsGoal, LongGoal, OvertimeGoal, TurtleGoal;
+    AerialHit, BicycleHit, BulletHit, JuggleHit, FirstTouch, BallHit;
+    Save, EpicSave, FreezeSave;
+    HatTrick, Savior, Playmaker, MVP;
+    FastestGoal, SlowestGoal, FurthestGoal, OwnGoal;
+    MostBallTouches, FewestBallTouches, MostBoostPickups, FewestBoostPickups, BoostPickups;
+    CarTouches, Demolition, Demolish;
+    LowFive, HighFive;
+    Note that the list clears every tick.
+    """
+    last_input: ControllerState
+    """
+    The last controller input from this player.
+    """
+    has_jumped: bool
+    """
+    True if the player has jumped into the air.
+    See dodge_timeout to know if a dodge/double jump is temporarily available.
+    Note that falling off a surface instead of jumping, does not activate the dodge timeout, and making a dodge/double jump possible indefinitely.
+    This is commonly known as a flip reset.
+    The car is holding a flip reset if `air_state == InAir && !has_jumped && !has_double_jumped && !has_dodged`.
+    """
+    has_double_jumped: bool
+    """
+    True if the player has doubled jumped since it left the ground. False while on the ground.
+    """
+    has_dodged: bool
+    """
+    True if the player has dodged since it left the ground. False while the ground.
+    """
+    dodge_elapsed: float
+    """
+    The time in seconds since the last dodge was initiated.
+    Resets to 0 when the player lands on the ground.
     """
-    v_align: TextVAlign
+    dodge_dir: Vector2
     """
-    The vertical alignment of the text.
+    The unit direction of the latest dodge.
+    The value will be (0,0) if it was a stall.
     """
 
     __match_args__ = (
-        "text",
-        "anchor",
-        "scale",
-        "foreground",
-        "background",
-        "h_align",
-        "v_align",
+        "physics",
+        "score_info",
+        "hitbox",
+        "hitbox_offset",
+        "latest_touch",
+        "air_state",
+        "dodge_timeout",
+        "demolished_timeout",
+        "is_supersonic",
+        "is_bot",
+        "name",
+        "team",
+        "boost",
+        "player_id",
+        "accolades",
+        "last_input",
+        "has_jumped",
+        "has_double_jumped",
+        "has_dodged",
+        "dodge_elapsed",
+        "dodge_dir",
     )
 
     def __new__(
         cls,
-        text: str = "",
-        anchor: RenderAnchor = RenderAnchor(),
-        scale: float = 0.0,
-        foreground: Color = Color(),
-        background: Color = Color(),
-        h_align: TextHAlign = TextHAlign(),
-        v_align: TextVAlign = TextVAlign(),
-    ) -> String3D: ...
+        physics: Physics = Physics(),
+        score_info: ScoreInfo = ScoreInfo(),
+        hitbox: BoxShape = BoxShape(),
+        hitbox_offset: Vector3 = Vector3(),
+        latest_touch: Touch | None = None,
+        air_state: AirState = AirState(),
+        dodge_timeout: float = 0.0,
+        demolished_timeout: float = 0.0,
+        is_supersonic: bool = False,
+        is_bot: bool = False,
+        name: str = "",
+        team: int = 0,
+        boost: float = 0.0,
+        player_id: int = 0,
+        accolades: Sequence[str] = [],
+        last_input: ControllerState = ControllerState(),
+        has_jumped: bool = False,
+        has_double_jumped: bool = False,
+        has_dodged: bool = False,
+        dodge_elapsed: float = 0.0,
+        dodge_dir: Vector2 = Vector2(),
+    ) -> PlayerInfo: ...
     def __init__(
         self,
-        text: str = "",
-        anchor: RenderAnchor = RenderAnchor(),
-        scale: float = 0.0,
-        foreground: Color = Color(),
-        background: Color = Color(),
-        h_align: TextHAlign = TextHAlign(),
-        v_align: TextVAlign = TextVAlign(),
+        physics: Physics = Physics(),
+        score_info: ScoreInfo = ScoreInfo(),
+        hitbox: BoxShape = BoxShape(),
+        hitbox_offset: Vector3 = Vector3(),
+        latest_touch: Touch | None = None,
+        air_state: AirState = AirState(),
+        dodge_timeout: float = 0.0,
+        demolished_timeout: float = 0.0,
+        is_supersonic: bool = False,
+        is_bot: bool = False,
+        name: str = "",
+        team: int = 0,
+        boost: float = 0.0,
+        player_id: int = 0,
+        accolades: Sequence[str] = [],
+        last_input: ControllerState = ControllerState(),
+        has_jumped: bool = False,
+        has_double_jumped: bool = False,
+        has_dodged: bool = False,
+        dodge_elapsed: float = 0.0,
+        dodge_dir: Vector2 = Vector2(),
     ) -> None:
         """
         NOTE: All field initialization before `__init__`, inside of `__new__`.
@@ -4965,92 +8493,62 @@ class String3D:
         """
 
     @staticmethod
-    def unpack(data: bytes) -> String3D:
+    def unpack(data: bytes) -> PlayerInfo:
         """
         Deserializes the data into a new instance
 
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
-    def __str__(self) -> str: ...
-    def __repr__(self) -> str: ...
-
-class GamePacket:
-    """
-    A packet of data from the game.
-    Is is sent every tick to bots, scripts, etc.
-    Static data is found in the FieldInfo.
-    """
+    def replace(self, **changes: Any) -> PlayerInfo:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
 
-    players: Sequence[PlayerInfo]
-    """
-    The current state of all players and their cars.
-    """
-    boost_pads: Sequence[BoostPadState]
-    """
-    The current state of all boost pads.
-    The static information about boost pads are found in the FieldInfo.
-    The boost pads are ordered by y-coordinate and then x-coordinate.
-    """
-    balls: Sequence[BallInfo]
-    """
-    The current state of all balls.
-    """
-    match_info: MatchInfo
-    """
-    The current state of the match such as timers and gravity.
-    """
-    teams: Sequence[TeamInfo]
-    """
-    The current state of teams, i.e. the team scores.
-    """
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
 
-    __match_args__ = (
-        "players",
-        "boost_pads",
-        "balls",
-        "match_info",
-        "teams",
-    )
+    def to_builder(self) -> PlayerInfoBuilder:
+        """
+        Creates a mutable `PlayerInfoBuilder` with the values of this instance
+        """
 
-    def __new__(
-        cls,
-        players: Sequence[PlayerInfo] = [],
-        boost_pads: Sequence[BoostPadState] = [],
-        balls: Sequence[BallInfo] = [],
-        match_info: MatchInfo = MatchInfo(),
-        teams: Sequence[TeamInfo] = [],
-    ) -> GamePacket: ...
-    def __init__(
-        self,
-        players: Sequence[PlayerInfo] = [],
-        boost_pads: Sequence[BoostPadState] = [],
-        balls: Sequence[BallInfo] = [],
-        match_info: MatchInfo = MatchInfo(),
-        teams: Sequence[TeamInfo] = [],
-    ) -> None:
+    def can_jump(self) -> bool:
         """
-        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        Whether the car can jump, which is when it's on the ground and not demolished.
+        A `demolished_timeout` of 0 or more counts as demolished, so a `PlayerInfo()` with the default of 0 can't jump
         """
-    def pack(self) -> bytes:
+    def can_dodge(self) -> bool:
         """
-        Serializes this instance into a byte array
+        Whether the car can dodge or double jump,
+        which is until `dodge_timeout` runs out after the first jump, or until landing with a flip reset.
+        Like `can_jump`, it's false while `demolished_timeout` is 0 or more
         """
-
-    @staticmethod
-    def unpack(data: bytes) -> GamePacket:
+    def dodge_time_remaining(self) -> float:
         """
-        Deserializes the data into a new instance
-
-        :raises InvalidFlatbuffer: If the `data` is invalid for this type
+        The seconds left to dodge or double jump, 0 if the car can't,
+        or infinity if it's holding a flip reset
+        """
+    def hitbox_corners(self) -> list[Vector3]:
+        """
+        The 8 corners of the hitbox in world coordinates,
+        with the front corners first, then the right corners before the left, then the top corners before the bottom
+        """
+    def closest_point(self, ball: BallInfo) -> Vector3:
+        """
+        The closest point of the hitbox to the ball's `CollisionShape`,
+        which is inside the ball if they overlap
+        """
+    def distance_to(self, ball: BallInfo) -> float:
+        """
+        The distance between the hitbox and the ball's `CollisionShape`, or 0 if they overlap
         """
-
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class PlayerInfo:
+class PlayerInfoBuilder(Packable):
     """
-    A collection of information about a player and their car.
+    A mutable builder for `PlayerInfo`.
     """
 
     physics: Physics
@@ -5215,7 +8713,7 @@ class PlayerInfo:
         has_dodged: bool = False,
         dodge_elapsed: float = 0.0,
         dodge_dir: Vector2 = Vector2(),
-    ) -> PlayerInfo: ...
+    ) -> PlayerInfoBuilder: ...
     def __init__(
         self,
         physics: Physics = Physics(),
@@ -5243,23 +8741,20 @@ class PlayerInfo:
         """
         NOTE: All field initialization before `__init__`, inside of `__new__`.
         """
-    def pack(self) -> bytes:
+    def build(self) -> PlayerInfo:
         """
-        Serializes this instance into a byte array
+        Creates a new `PlayerInfo` from the current values of this builder
         """
 
-    @staticmethod
-    def unpack(data: bytes) -> PlayerInfo:
+    def pack(self) -> bytes:
         """
-        Deserializes the data into a new instance
-
-        :raises InvalidFlatbuffer: If the `data` is invalid for this type
+        Serializes this builder as a `PlayerInfo` into a byte array
         """
 
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class MutatorSettings:
+class MutatorSettings(Table):
     """
     All mutators options.
     """
@@ -5515,10 +9010,18 @@ class MutatorSettings:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> MutatorSettings:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class CorePacket:
+class CorePacket(Table):
     """
     Packet containing a CoreMessage
     """
@@ -5553,10 +9056,58 @@ class CorePacket:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> CorePacket:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> CorePacketBuilder:
+        """
+        Creates a mutable `CorePacketBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class CorePacketBuilder(Packable):
+    """
+    A mutable builder for `CorePacket`.
+    """
+
+    message: BallPrediction | ControllableTeamInfo | DisconnectSignal | FieldInfo | GamePacket | MatchComm | MatchConfiguration | PingRequest | PingResponse | RenderingStatus
+
+    __match_args__ = (
+        "message",
+    )
+
+    def __new__(
+        cls,
+        message: BallPrediction | ControllableTeamInfo | DisconnectSignal | FieldInfo | GamePacket | MatchComm | MatchConfiguration | PingRequest | PingResponse | RenderingStatus = BallPrediction(),
+    ) -> CorePacketBuilder: ...
+    def __init__(
+        self,
+        message: BallPrediction | ControllableTeamInfo | DisconnectSignal | FieldInfo | GamePacket | MatchComm | MatchConfiguration | PingRequest | PingResponse | RenderingStatus = BallPrediction(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> CorePacket:
+        """
+        Creates a new `CorePacket` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `CorePacket` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
-class InterfacePacket:
+class InterfacePacket(Table):
     """
     Packet containing a InterfaceMessage
     """
@@ -5591,5 +9142,53 @@ class InterfacePacket:
         :raises InvalidFlatbuffer: If the `data` is invalid for this type
         """
 
+    def replace(self, **changes: Any) -> InterfacePacket:
+        """
+        Creates a copy of this instance with the given fields replaced.
+        Nested fields can be replaced by using a dotted path as the key.
+
+        :raises TypeError: If a field in `changes` doesn't exist
+        """
+
+    def to_builder(self) -> InterfacePacketBuilder:
+        """
+        Creates a mutable `InterfacePacketBuilder` with the values of this instance
+        """
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+class InterfacePacketBuilder(Packable):
+    """
+    A mutable builder for `InterfacePacket`.
+    """
+
+    message: ConnectionSettings | DesiredGameState | DisconnectSignal | InitComplete | MatchComm | MatchConfiguration | PingRequest | PingResponse | PlayerInput | RemoveRenderGroup | RenderGroup | RenderingStatus | SetLoadout | StartCommand | StopCommand
+
+    __match_args__ = (
+        "message",
+    )
+
+    def __new__(
+        cls,
+        message: ConnectionSettings | DesiredGameState | DisconnectSignal | InitComplete | MatchComm | MatchConfiguration | PingRequest | PingResponse | PlayerInput | RemoveRenderGroup | RenderGroup | RenderingStatus | SetLoadout | StartCommand | StopCommand = ConnectionSettings(),
+    ) -> InterfacePacketBuilder: ...
+    def __init__(
+        self,
+        message: ConnectionSettings | DesiredGameState | DisconnectSignal | InitComplete | MatchComm | MatchConfiguration | PingRequest | PingResponse | PlayerInput | RemoveRenderGroup | RenderGroup | RenderingStatus | SetLoadout | StartCommand | StopCommand = ConnectionSettings(),
+    ) -> None:
+        """
+        NOTE: All field initialization before `__init__`, inside of `__new__`.
+        """
+    def build(self) -> InterfacePacket:
+        """
+        Creates a new `InterfacePacket` from the current values of this builder
+        """
+
+    def pack(self) -> bytes:
+        """
+        Serializes this builder as a `InterfacePacket` into a byte array
+        """
+
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
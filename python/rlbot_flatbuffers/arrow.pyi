@@ -0,0 +1,47 @@
+"""
+Exports sequences of tables and structs to Apache Arrow.
+
+Every item becomes a row, and nested fields are flattened into columns named by their path
+(e.g. `players[0].physics.location.x`).
+Lists get a column per index, which is null for rows with shorter lists.
+Unions and lists of strings or bytes are skipped.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Iterable
+
+class RecordBatch:
+    """
+    A table of flattened items, which can be imported by any library that supports
+    the Arrow PyCapsule interface (e.g. `pyarrow.table(batch)` or `polars.DataFrame(batch)`)
+    """
+
+    num_rows: int
+    column_names: list[str]
+
+    def column(self, name: str) -> list[float | int | bool | str | None]:
+        """
+        The values of a column, with `None` for rows that don't have a value
+
+        :raises KeyError: If there's no column with that name
+        """
+    def __len__(self) -> int: ...
+    def __arrow_c_schema__(self) -> Any: ...
+    def __arrow_c_array__(self, requested_schema: Any | None = None) -> tuple[Any, Any]:
+        """
+        Exports the table as a struct array.
+        `requested_schema` is ignored, which the protocol allows
+        """
+    def __arrow_c_stream__(self, requested_schema: Any | None = None) -> Any:
+        """
+        Exports the table as a stream with a single record batch.
+        `requested_schema` is ignored, which the protocol allows
+        """
+
+def record_batch(items: Iterable[Any]) -> RecordBatch:
+    """
+    Flattens `items` (e.g. a list of `GamePacket`s) into a record batch with a row per item
+
+    :raises TypeError: If the items aren't all the same generated table or struct
+    """
@@ -0,0 +1,90 @@
+"""
+Finds the earliest point in a ball prediction that a car can reach.
+
+The car is simulated driving in a straight line towards each slice at the same 120Hz as the ball prediction.
+It starts with only the part of its velocity that's already towards the ball,
+since turning, jumping and the car's current heading aren't modeled.
+"""
+
+from __future__ import annotations
+
+from . import BallPrediction, Physics, PlayerInfo, PredictionSlice, Vector3
+
+class DriveModel:
+    """
+    A simple model of a car driving in a straight line,
+    with constant accelerations from the throttle and from boosting
+    """
+
+    max_speed: float
+    """
+    The top speed while boosting
+    """
+    throttle_max_speed: float
+    """
+    The top speed that the throttle alone can reach
+    """
+    throttle_acceleration: float
+    boost_acceleration: float
+    """
+    The acceleration that's added while boosting
+    """
+    boost_per_second: float
+    """
+    The boost used for each second of boosting
+    """
+    use_boost: bool
+    reach: float
+    """
+    The distance between the center of the car and the center of the ball when they touch
+    """
+
+    def __init__(
+        self,
+        max_speed: float = 2300.0,
+        throttle_max_speed: float = 1410.0,
+        throttle_acceleration: float = 1600.0,
+        boost_acceleration: float = 991.666,
+        boost_per_second: float = 33.333,
+        use_boost: bool = True,
+        reach: float = 150.2537,
+    ) -> None: ...
+
+class Intercept:
+    """
+    The earliest slice of a ball prediction that a car can reach
+    """
+
+    index: int
+    """
+    The index of the slice in `BallPrediction.slices`
+    """
+    slice: PredictionSlice
+    time: float
+    """
+    The seconds from the first slice until the intercept
+    """
+    required_speed: float
+    """
+    The average speed the car needs to arrive in time
+    """
+    arrival_location: Vector3
+    """
+    Where the car arrives, `reach` short of the ball on the line from the car
+    """
+
+def find_intercept(
+    car: PlayerInfo | Physics,
+    ball_prediction: BallPrediction,
+    model: DriveModel | None = None,
+    boost: float | None = None,
+    max_height: float | None = None,
+) -> Intercept | None:
+    """
+    Finds the earliest slice of `ball_prediction` that the car can reach, or `None` if there isn't one.
+    `car` is a `PlayerInfo`, or a `Physics` with `boost` (100 by default),
+    and slices where the ball is higher than `max_height` are skipped.
+    The car starts with only the part of its velocity that's towards each slice
+
+    :raises TypeError: If `car` isn't a `PlayerInfo` or `Physics`
+    """
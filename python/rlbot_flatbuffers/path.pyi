@@ -0,0 +1,37 @@
+"""
+Generic access to the fields of generated types with paths like `players[2].physics.location.x`.
+"""
+
+from __future__ import annotations
+
+from typing import Any
+
+class Walk:
+    def __iter__(self) -> Walk: ...
+    def __next__(self) -> tuple[str, Any]: ...
+
+def get(obj: Any, path: str) -> Any:
+    """
+    Gets the value at `path` in `obj`, e.g. `get(packet, "players[2].physics.location.x")`
+
+    :raises ValueError: If the `path` is malformed
+    :raises AttributeError: If a field in the `path` doesn't exist
+    :raises IndexError: If an index in the `path` is out of range
+    :raises TypeError: If the `path` indexes into something that isn't a list
+    """
+
+def set(obj: Any, path: str, value: Any) -> None:
+    """
+    Sets the value at `path` in `obj`, e.g. `set(state, "match_info.game_speed", 2)`
+
+    :raises TypeError: If the value is owned by a frozen type, use `replace` or `to_builder` instead
+    :raises ValueError: If the `path` is malformed
+    :raises AttributeError: If a field in the `path` doesn't exist
+    :raises IndexError: If an index in the `path` is out of range
+    """
+
+def walk(obj: Any) -> Walk:
+    """
+    Walks through every leaf of `obj`, yielding `(path, value)` in field order.
+    Empty lists are leaves too, so they're yielded as themselves
+    """
@@ -0,0 +1,232 @@
+"""
+Reading and writing recordings of the messages of a match.
+"""
+
+from __future__ import annotations
+
+from os import PathLike
+from typing import Any, Iterator, Literal
+
+from . import arrow, CorePacket, InterfacePacket, MatchConfiguration
+
+SCHEMA_HASH: int
+"""
+A hash of the schema this module was built with, stored in every recording it writes
+"""
+
+class Record:
+    """
+    A message in a recording
+    """
+
+    index: int
+    """
+    The index of the record in the recording
+    """
+    kind: Literal["core", "interface"]
+    """
+    `"core"` for a `CorePacket` or `"interface"` for an `InterfacePacket`
+    """
+    frame_num: int
+    """
+    The latest `MatchInfo.frame_num` when the record was written
+    """
+    seconds: float
+    """
+    The latest `MatchInfo.seconds_elapsed` when the record was written
+    """
+    data: bytes
+    """
+    The packed `CorePacket` or `InterfacePacket`
+    """
+
+    def unpack(self) -> CorePacket | InterfacePacket:
+        """
+        Unpacks the record into a `CorePacket` or `InterfacePacket`
+
+        :raises InvalidFlatbuffer: If the record doesn't contain a valid packet
+        """
+
+class RecordingWriter:
+    """
+    Writes messages to a new recording file.
+    The index is written when the recording is closed, which also happens when it's garbage collected
+    """
+
+    closed: bool
+
+    def __init__(
+        self, path: str | PathLike[str], match_config: MatchConfiguration | None = None
+    ) -> None:
+        """
+        Creates (or overwrites) the recording at `path`, which can optionally store the match config
+        """
+    def write(self, packet: CorePacket | InterfacePacket) -> None:
+        """
+        Writes a `CorePacket` or `InterfacePacket` to the recording.
+        Every record is timestamped with the frame number and game time of the latest `GamePacket`
+
+        :raises TypeError: If `packet` isn't a `CorePacket` or `InterfacePacket`
+        :raises ValueError: If the recording is already closed
+        """
+    def close(self) -> None:
+        """
+        Writes the index and closes the file
+        """
+    def __len__(self) -> int:
+        """
+        The number of records written so far
+        """
+    def __enter__(self) -> RecordingWriter: ...
+    def __exit__(self, exc_type: Any, exc_value: Any, traceback: Any) -> None: ...
+
+class RecordingReader:
+    """
+    Reads a recording, with random access to every record through its index.
+    If the recording wasn't closed properly, the index is rebuilt from the records that were fully written
+    """
+
+    schema_hash: int
+    """
+    The schema hash of the version of this module that wrote the recording.
+    If it isn't the same as `SCHEMA_HASH`, some records might not unpack correctly
+    """
+    match_config: MatchConfiguration | None
+
+    def __init__(self, path: str | PathLike[str]) -> None:
+        """
+        Opens the recording at `path`
+
+        :raises ValueError: If the file isn't a valid recording
+        """
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Record:
+        """
+        Reads a record, checking that it isn't corrupted
+
+        :raises ValueError: If the record's checksum doesn't match
+        """
+    def __iter__(self) -> Iterator[Record]: ...
+    def records(
+        self, start: int = 0, stop: int | None = None
+    ) -> Iterator[Record]:
+        """
+        Iterates over the records from `start` until `stop`, reading one record at a time
+        """
+    def seek_frame(self, frame_num: int) -> int:
+        """
+        The index of the first record at or after `frame_num`,
+        or the number of records if there isn't one.
+        If the frame numbers don't always increase, like after a match restart,
+        the records are scanned in order instead of binary searched
+        """
+    def seek_time(self, seconds: float) -> int:
+        """
+        The index of the first record at or after `seconds` of game time,
+        or the number of records if there isn't one.
+        Like with `seek_frame`, the records are scanned in order if the game time doesn't always increase
+        """
+
+class RecordView:
+    """
+    A record in a `MappedRecording`, which is only read when it's used
+    """
+
+    index: int
+    """
+    The index of the record in the recording
+    """
+    kind: Literal["core", "interface"]
+    """
+    `"core"` for a `CorePacket` or `"interface"` for an `InterfacePacket`
+    """
+    frame_num: int
+    """
+    The latest `MatchInfo.frame_num` when the record was written
+    """
+    seconds: float
+    """
+    The latest `MatchInfo.seconds_elapsed` when the record was written
+    """
+    data: bytes
+    """
+    A copy of the packed `CorePacket` or `InterfacePacket`
+
+    :raises ValueError: If the record's checksum doesn't match
+    """
+
+    def unpack(self) -> CorePacket | InterfacePacket:
+        """
+        Unpacks the record into a `CorePacket` or `InterfacePacket`, straight from the mapped file
+
+        :raises ValueError: If the record's checksum doesn't match
+        :raises InvalidFlatbuffer: If the record doesn't contain a valid packet
+        """
+
+class MappedRecording:
+    """
+    A recording that's memory-mapped instead of read into memory.
+    Records are sliced out of the mapping, so they're only copied when they're unpacked.
+    It can be shared between threads.
+    Frame ranges include every record in the range, even if the frame numbers start over partway through.
+
+    The file must not be modified while it's open
+    """
+
+    schema_hash: int
+    """
+    The schema hash of the version of this module that wrote the recording.
+    If it isn't the same as `SCHEMA_HASH`, some records might not unpack correctly
+    """
+    match_config: MatchConfiguration | None
+
+    def __init__(self, path: str | PathLike[str]) -> None:
+        """
+        Memory-maps the recording at `path`
+
+        :raises ValueError: If the file isn't a valid recording
+        """
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> RecordView:
+        """
+        A lazy view of a record, which isn't read until it's used
+        """
+    def __iter__(self) -> Iterator[RecordView]: ...
+    def views(
+        self, start_frame: int | None = None, stop_frame: int | None = None
+    ) -> list[RecordView]:
+        """
+        Lazy views of the records with a frame number from `start_frame` up to (but not including) `stop_frame`
+        """
+    def unpack_range(
+        self, start_frame: int | None = None, stop_frame: int | None = None
+    ) -> list[CorePacket | InterfacePacket]:
+        """
+        Unpacks the records with a frame number from `start_frame` up to (but not including) `stop_frame`.
+        The records are checked and parsed without holding the GIL,
+        so ranges can be unpacked from multiple threads in parallel
+
+        :raises ValueError: If a record's checksum doesn't match
+        """
+    def to_arrow(
+        self, start_frame: int | None = None, stop_frame: int | None = None
+    ) -> arrow.RecordBatch:
+        """
+        Flattens the `GamePacket`s with a frame number from `start_frame` up to (but not including) `stop_frame`
+        into an Arrow record batch, with a row per packet
+
+        :raises ValueError: If a record's checksum doesn't match
+        """
+    def seek_frame(self, frame_num: int) -> int:
+        """
+        The index of the first record at or after `frame_num`,
+        or the number of records if there isn't one.
+        If the frame numbers don't always increase, like after a match restart,
+        the records are scanned in order instead of binary searched
+        """
+    def seek_time(self, seconds: float) -> int:
+        """
+        The index of the first record at or after `seconds` of game time,
+        or the number of records if there isn't one.
+        Like with `seek_frame`, the records are scanned in order if the game time doesn't always increase
+        """
@@ -0,0 +1,60 @@
+"""
+Runtime reflection of the flatbuffers schema.
+"""
+
+from __future__ import annotations
+
+from typing import Any, Literal
+
+class TypeSchema:
+    name: str
+    kind: Literal["table", "struct", "enum", "union"]
+    doc: str | None
+    fields: tuple[FieldSchema, ...]
+    """
+    The fields of a table or struct, in the order of `__match_args__`
+    """
+    variants: tuple[VariantSchema, ...]
+    """
+    The values of an enum or the types of a union
+    """
+
+    def field(self, name: str) -> FieldSchema:
+        """
+        :raises KeyError: If this type has no field with the given `name`
+        """
+
+class FieldSchema:
+    name: str
+    type_hint: str
+    """
+    The type of the field, as written in type hints (e.g. `Sequence[PlayerInfo]`)
+    """
+    base_type: str
+    """
+    The name of the field's type, without `Sequence` or `None` (e.g. `PlayerInfo`)
+    """
+    default: str
+    """
+    The default value of the field, as a Python expression
+    """
+    optional: bool
+    is_list: bool
+    doc: str | None
+
+class VariantSchema:
+    name: str
+    value: int
+    doc: str | None
+
+def get(obj: str | type | Any) -> TypeSchema:
+    """
+    Gets the schema of a type from its name, its class, or an instance of it
+
+    :raises KeyError: If `obj` isn't a type from the schema
+    """
+
+def types() -> list[TypeSchema]:
+    """
+    Gets the schema of every type, sorted by name
+    """
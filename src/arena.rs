@@ -0,0 +1,225 @@
+//! Constants of the standard soccar arena, exposed to Python as `rlbot_flatbuffers.arena`.
+//!
+//! All distances are in unreal units (uu), with the blue goal at -y and the orange goal at +y.
+
+use crate::{
+    math::Vec3,
+    python::{BoostPad, BoxShape, FieldInfo, GoalInfo},
+};
+use pyo3::{prelude::*, types::*};
+
+/// The distance from the center of the field to the side walls
+pub const SIDE_WALL_X: f64 = 4096.0;
+/// The distance from the center of the field to the back walls, where the goals are
+pub const BACK_WALL_Y: f64 = 5120.0;
+pub const CEILING_Z: f64 = 2044.0;
+
+pub const GOAL_WIDTH: f64 = 1785.51;
+pub const GOAL_HEIGHT: f64 = 642.775;
+/// How far the goals extend past the back walls
+pub const GOAL_DEPTH: f64 = 880.0;
+
+pub const BALL_RADIUS: f64 = 91.25;
+
+/// The seconds it takes for a big boost pad to become active again
+pub const BIG_PAD_RESPAWN_TIME: f64 = 10.0;
+/// The seconds it takes for a small boost pad to become active again
+pub const SMALL_PAD_RESPAWN_TIME: f64 = 4.0;
+pub const BIG_PAD_BOOST: f64 = 100.0;
+pub const SMALL_PAD_BOOST: f64 = 12.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PadLayout {
+    pub location: Vec3,
+    pub is_full_boost: bool,
+}
+
+const fn small_pad(x: f64, y: f64) -> PadLayout {
+    PadLayout {
+        location: Vec3::new(x, y, 70.0),
+        is_full_boost: false,
+    }
+}
+
+const fn big_pad(x: f64, y: f64) -> PadLayout {
+    PadLayout {
+        location: Vec3::new(x, y, 73.0),
+        is_full_boost: true,
+    }
+}
+
+/// The boost pads of the standard soccar arena, in the same order as `FieldInfo.boost_pads`
+pub const SOCCAR_BOOST_PADS: [PadLayout; 34] = [
+    small_pad(0.0, -4240.0),
+    small_pad(-1792.0, -4184.0),
+    small_pad(1792.0, -4184.0),
+    big_pad(-3072.0, -4096.0),
+    big_pad(3072.0, -4096.0),
+    small_pad(-940.0, -3308.0),
+    small_pad(940.0, -3308.0),
+    small_pad(0.0, -2816.0),
+    small_pad(-3584.0, -2484.0),
+    small_pad(3584.0, -2484.0),
+    small_pad(-1788.0, -2300.0),
+    small_pad(1788.0, -2300.0),
+    small_pad(-2048.0, -1036.0),
+    small_pad(0.0, -1024.0),
+    small_pad(2048.0, -1036.0),
+    big_pad(-3584.0, 0.0),
+    small_pad(-1024.0, 0.0),
+    small_pad(1024.0, 0.0),
+    big_pad(3584.0, 0.0),
+    small_pad(-2048.0, 1036.0),
+    small_pad(0.0, 1024.0),
+    small_pad(2048.0, 1036.0),
+    small_pad(-1788.0, 2300.0),
+    small_pad(1788.0, 2300.0),
+    small_pad(-3584.0, 2484.0),
+    small_pad(3584.0, 2484.0),
+    small_pad(0.0, 2816.0),
+    // the game reports this pad slightly off from its mirror image
+    small_pad(-940.0, 3310.0),
+    small_pad(940.0, 3308.0),
+    big_pad(-3072.0, 4096.0),
+    big_pad(3072.0, 4096.0),
+    small_pad(-1792.0, 4184.0),
+    small_pad(1792.0, 4184.0),
+    small_pad(0.0, 4240.0),
+];
+
+/// The dimensions of a car's hitbox, as in `BoxShape`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hitbox {
+    pub length: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Hitbox {
+    fn to_py(self, py: Python) -> BoxShape {
+        BoxShape {
+            length: PyFloat::new(py, self.length).unbind(),
+            width: PyFloat::new(py, self.width).unbind(),
+            height: PyFloat::new(py, self.height).unbind(),
+        }
+    }
+}
+
+/// The hitbox presets that every car in the game uses one of
+pub const HITBOXES: [(&str, Hitbox); 6] = [
+    (
+        "OCTANE",
+        Hitbox {
+            length: 118.0074,
+            width: 84.1994,
+            height: 36.1591,
+        },
+    ),
+    (
+        "DOMINUS",
+        Hitbox {
+            length: 127.9268,
+            width: 83.2799,
+            height: 31.3,
+        },
+    ),
+    (
+        "PLANK",
+        Hitbox {
+            length: 128.8198,
+            width: 84.6704,
+            height: 29.3944,
+        },
+    ),
+    (
+        "BREAKOUT",
+        Hitbox {
+            length: 131.4924,
+            width: 80.521,
+            height: 30.3,
+        },
+    ),
+    (
+        "HYBRID",
+        Hitbox {
+            length: 127.0192,
+            width: 82.1878,
+            height: 34.159,
+        },
+    ),
+    (
+        "MERC",
+        Hitbox {
+            length: 120.72,
+            width: 76.71,
+            height: 41.66,
+        },
+    ),
+];
+
+fn goal(py: Python, team_num: i32, side: f64) -> PyResult<GoalInfo> {
+    Ok(GoalInfo {
+        team_num,
+        location: Py::new(
+            py,
+            Vec3::new(0.0, side * BACK_WALL_Y, GOAL_HEIGHT / 2.0).to_py(py),
+        )?,
+        direction: Py::new(py, Vec3::new(0.0, -side, 0.0).to_py(py))?,
+        width: PyFloat::new(py, GOAL_WIDTH).unbind(),
+        height: PyFloat::new(py, GOAL_HEIGHT).unbind(),
+    })
+}
+
+#[pymethods]
+impl FieldInfo {
+    /// The field info of the standard soccar arena, like DFH Stadium,
+    /// with its 34 boost pads and the two goals
+    #[staticmethod]
+    fn standard_soccar(py: Python) -> PyResult<Self> {
+        let boost_pads = SOCCAR_BOOST_PADS
+            .iter()
+            .map(|pad| {
+                Py::new(
+                    py,
+                    BoostPad {
+                        location: Py::new(py, pad.location.to_py(py))?,
+                        is_full_boost: pad.is_full_boost,
+                    },
+                )
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let goals = [goal(py, 0, -1.0)?, goal(py, 1, 1.0)?];
+
+        Ok(Self {
+            boost_pads: PyList::new(py, boost_pads)?.unbind(),
+            goals: PyList::new(py, goals)?.unbind(),
+        })
+    }
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "arena")?;
+    m.add(
+        "__doc__",
+        "Constants of the standard soccar arena, in unreal units.",
+    )?;
+
+    m.add("SIDE_WALL_X", SIDE_WALL_X)?;
+    m.add("BACK_WALL_Y", BACK_WALL_Y)?;
+    m.add("CEILING_Z", CEILING_Z)?;
+    m.add("GOAL_WIDTH", GOAL_WIDTH)?;
+    m.add("GOAL_HEIGHT", GOAL_HEIGHT)?;
+    m.add("GOAL_DEPTH", GOAL_DEPTH)?;
+    m.add("BALL_RADIUS", BALL_RADIUS)?;
+    m.add("BIG_PAD_RESPAWN_TIME", BIG_PAD_RESPAWN_TIME)?;
+    m.add("SMALL_PAD_RESPAWN_TIME", SMALL_PAD_RESPAWN_TIME)?;
+    m.add("BIG_PAD_BOOST", BIG_PAD_BOOST)?;
+    m.add("SMALL_PAD_BOOST", SMALL_PAD_BOOST)?;
+
+    for (name, hitbox) in HITBOXES {
+        m.add(format!("{name}_HITBOX"), hitbox.to_py(py))?;
+    }
+
+    Ok(m)
+}
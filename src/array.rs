@@ -0,0 +1,450 @@
+//! Columnar exports of lists of tables and structs as float32 arrays, and construction from them.
+//!
+//! The arrays are `memoryview`s over a `bytearray`, cast to an N×K shape of float32s
+//! (or over an empty ctypes array, since a cast can't have a 0 in its shape).
+//! They implement the buffer protocol, so `numpy.asarray(view)` wraps them without copying
+//! and without this module depending on numpy.
+//! Going the other way, any buffer of float32s or float64s is read through a `memoryview`,
+//! straight into the flat representation, which the `pack_*` methods pack without creating any Python objects.
+
+use crate::{
+    FromGil, flat,
+    python::{BallPrediction, Color, GamePacket, PolyLine3D},
+    schema::{SchemaKind, TypeSchema},
+};
+use planus::Builder;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    intern,
+    prelude::*,
+    types::*,
+};
+
+/// The fields of `Physics`, in the order they're exported by default
+const PHYSICS_FIELDS: [&str; 4] = [
+    "physics.location",
+    "physics.rotation",
+    "physics.velocity",
+    "physics.angular_velocity",
+];
+
+/// A path of attribute names to a number
+type Leaf = Vec<&'static str>;
+
+fn is_number(base_type: &str) -> bool {
+    matches!(base_type, "float" | "int" | "bool")
+}
+
+/// The schema of a field that can be expanded into its numbers
+fn struct_schema(base_type: &str) -> Option<&'static TypeSchema> {
+    crate::schema::find(base_type)
+        .filter(|schema| matches!(schema.kind, SchemaKind::Table | SchemaKind::Struct))
+}
+
+/// Adds every number in `schema`, skipping lists, optional fields and strings
+fn expand(schema: &'static TypeSchema, prefix: &Leaf, leaves: &mut Vec<Leaf>) {
+    for field in schema.fields {
+        if field.is_list || field.optional {
+            continue;
+        }
+
+        let mut leaf = prefix.clone();
+        leaf.push(field.name);
+
+        if is_number(field.base_type) {
+            leaves.push(leaf);
+        } else if let Some(nested) = struct_schema(field.base_type) {
+            expand(nested, &leaf, leaves);
+        }
+    }
+}
+
+/// Resolves dotted paths (e.g. `physics.location`) into the numbers they contain
+fn resolve(schema: &'static TypeSchema, paths: &[&str]) -> PyResult<Vec<Leaf>> {
+    if paths.is_empty() {
+        return Err(PyValueError::new_err("At least one field must be exported"));
+    }
+
+    let mut leaves = Vec::new();
+
+    for &path in paths {
+        let invalid = || {
+            PyValueError::new_err(format!(
+                "'{path}' isn't a number or a struct of numbers in {}",
+                schema.name
+            ))
+        };
+
+        let mut current = schema;
+        let mut leaf = Leaf::new();
+        let mut segments = path.split('.').peekable();
+
+        while let Some(segment) = segments.next() {
+            let field = current
+                .find_field(segment)
+                .filter(|field| !field.is_list)
+                .ok_or_else(invalid)?;
+            leaf.push(field.name);
+
+            if segments.peek().is_some() {
+                current = struct_schema(field.base_type).ok_or_else(invalid)?;
+            } else if is_number(field.base_type) {
+                leaves.push(leaf.clone());
+            } else {
+                let nested = struct_schema(field.base_type).ok_or_else(invalid)?;
+                expand(nested, &leaf, &mut leaves);
+            }
+        }
+    }
+
+    Ok(leaves)
+}
+
+/// Reads the numbers at `leaves` of every item, with NaN for fields that aren't set
+fn collect(items: &Bound<PyList>, leaves: &[Leaf]) -> PyResult<Vec<f32>> {
+    let mut values = Vec::with_capacity(items.len() * leaves.len());
+
+    for item in items.iter() {
+        for leaf in leaves {
+            let mut value = item.clone();
+            for name in leaf {
+                if value.is_none() {
+                    break;
+                }
+                value = value.getattr(name)?;
+            }
+
+            values.push(if value.is_none() {
+                f32::NAN
+            } else {
+                value.extract::<f64>()? as f32
+            });
+        }
+    }
+
+    Ok(values)
+}
+
+/// Wraps `values` in a writable `memoryview` of float32s with the given number of columns
+fn to_memoryview<'py>(
+    py: Python<'py>,
+    values: &[f32],
+    columns: usize,
+) -> PyResult<Bound<'py, PyAny>> {
+    if values.is_empty() {
+        // `memoryview.cast` can't have a 0 in its shape, but a ctypes array can,
+        // so an empty array still has `columns` columns
+        let c_float = py
+            .import(intern!(py, "ctypes"))?
+            .getattr(intern!(py, "c_float"))?;
+        let array = c_float.mul(columns)?.mul(0)?.call0()?;
+        return Ok(PyMemoryView::from(&array)?.into_any());
+    }
+
+    let bytes: Vec<u8> = values
+        .iter()
+        .flat_map(|value| value.to_ne_bytes())
+        .collect();
+    let view = PyMemoryView::from(PyByteArray::new(py, &bytes).as_any())?;
+
+    let shape = (values.len() / columns, columns);
+    view.call_method1(intern!(py, "cast"), ("f", shape))
+}
+
+/// Exports the `fields` of every item in `items`, which are all `type_name`s
+fn export<'py>(
+    items: &Bound<'py, PyList>,
+    type_name: &str,
+    fields: Option<Vec<String>>,
+    default_fields: &[&str],
+) -> PyResult<Bound<'py, PyAny>> {
+    let schema = crate::schema::find(type_name).unwrap();
+    let leaves = match &fields {
+        Some(fields) => resolve(
+            schema,
+            &fields.iter().map(String::as_str).collect::<Vec<_>>(),
+        )?,
+        None => resolve(schema, default_fields)?,
+    };
+
+    let values = collect(items, &leaves)?;
+    to_memoryview(items.py(), &values, leaves.len())
+}
+
+/// Reads `array`, a buffer of float32s or float64s with `columns` numbers per row,
+/// as either an N×`columns` array or a flat array of N×`columns` numbers
+fn read_rows(array: &Bound<PyAny>, name: &str, columns: usize) -> PyResult<Vec<f32>> {
+    let py = array.py();
+    let view = PyMemoryView::from(array)?;
+
+    let format: String = view.getattr(intern!(py, "format"))?.extract()?;
+    let little_endian = cfg!(target_endian = "little");
+    let is_double = match format.as_str() {
+        "f" | "@f" | "=f" => false,
+        "d" | "@d" | "=d" => true,
+        "<f" if little_endian => false,
+        "<d" if little_endian => true,
+        ">f" if !little_endian => false,
+        ">d" if !little_endian => true,
+        _ => {
+            return Err(PyTypeError::new_err(format!(
+                "{name} must be an array of float32s or float64s, got format '{format}'"
+            )));
+        }
+    };
+
+    let shape = view.getattr(intern!(py, "shape"))?;
+    let shape_repr = shape.repr()?;
+    let shape: Vec<usize> = shape.extract()?;
+    let valid_shape = match shape[..] {
+        [len] => len % columns == 0,
+        [_, row_len] => row_len == columns,
+        _ => false,
+    };
+    if !valid_shape {
+        return Err(PyValueError::new_err(format!(
+            "{name} must have {columns} number(s) per row, got shape {shape_repr}"
+        )));
+    }
+
+    // tobytes copies the array in C order, even when it isn't contiguous
+    let bytes = view.call_method0(intern!(py, "tobytes"))?;
+    let bytes = bytes.cast::<PyBytes>()?.as_bytes();
+
+    Ok(if is_double {
+        bytes
+            .chunks_exact(8)
+            .map(|chunk| f64::from_ne_bytes(chunk.try_into().unwrap()) as f32)
+            .collect()
+    } else {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect()
+    })
+}
+
+/// Reads an N×3 array of `Vector3`s
+fn read_vectors(array: &Bound<PyAny>, name: &str) -> PyResult<Vec<[f32; 3]>> {
+    Ok(read_rows(array, name, 3)?
+        .chunks_exact(3)
+        .map(|row| [row[0], row[1], row[2]])
+        .collect())
+}
+
+/// Reads an optional N×3 array of `Vector3`s that must have `len` rows, or all zeros if it isn't given
+fn read_column(array: Option<&Bound<PyAny>>, name: &str, len: usize) -> PyResult<Vec<[f32; 3]>> {
+    let Some(array) = array else {
+        return Ok(vec![[0.0; 3]; len]);
+    };
+
+    let vectors = read_vectors(array, name)?;
+    if vectors.len() != len {
+        return Err(PyValueError::new_err(format!(
+            "{name} has {} row(s), but times has {len}",
+            vectors.len()
+        )));
+    }
+
+    Ok(vectors)
+}
+
+fn to_vector3([x, y, z]: [f32; 3]) -> flat::Vector3 {
+    flat::Vector3 { x, y, z }
+}
+
+fn read_poly_line(
+    py: Python,
+    points: &Bound<PyAny>,
+    color: Option<PyRef<Color>>,
+) -> PyResult<flat::PolyLine3D> {
+    Ok(flat::PolyLine3D {
+        points: read_vectors(points, "points")?
+            .into_iter()
+            .map(to_vector3)
+            .collect(),
+        color: color.map_or_else(flat::Color::default, |color| {
+            flat::Color::from_gil(py, &*color)
+        }),
+    })
+}
+
+#[pymethods]
+impl PolyLine3D {
+    /// Creates a poly line from an N×3 array (or a flat array) of float32s or float64s.
+    /// This still creates a `Vector3` for every point, so `pack_points` is faster for long lines
+    ///
+    /// :raises TypeError: If `points` isn't an array of float32s or float64s
+    /// :raises ValueError: If `points` doesn't have 3 numbers per row
+    #[staticmethod]
+    #[pyo3(signature = (points, color=None))]
+    fn from_points(
+        py: Python,
+        points: &Bound<PyAny>,
+        color: Option<PyRef<Color>>,
+    ) -> PyResult<Py<Self>> {
+        let flat_t = read_poly_line(py, points, color)?;
+        Ok(crate::into_py_from(py, &flat_t))
+    }
+
+    /// Packs a poly line from an N×3 array (or a flat array) of float32s or float64s,
+    /// which is read straight into the flat representation without creating a `Vector3` per point.
+    /// The bytes are the same as `PolyLine3D.from_points(points, color).pack()`
+    ///
+    /// :raises TypeError: If `points` isn't an array of float32s or float64s
+    /// :raises ValueError: If `points` doesn't have 3 numbers per row
+    #[staticmethod]
+    #[pyo3(signature = (points, color=None))]
+    fn pack_points<'py>(
+        py: Python<'py>,
+        points: &Bound<PyAny>,
+        color: Option<PyRef<Color>>,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let flat_t = read_poly_line(py, points, color)?;
+
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+        Ok(PyBytes::new(py, builder.finish(flat_t, None)))
+    }
+}
+
+#[pymethods]
+impl GamePacket {
+    /// Exports the `fields` of every player as an N×K float32 array (a `memoryview`),
+    /// which `numpy.asarray` can wrap without copying.
+    /// Fields are paths in `PlayerInfo` like `"boost"` or `"physics.location"`,
+    /// where structs are expanded into their numbers (e.g. x, y and z).
+    /// By default, the 12 numbers of `physics` are exported.
+    /// Booleans become 0 or 1, and fields of a `latest_touch` that isn't set become NaN
+    ///
+    /// :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+    #[pyo3(signature = (fields=None))]
+    fn player_array<'py>(
+        &self,
+        py: Python<'py>,
+        fields: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        export(self.players.bind(py), "PlayerInfo", fields, &PHYSICS_FIELDS)
+    }
+
+    /// Exports the `fields` of every ball as an N×K float32 array (a `memoryview`),
+    /// like `player_array` does for the players
+    ///
+    /// :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+    #[pyo3(signature = (fields=None))]
+    fn ball_array<'py>(
+        &self,
+        py: Python<'py>,
+        fields: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        export(self.balls.bind(py), "BallInfo", fields, &PHYSICS_FIELDS)
+    }
+}
+
+fn read_ball_prediction(
+    times: &Bound<PyAny>,
+    locations: &Bound<PyAny>,
+    velocities: Option<&Bound<PyAny>>,
+    rotations: Option<&Bound<PyAny>>,
+    angular_velocities: Option<&Bound<PyAny>>,
+) -> PyResult<flat::BallPrediction> {
+    let times = read_rows(times, "times", 1)?;
+    let len = times.len();
+
+    let locations = read_column(Some(locations), "locations", len)?;
+    let velocities = read_column(velocities, "velocities", len)?;
+    let rotations = read_column(rotations, "rotations", len)?;
+    let angular_velocities = read_column(angular_velocities, "angular_velocities", len)?;
+
+    Ok(flat::BallPrediction {
+        slices: (0..len)
+            .map(|i| {
+                let [pitch, yaw, roll] = rotations[i];
+
+                flat::PredictionSlice {
+                    game_seconds: times[i],
+                    physics: flat::Physics {
+                        location: to_vector3(locations[i]),
+                        rotation: flat::Rotator { pitch, yaw, roll },
+                        velocity: to_vector3(velocities[i]),
+                        angular_velocity: to_vector3(angular_velocities[i]),
+                    },
+                }
+            })
+            .collect(),
+    })
+}
+
+#[pymethods]
+impl BallPrediction {
+    /// Exports the `fields` of every slice as an N×K float32 array (a `memoryview`),
+    /// which `numpy.asarray` can wrap without copying.
+    /// By default, `game_seconds` and then the 12 numbers of `physics` are exported
+    ///
+    /// :raises ValueError: If `fields` is empty, or a field isn't a number or a struct of numbers
+    #[pyo3(signature = (fields=None))]
+    fn to_array<'py>(
+        &self,
+        py: Python<'py>,
+        fields: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        const DEFAULT_FIELDS: [&str; 5] = [
+            "game_seconds",
+            PHYSICS_FIELDS[0],
+            PHYSICS_FIELDS[1],
+            PHYSICS_FIELDS[2],
+            PHYSICS_FIELDS[3],
+        ];
+
+        export(
+            self.slices.bind(py),
+            "PredictionSlice",
+            fields,
+            &DEFAULT_FIELDS,
+        )
+    }
+
+    /// Creates a ball prediction from a 1-dimensional array of `times`
+    /// and N×3 arrays of the physics at each of them, all float32s or float64s.
+    /// `rotations` are in pitch, yaw and roll, and any array that isn't given is all zeros.
+    /// This still creates a `PredictionSlice` for every row, so `pack_array` is faster
+    ///
+    /// :raises TypeError: If an array isn't an array of float32s or float64s
+    /// :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+    #[staticmethod]
+    #[pyo3(signature = (times, locations, velocities=None, rotations=None, angular_velocities=None))]
+    fn from_array(
+        py: Python,
+        times: &Bound<PyAny>,
+        locations: &Bound<PyAny>,
+        velocities: Option<&Bound<PyAny>>,
+        rotations: Option<&Bound<PyAny>>,
+        angular_velocities: Option<&Bound<PyAny>>,
+    ) -> PyResult<Py<Self>> {
+        let flat_t =
+            read_ball_prediction(times, locations, velocities, rotations, angular_velocities)?;
+        Ok(crate::into_py_from(py, &flat_t))
+    }
+
+    /// Packs a ball prediction from the same arrays as `from_array`,
+    /// which are read straight into the flat representation without creating a `PredictionSlice` per row.
+    /// The bytes are the same as `BallPrediction.from_array(...).pack()`
+    ///
+    /// :raises TypeError: If an array isn't an array of float32s or float64s
+    /// :raises ValueError: If an array doesn't have the same number of rows as `times`, or doesn't have 3 numbers per row
+    #[staticmethod]
+    #[pyo3(signature = (times, locations, velocities=None, rotations=None, angular_velocities=None))]
+    fn pack_array<'py>(
+        py: Python<'py>,
+        times: &Bound<PyAny>,
+        locations: &Bound<PyAny>,
+        velocities: Option<&Bound<PyAny>>,
+        rotations: Option<&Bound<PyAny>>,
+        angular_velocities: Option<&Bound<PyAny>>,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let flat_t =
+            read_ball_prediction(times, locations, velocities, rotations, angular_velocities)?;
+
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+        Ok(PyBytes::new(py, builder.finish(flat_t, None)))
+    }
+}
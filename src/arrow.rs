@@ -0,0 +1,785 @@
+//! Exports sequences of tables and structs to Apache Arrow, exposed to Python as `rlbot_flatbuffers.arrow`.
+//!
+//! Every item becomes a row, and nested fields are flattened into columns named by their path
+//! (e.g. `players[0].physics.location.x`), using the schema in [`crate::schema`].
+//! Lists get a column per index, which is null for rows with shorter lists.
+//! Unions and lists of strings or bytes are skipped.
+//!
+//! The columns are handed out through the
+//! [Arrow C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and the [PyCapsule protocol](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html),
+//! so pyarrow, polars, duckdb and others can import them without a dependency on any of them.
+
+use crate::schema::{SchemaKind, TypeSchema};
+use pyo3::{
+    exceptions::{PyKeyError, PyTypeError},
+    prelude::*,
+    types::*,
+};
+use std::{
+    collections::HashMap,
+    ffi::{CString, c_char, c_int, c_void},
+    ptr,
+    sync::Arc,
+};
+
+#[derive(Clone, Debug)]
+enum Value {
+    Float(f32),
+    Int(i64),
+    Bool(bool),
+    Str(String),
+}
+
+#[derive(Clone, Debug)]
+pub enum ColumnData {
+    Float(Vec<f32>),
+    Int(Vec<i64>),
+    Bool(Vec<bool>),
+    Str(Vec<String>),
+}
+
+impl ColumnData {
+    /// The Arrow format string of the column's type
+    const fn format(&self) -> &'static str {
+        match self {
+            Self::Float(_) => "f",
+            Self::Int(_) => "l",
+            Self::Bool(_) => "b",
+            Self::Str(_) => "u",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub name: String,
+    pub data: ColumnData,
+    /// If each row has a value, which it doesn't when e.g. the row has fewer players
+    pub validity: Vec<bool>,
+}
+
+impl Column {
+    fn new(name: String, value: &Value, rows: usize) -> Self {
+        let data = match value {
+            Value::Float(_) => ColumnData::Float(vec![0.0; rows]),
+            Value::Int(_) => ColumnData::Int(vec![0; rows]),
+            Value::Bool(_) => ColumnData::Bool(vec![false; rows]),
+            Value::Str(_) => ColumnData::Str(vec![String::new(); rows]),
+        };
+
+        Self {
+            name,
+            data,
+            validity: vec![false; rows],
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn push(&mut self, value: Value) {
+        match (&mut self.data, value) {
+            (ColumnData::Float(values), Value::Float(value)) => values.push(value),
+            (ColumnData::Int(values), Value::Int(value)) => values.push(value),
+            (ColumnData::Bool(values), Value::Bool(value)) => values.push(value),
+            (ColumnData::Str(values), Value::Str(value)) => values.push(value),
+            // the schema of every row is the same, so the types always match
+            _ => return self.push_null(),
+        }
+        self.validity.push(true);
+    }
+
+    fn push_null(&mut self) {
+        match &mut self.data {
+            ColumnData::Float(values) => values.push(0.0),
+            ColumnData::Int(values) => values.push(0),
+            ColumnData::Bool(values) => values.push(false),
+            ColumnData::Str(values) => values.push(String::new()),
+        }
+        self.validity.push(false);
+    }
+
+    #[must_use]
+    pub fn null_count(&self) -> usize {
+        self.validity.iter().filter(|&&valid| !valid).count()
+    }
+
+    fn value_to_py<'py>(&self, py: Python<'py>, row: usize) -> PyResult<Bound<'py, PyAny>> {
+        if !self.validity[row] {
+            return Ok(py.None().into_bound(py));
+        }
+
+        Ok(match &self.data {
+            ColumnData::Float(values) => values[row].into_pyobject(py)?.into_any(),
+            ColumnData::Int(values) => values[row].into_pyobject(py)?.into_any(),
+            ColumnData::Bool(values) => values[row].into_pyobject(py)?.to_owned().into_any(),
+            ColumnData::Str(values) => values[row].as_str().into_pyobject(py)?.into_any(),
+        })
+    }
+}
+
+/// A field (or an item of a list) somewhere below the root
+struct Node {
+    name: String,
+    /// Sorts the columns in schema order, with the items of lists in order
+    key: Vec<usize>,
+    column: Option<Column>,
+}
+
+/// Flattens rows of the same type into columns
+struct Flattener {
+    schema: &'static TypeSchema,
+    nodes: Vec<Node>,
+    /// Maps the parent node, field index and list index (plus 1, or 0 if not a list) to a node
+    children: HashMap<(usize, usize, usize), usize>,
+    rows: usize,
+}
+
+impl Flattener {
+    fn new(schema: &'static TypeSchema) -> Self {
+        Self {
+            schema,
+            nodes: vec![Node {
+                name: String::new(),
+                key: Vec::new(),
+                column: None,
+            }],
+            children: HashMap::new(),
+            rows: 0,
+        }
+    }
+
+    fn child(
+        &mut self,
+        parent: usize,
+        field_index: usize,
+        name: &str,
+        list_index: Option<usize>,
+    ) -> usize {
+        let list_key = list_index.map_or(0, |index| index + 1);
+        if let Some(&node) = self.children.get(&(parent, field_index, list_key)) {
+            return node;
+        }
+
+        let parent_node = &self.nodes[parent];
+        let mut full_name = if parent_node.name.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{name}", parent_node.name)
+        };
+        if let Some(index) = list_index {
+            full_name.push_str(&format!("[{index}]"));
+        }
+
+        let mut key = parent_node.key.clone();
+        key.extend([field_index, list_key]);
+
+        self.nodes.push(Node {
+            name: full_name,
+            key,
+            column: None,
+        });
+
+        let node = self.nodes.len() - 1;
+        self.children.insert((parent, field_index, list_key), node);
+        node
+    }
+
+    fn push_value(&mut self, node: usize, value: Value) {
+        let rows = self.rows;
+        let node = &mut self.nodes[node];
+        let column = node
+            .column
+            .get_or_insert_with(|| Column::new(node.name.clone(), &value, rows));
+
+        if column.len() == rows {
+            column.push(value);
+        }
+    }
+
+    fn push_row(&mut self, obj: &Bound<PyAny>) -> PyResult<()> {
+        self.walk(obj, self.schema, 0)?;
+        self.rows += 1;
+
+        for column in self
+            .nodes
+            .iter_mut()
+            .filter_map(|node| node.column.as_mut())
+        {
+            if column.len() < self.rows {
+                column.push_null();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn walk(&mut self, obj: &Bound<PyAny>, schema: &TypeSchema, parent: usize) -> PyResult<()> {
+        for (field_index, field) in schema.fields.iter().enumerate() {
+            let value = obj.getattr(field.name)?;
+
+            if field.is_list {
+                let Some(item_schema) = nested_schema(field.base_type) else {
+                    continue;
+                };
+
+                for (i, item) in value.try_iter()?.enumerate() {
+                    let node = self.child(parent, field_index, field.name, Some(i));
+                    self.walk(&item?, item_schema, node)?;
+                }
+                continue;
+            }
+
+            let value = match field.base_type {
+                "float" => Value::Float(value.extract::<f64>()? as f32),
+                "int" => Value::Int(value.extract()?),
+                "bool" => Value::Bool(value.extract()?),
+                "str" => Value::Str(value.extract()?),
+                base_type => match crate::schema::find(base_type) {
+                    Some(
+                        item_schema @ TypeSchema {
+                            kind: SchemaKind::Table | SchemaKind::Struct,
+                            ..
+                        },
+                    ) => {
+                        // optional fields that aren't set are left as nulls
+                        if !value.is_none() {
+                            let node = self.child(parent, field_index, field.name, None);
+                            self.walk(&value, item_schema, node)?;
+                        }
+                        continue;
+                    }
+                    Some(TypeSchema {
+                        kind: SchemaKind::Enum,
+                        ..
+                    }) => Value::Str(value.getattr("name")?.extract()?),
+                    _ => continue,
+                },
+            };
+
+            let node = self.child(parent, field_index, field.name, None);
+            self.push_value(node, value);
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Table {
+        let mut nodes: Vec<_> = self
+            .nodes
+            .into_iter()
+            .filter(|node| node.column.is_some())
+            .collect();
+        nodes.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Table {
+            columns: nodes.into_iter().filter_map(|node| node.column).collect(),
+            num_rows: self.rows,
+        }
+    }
+}
+
+/// The schema of the items of a list field, if they're tables or structs
+fn nested_schema(base_type: &str) -> Option<&'static TypeSchema> {
+    crate::schema::find(base_type)
+        .filter(|schema| matches!(schema.kind, SchemaKind::Table | SchemaKind::Struct))
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub columns: Vec<Column>,
+    pub num_rows: usize,
+}
+
+impl Table {
+    /// Flattens `items`, which must all be the same generated type, into a table
+    pub fn from_items(items: &Bound<PyAny>) -> PyResult<Self> {
+        let mut flattener: Option<Flattener> = None;
+
+        for item in items.try_iter()? {
+            let item = item?;
+            let Some(schema) = crate::schema::find_class(&item.get_type())?
+                .filter(|schema| matches!(schema.kind, SchemaKind::Table | SchemaKind::Struct))
+            else {
+                return Err(PyTypeError::new_err(format!(
+                    "Expected a generated table or struct, got {}",
+                    item.get_type().name()?
+                )));
+            };
+
+            let flattener = flattener.get_or_insert_with(|| Flattener::new(schema));
+            if !ptr::eq(flattener.schema, schema) {
+                return Err(PyTypeError::new_err(format!(
+                    "Every item must be a {}, got {}",
+                    flattener.schema.name, schema.name
+                )));
+            }
+
+            flattener.push_row(&item)?;
+        }
+
+        Ok(flattener.map(Flattener::finish).unwrap_or_default())
+    }
+
+    fn schema(&self) -> ArrowSchema {
+        let children = self
+            .columns
+            .iter()
+            .map(|column| {
+                ArrowSchema::new(
+                    column.data.format(),
+                    &column.name,
+                    ArrowSchema::FLAG_NULLABLE,
+                    Vec::new(),
+                )
+            })
+            .collect();
+
+        ArrowSchema::new("+s", "", 0, children)
+    }
+
+    fn array(&self) -> ArrowArray {
+        let children = self
+            .columns
+            .iter()
+            .map(|column| {
+                let null_count = column.null_count();
+                let validity = (null_count != 0).then(|| bitmap(&column.validity));
+
+                let buffers = match &column.data {
+                    ColumnData::Float(values) => {
+                        vec![validity, Some(to_words(values, f32::to_ne_bytes))]
+                    }
+                    ColumnData::Int(values) => {
+                        vec![validity, Some(to_words(values, i64::to_ne_bytes))]
+                    }
+                    ColumnData::Bool(values) => vec![validity, Some(bitmap(values))],
+                    ColumnData::Str(values) => {
+                        let mut offsets = Vec::with_capacity(values.len() + 1);
+                        let mut data = Vec::new();
+
+                        offsets.push(0i32);
+                        for value in values {
+                            data.extend_from_slice(value.as_bytes());
+                            offsets.push(data.len() as i32);
+                        }
+
+                        vec![
+                            validity,
+                            Some(to_words(&offsets, i32::to_ne_bytes)),
+                            Some(to_words(&data, u8::to_ne_bytes)),
+                        ]
+                    }
+                };
+
+                ArrowArray::new(self.num_rows, null_count, buffers, Vec::new())
+            })
+            .collect();
+
+        ArrowArray::new(self.num_rows, 0, vec![None], children)
+    }
+}
+
+/// Packs `values` into 8-byte aligned words, so any type can be read from the buffer.
+/// The bytes of every value stay in the same order in memory, which is the native endianness Arrow expects
+fn to_words<T: Copy, const N: usize>(values: &[T], to_bytes: fn(T) -> [u8; N]) -> Vec<u64> {
+    let bytes: Vec<u8> = values.iter().flat_map(|&value| to_bytes(value)).collect();
+
+    bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_ne_bytes(word)
+        })
+        .collect()
+}
+
+/// Packs `values` into a bitmap with the least significant bit of the first byte first, like Arrow expects.
+/// The words are stored little-endian so that their bytes are in that order on any target
+fn bitmap(values: &[bool]) -> Vec<u64> {
+    values
+        .chunks(64)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u64, |word, (i, &value)| word | (u64::from(value) << i))
+                .to_le()
+        })
+        .collect()
+}
+
+/// `struct ArrowSchema` of the C data interface
+#[repr(C)]
+pub struct ArrowSchema {
+    format: *const c_char,
+    name: *const c_char,
+    metadata: *const c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut ArrowSchema,
+    dictionary: *mut ArrowSchema,
+    release: Option<unsafe extern "C" fn(*mut ArrowSchema)>,
+    private_data: *mut c_void,
+}
+
+// SAFETY: the C data interface allows the structs to be moved between threads,
+// and the private data is only used by the release callback
+unsafe impl Send for ArrowSchema {}
+
+struct SchemaPrivate {
+    format: CString,
+    name: CString,
+    children: Vec<*mut ArrowSchema>,
+}
+
+impl ArrowSchema {
+    const FLAG_NULLABLE: i64 = 2;
+
+    fn new(format: &str, name: &str, flags: i64, children: Vec<Self>) -> Self {
+        let mut private = Box::new(SchemaPrivate {
+            format: CString::new(format).unwrap(),
+            name: CString::new(name).unwrap(),
+            children: children
+                .into_iter()
+                .map(|child| Box::into_raw(Box::new(child)))
+                .collect(),
+        });
+
+        Self {
+            format: private.format.as_ptr(),
+            name: private.name.as_ptr(),
+            metadata: ptr::null(),
+            flags,
+            n_children: private.children.len() as i64,
+            children: private.children.as_mut_ptr(),
+            dictionary: ptr::null_mut(),
+            release: Some(release_schema),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+unsafe extern "C" fn release_schema(schema: *mut ArrowSchema) {
+    // SAFETY: the consumer passes the schema this callback was set on,
+    // so `private_data` is the `SchemaPrivate` it was created with
+    let schema = unsafe { &mut *schema };
+    let private = unsafe { Box::from_raw(schema.private_data.cast::<SchemaPrivate>()) };
+
+    for child in private.children {
+        // dropping a child that wasn't moved by the consumer releases it
+        drop(unsafe { Box::from_raw(child) });
+    }
+
+    schema.release = None;
+}
+
+impl Drop for ArrowSchema {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: `release` is the callback of this schema, which hasn't been released yet
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// `struct ArrowArray` of the C data interface
+#[repr(C)]
+pub struct ArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut ArrowArray,
+    dictionary: *mut ArrowArray,
+    release: Option<unsafe extern "C" fn(*mut ArrowArray)>,
+    private_data: *mut c_void,
+}
+
+// SAFETY: the C data interface allows the structs to be moved between threads,
+// and the private data is only used by the release callback
+unsafe impl Send for ArrowArray {}
+
+struct ArrayPrivate {
+    /// Keeps the buffers alive, as `buffer_ptrs` points into them
+    _buffers: Vec<Option<Vec<u64>>>,
+    buffer_ptrs: Vec<*const c_void>,
+    children: Vec<*mut ArrowArray>,
+}
+
+impl ArrowArray {
+    fn new(
+        length: usize,
+        null_count: usize,
+        buffers: Vec<Option<Vec<u64>>>,
+        children: Vec<Self>,
+    ) -> Self {
+        let buffer_ptrs = buffers
+            .iter()
+            .map(|buffer| {
+                buffer
+                    .as_ref()
+                    .map_or(ptr::null(), |buffer| buffer.as_ptr().cast())
+            })
+            .collect();
+
+        let mut private = Box::new(ArrayPrivate {
+            _buffers: buffers,
+            buffer_ptrs,
+            children: children
+                .into_iter()
+                .map(|child| Box::into_raw(Box::new(child)))
+                .collect(),
+        });
+
+        Self {
+            length: length as i64,
+            null_count: null_count as i64,
+            offset: 0,
+            n_buffers: private.buffer_ptrs.len() as i64,
+            n_children: private.children.len() as i64,
+            buffers: private.buffer_ptrs.as_mut_ptr(),
+            children: private.children.as_mut_ptr(),
+            dictionary: ptr::null_mut(),
+            release: Some(release_array),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+
+    /// An array that's already released, which marks the end of a stream
+    const fn released() -> Self {
+        Self {
+            length: 0,
+            null_count: 0,
+            offset: 0,
+            n_buffers: 0,
+            n_children: 0,
+            buffers: ptr::null_mut(),
+            children: ptr::null_mut(),
+            dictionary: ptr::null_mut(),
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+unsafe extern "C" fn release_array(array: *mut ArrowArray) {
+    // SAFETY: the consumer passes the array this callback was set on,
+    // so `private_data` is the `ArrayPrivate` it was created with
+    let array = unsafe { &mut *array };
+    let private = unsafe { Box::from_raw(array.private_data.cast::<ArrayPrivate>()) };
+
+    for child in private.children {
+        // dropping a child that wasn't moved by the consumer releases it
+        drop(unsafe { Box::from_raw(child) });
+    }
+
+    array.release = None;
+}
+
+impl Drop for ArrowArray {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: `release` is the callback of this array, which hasn't been released yet
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// `struct ArrowArrayStream` of the C stream interface, which streams a single record batch
+#[repr(C)]
+pub struct ArrowArrayStream {
+    get_schema: Option<unsafe extern "C" fn(*mut ArrowArrayStream, *mut ArrowSchema) -> c_int>,
+    get_next: Option<unsafe extern "C" fn(*mut ArrowArrayStream, *mut ArrowArray) -> c_int>,
+    get_last_error: Option<unsafe extern "C" fn(*mut ArrowArrayStream) -> *const c_char>,
+    release: Option<unsafe extern "C" fn(*mut ArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+// SAFETY: the C data interface allows the structs to be moved between threads,
+// and the private data is only used by the callbacks
+unsafe impl Send for ArrowArrayStream {}
+
+struct StreamPrivate {
+    table: Arc<Table>,
+    finished: bool,
+}
+
+impl ArrowArrayStream {
+    fn new(table: Arc<Table>) -> Self {
+        let private = Box::new(StreamPrivate {
+            table,
+            finished: false,
+        });
+
+        Self {
+            get_schema: Some(stream_get_schema),
+            get_next: Some(stream_get_next),
+            get_last_error: Some(stream_get_last_error),
+            release: Some(release_stream),
+            private_data: Box::into_raw(private).cast(),
+        }
+    }
+}
+
+unsafe extern "C" fn stream_get_schema(
+    stream: *mut ArrowArrayStream,
+    out: *mut ArrowSchema,
+) -> c_int {
+    // SAFETY: the consumer passes an unreleased stream and space for a schema
+    let private = unsafe { &*(*stream).private_data.cast::<StreamPrivate>() };
+    unsafe { out.write(private.table.schema()) };
+    0
+}
+
+unsafe extern "C" fn stream_get_next(stream: *mut ArrowArrayStream, out: *mut ArrowArray) -> c_int {
+    // SAFETY: the consumer passes an unreleased stream and space for an array
+    let private = unsafe { &mut *(*stream).private_data.cast::<StreamPrivate>() };
+
+    let array = if private.finished {
+        ArrowArray::released()
+    } else {
+        private.finished = true;
+        private.table.array()
+    };
+
+    unsafe { out.write(array) };
+    0
+}
+
+unsafe extern "C" fn stream_get_last_error(_stream: *mut ArrowArrayStream) -> *const c_char {
+    // the callbacks never fail
+    ptr::null()
+}
+
+unsafe extern "C" fn release_stream(stream: *mut ArrowArrayStream) {
+    // SAFETY: the consumer passes the stream this callback was set on,
+    // so `private_data` is the `StreamPrivate` it was created with
+    let stream = unsafe { &mut *stream };
+    drop(unsafe { Box::from_raw(stream.private_data.cast::<StreamPrivate>()) });
+    stream.release = None;
+}
+
+impl Drop for ArrowArrayStream {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            // SAFETY: `release` is the callback of this stream, which hasn't been released yet
+            unsafe { release(self) };
+        }
+    }
+}
+
+/// A table of flattened items, which can be imported by any library that supports
+/// the Arrow PyCapsule interface (e.g. `pyarrow.table(batch)` or `polars.DataFrame(batch)`)
+#[pyclass(module = "rlbot_flatbuffers.arrow", frozen)]
+pub struct RecordBatch {
+    pub table: Arc<Table>,
+}
+
+#[pymethods]
+impl RecordBatch {
+    #[getter]
+    fn num_rows(&self) -> usize {
+        self.table.num_rows
+    }
+
+    #[getter]
+    fn column_names(&self) -> Vec<&str> {
+        self.table
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect()
+    }
+
+    /// The values of a column, with `None` for rows that don't have a value
+    fn column<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyList>> {
+        let column = self
+            .table
+            .columns
+            .iter()
+            .find(|column| column.name == name)
+            .ok_or_else(|| PyKeyError::new_err(format!("No column named '{name}'")))?;
+
+        let values = (0..self.table.num_rows)
+            .map(|row| column.value_to_py(py, row))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        PyList::new(py, values)
+    }
+
+    fn __len__(&self) -> usize {
+        self.table.num_rows
+    }
+
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        PyCapsule::new(py, self.table.schema(), Some(c"arrow_schema".into()))
+    }
+
+    /// Exports the table as a struct array.
+    /// `requested_schema` is ignored, which the protocol allows
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+
+        Ok((
+            self.__arrow_c_schema__(py)?,
+            PyCapsule::new(py, self.table.array(), Some(c"arrow_array".into()))?,
+        ))
+    }
+
+    /// Exports the table as a stream with a single record batch.
+    /// `requested_schema` is ignored, which the protocol allows
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<PyAny>>,
+    ) -> PyResult<Bound<'py, PyCapsule>> {
+        let _ = requested_schema;
+
+        PyCapsule::new(
+            py,
+            ArrowArrayStream::new(self.table.clone()),
+            Some(c"arrow_array_stream".into()),
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RecordBatch(num_rows={}, num_columns={})",
+            self.table.num_rows,
+            self.table.columns.len()
+        )
+    }
+}
+
+/// Flattens `items` (e.g. a list of `GamePacket`s) into a record batch with a row per item
+///
+/// :raises TypeError: If the items aren't all the same generated table or struct
+#[pyfunction]
+pub fn record_batch(items: &Bound<PyAny>) -> PyResult<RecordBatch> {
+    Ok(RecordBatch {
+        table: Arc::new(Table::from_items(items)?),
+    })
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "arrow")?;
+    m.add(
+        "__doc__",
+        "Exports sequences of tables and structs to Apache Arrow.",
+    )?;
+    m.add_class::<RecordBatch>()?;
+    m.add_function(wrap_pyfunction!(record_batch, &m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,270 @@
+//! A ballistic ball simulator that creates `BallPrediction`s without RLBot's core.
+//!
+//! The arena is the standard soccar arena as a box, with the goals cut out of the back walls.
+//! Its rounded corners and edges aren't modeled, and neither are collisions with cars,
+//! so the predictions are plausible rather than exact.
+
+use crate::{
+    arena::{
+        BACK_WALL_Y, BALL_RADIUS, CEILING_Z, GOAL_DEPTH, GOAL_HEIGHT, GOAL_WIDTH, SIDE_WALL_X,
+    },
+    flat,
+    math::Vec3,
+    orientation::Orientation,
+    python::{BallInfo, BallPrediction, CollisionShape, Physics},
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+};
+
+/// The ball predictions of RLBot are at 120Hz, like the game's physics
+const TICK_RATE: f64 = 120.0;
+/// The longest prediction that can be simulated, so a huge `seconds` can't allocate without bound
+const MAX_SECONDS: f64 = 60.0;
+/// The fraction of the ball's velocity that air resistance removes every second
+const DRAG: f64 = 0.0305;
+const MAX_SPEED: f64 = 6000.0;
+const MAX_ANGULAR_SPEED: f64 = 6.0;
+/// The fraction of the speed into a surface that the ball bounces back with
+const RESTITUTION: f64 = 0.6;
+/// The most of the sliding speed at the contact point that a bounce can remove
+const MAX_FRICTION: f64 = 0.35;
+/// Limits the friction of a bounce to a multiple of the speed change that it causes along the normal
+const FRICTION_COEFFICIENT: f64 = 2.0;
+
+const UP: Vec3 = Vec3::new(0.0, 0.0, 1.0);
+
+/// Rotates `vec` by `angle` radians around the unit vector `axis`
+fn rotate(vec: Vec3, axis: Vec3, angle: f64) -> Vec3 {
+    let (sin, cos) = angle.sin_cos();
+    vec * cos + axis.cross(vec) * sin + axis * (axis.dot(vec) * (1.0 - cos))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BallState {
+    pub location: Vec3,
+    pub velocity: Vec3,
+    pub angular_velocity: Vec3,
+    pub orientation: Orientation,
+}
+
+impl BallState {
+    fn from_py(py: Python, physics: &Physics) -> Self {
+        Self {
+            location: Vec3::from_py(py, &physics.location.borrow(py)),
+            velocity: Vec3::from_py(py, &physics.velocity.borrow(py)),
+            angular_velocity: Vec3::from_py(py, &physics.angular_velocity.borrow(py)),
+            orientation: physics.rotation.borrow(py).orientation(py),
+        }
+    }
+
+    fn to_flat(self, game_seconds: f64) -> flat::PredictionSlice {
+        let vector = |vec: Vec3| flat::Vector3 {
+            x: vec.x as f32,
+            y: vec.y as f32,
+            z: vec.z as f32,
+        };
+        let (pitch, yaw, roll) = self.orientation.to_euler();
+
+        flat::PredictionSlice {
+            game_seconds: game_seconds as f32,
+            physics: flat::Physics {
+                location: vector(self.location),
+                rotation: flat::Rotator {
+                    pitch: pitch as f32,
+                    yaw: yaw as f32,
+                    roll: roll as f32,
+                },
+                velocity: vector(self.velocity),
+                angular_velocity: vector(self.angular_velocity),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BallSimulator {
+    pub gravity: f64,
+    pub radius: f64,
+}
+
+impl BallSimulator {
+    /// The surfaces that the ball at `location` is touching,
+    /// as the normal of each surface and how far the ball is inside it
+    fn contacts(&self, location: Vec3) -> Vec<(Vec3, f64)> {
+        // the distance from the ball's center to each surface it can touch, along the surface's normal
+        let mut surfaces = vec![(UP, location.z)];
+
+        // a ball that crossed the back wall outside of the goal mouth is pushed back by the wall,
+        // instead of being treated like it's inside the goal
+        let in_goal_mouth = location.x.abs() < GOAL_WIDTH / 2.0 && location.z < GOAL_HEIGHT;
+        if location.y.abs() > BACK_WALL_Y && in_goal_mouth {
+            let side = location.y.signum();
+            surfaces.extend([
+                (-UP, GOAL_HEIGHT - location.z),
+                (Vec3::new(1.0, 0.0, 0.0), location.x + GOAL_WIDTH / 2.0),
+                (Vec3::new(-1.0, 0.0, 0.0), GOAL_WIDTH / 2.0 - location.x),
+                (
+                    Vec3::new(0.0, -side, 0.0),
+                    BACK_WALL_Y + GOAL_DEPTH - location.y.abs(),
+                ),
+            ]);
+        } else {
+            surfaces.extend([
+                (-UP, CEILING_Z - location.z),
+                (Vec3::new(1.0, 0.0, 0.0), location.x + SIDE_WALL_X),
+                (Vec3::new(-1.0, 0.0, 0.0), SIDE_WALL_X - location.x),
+            ]);
+
+            if !in_goal_mouth {
+                surfaces.extend([
+                    (Vec3::new(0.0, 1.0, 0.0), location.y + BACK_WALL_Y),
+                    (Vec3::new(0.0, -1.0, 0.0), BACK_WALL_Y - location.y),
+                ]);
+            }
+        }
+
+        surfaces
+            .into_iter()
+            .filter(|&(_, distance)| distance < self.radius)
+            .map(|(normal, distance)| (normal, self.radius - distance))
+            .collect()
+    }
+
+    /// Bounces the ball off a surface it's inside of
+    fn bounce(&self, state: &mut BallState, normal: Vec3, penetration: f64) {
+        state.location = state.location + normal * penetration;
+
+        let normal_speed = state.velocity.dot(normal);
+        if normal_speed >= 0.0 {
+            return;
+        }
+
+        let mut tangent = state.velocity - normal * normal_speed;
+
+        // the ball comes to rest against the surface instead of bouncing
+        // less than gravity can pull it in a tick, and then slides or rolls without friction
+        let mut bounce_speed = -normal_speed * RESTITUTION;
+        if bounce_speed < self.gravity.abs() / TICK_RATE {
+            bounce_speed = 0.0;
+        } else {
+            // friction works against the sliding of the contact point,
+            // which doesn't slide at all when the ball is rolling
+            let contact = tangent + state.angular_velocity.cross(normal * -self.radius);
+            let friction = (contact.length() * MAX_FRICTION)
+                .min(FRICTION_COEFFICIENT * (bounce_speed - normal_speed));
+            tangent = tangent - contact.normalized() * friction;
+        }
+
+        let spin = normal * state.angular_velocity.dot(normal);
+        state.angular_velocity = spin + normal.cross(tangent) / self.radius;
+        state.velocity = tangent + normal * bounce_speed;
+    }
+
+    /// Advances the ball by a tick
+    pub fn step(&self, state: &mut BallState) {
+        let dt = 1.0 / TICK_RATE;
+
+        state.velocity.z += self.gravity * dt;
+        state.velocity = state.velocity * (1.0 - DRAG * dt);
+        if state.velocity.length() > MAX_SPEED {
+            state.velocity = state.velocity.normalized() * MAX_SPEED;
+        }
+
+        state.location = state.location + state.velocity * dt;
+
+        let angle = state.angular_velocity.length() * dt;
+        if angle > 0.0 {
+            let axis = state.angular_velocity.normalized();
+            let Orientation { forward, right, up } = state.orientation;
+            state.orientation = Orientation {
+                forward: rotate(forward, axis, angle),
+                right: rotate(right, axis, angle),
+                up: rotate(up, axis, angle),
+            };
+        }
+
+        for (normal, penetration) in self.contacts(state.location) {
+            self.bounce(state, normal, penetration);
+        }
+
+        if state.angular_velocity.length() > MAX_ANGULAR_SPEED {
+            state.angular_velocity = state.angular_velocity.normalized() * MAX_ANGULAR_SPEED;
+        }
+    }
+
+    /// Simulates the ball for `seconds`, starting at `game_seconds`
+    #[must_use]
+    pub fn predict(
+        &self,
+        mut state: BallState,
+        game_seconds: f64,
+        seconds: f64,
+    ) -> flat::BallPrediction {
+        let ticks = (seconds * TICK_RATE).round().max(0.0) as usize;
+
+        let slices = (0..ticks)
+            .map(|tick| {
+                if tick > 0 {
+                    self.step(&mut state);
+                }
+                state.to_flat(game_seconds + tick as f64 / TICK_RATE)
+            })
+            .collect();
+
+        flat::BallPrediction { slices }
+    }
+}
+
+#[pymethods]
+impl BallPrediction {
+    /// Simulates a prediction of `ball` (a `BallInfo` or `Physics`) at 120Hz for `seconds`,
+    /// without collisions with cars, for testing without RLBot's core.
+    /// The first slice is at `game_seconds`, and `gravity` is usually `MatchInfo.world_gravity_z`.
+    /// The ball bounces off the floor, walls and ceiling of the standard soccar arena (without its rounded corners),
+    /// and its radius is that of its `SphereShape` or `CylinderShape`, or the standard ball's otherwise
+    ///
+    /// :raises TypeError: If `ball` isn't a `BallInfo` or `Physics`
+    /// :raises ValueError: If `seconds` isn't finite or is over 60
+    #[staticmethod]
+    #[pyo3(signature = (ball, gravity=-650.0, game_seconds=0.0, seconds=6.0))]
+    fn simulate(
+        py: Python,
+        ball: &Bound<PyAny>,
+        gravity: f64,
+        game_seconds: f64,
+        seconds: f64,
+    ) -> PyResult<Py<Self>> {
+        if !seconds.is_finite() || seconds > MAX_SECONDS {
+            return Err(PyValueError::new_err(format!(
+                "seconds must be finite and at most {MAX_SECONDS}, got {seconds}"
+            )));
+        }
+
+        let (state, radius) = if let Ok(ball) = ball.cast::<BallInfo>() {
+            let ball = ball.get();
+            let radius = match ball.shape.extract::<CollisionShape>(py)? {
+                CollisionShape::SphereShape(sphere) => sphere.get().diameter.bind(py).value() / 2.0,
+                CollisionShape::CylinderShape(cylinder) => {
+                    cylinder.get().diameter.bind(py).value() / 2.0
+                }
+                CollisionShape::BoxShape(_) => BALL_RADIUS,
+            };
+
+            (BallState::from_py(py, ball.physics.get()), radius)
+        } else if let Ok(physics) = ball.cast::<Physics>() {
+            (BallState::from_py(py, physics.get()), BALL_RADIUS)
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a BallInfo or Physics, got {}",
+                ball.get_type().name()?
+            )));
+        };
+
+        let simulator = BallSimulator { gravity, radius };
+        let flat_t = py.detach(|| simulator.predict(state, game_seconds, seconds));
+
+        Ok(crate::into_py_from(py, &flat_t))
+    }
+}
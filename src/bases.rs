@@ -0,0 +1,111 @@
+//! Abstract base classes that give Python a common hierarchy for the generated types.
+//!
+//! The generated classes are registered as virtual subclasses (like with `abc.ABC.register`),
+//! so `isinstance` and `issubclass` checks work without changing their layout.
+
+use crate::schema::{SchemaKind, TYPES};
+use pyo3::{intern, prelude::*, types::*};
+
+fn new_abc<'py>(
+    m: &Bound<'py, PyModule>,
+    name: &str,
+    doc: &str,
+    bases: &Bound<'py, PyTuple>,
+    attrs: &Bound<'py, PyDict>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = m.py();
+
+    attrs.set_item(intern!(py, "__module__"), m.name()?)?;
+    attrs.set_item(intern!(py, "__doc__"), doc)?;
+    attrs.set_item(intern!(py, "__slots__"), PyTuple::empty(py))?;
+
+    let abc_meta = py.import("abc")?.getattr("ABCMeta")?;
+    let cls = abc_meta.call1((name, bases, attrs))?;
+    m.add(name, &cls)?;
+
+    Ok(cls)
+}
+
+/// Registers the builder of a table or struct with `packable_base`, if it has one
+fn register_builder(
+    m: &Bound<PyModule>,
+    packable_base: &Bound<PyAny>,
+    type_name: &str,
+) -> PyResult<()> {
+    let builder_name = format!("{type_name}Builder");
+    if m.hasattr(builder_name.as_str())? {
+        packable_base.call_method1(
+            intern!(m.py(), "register"),
+            (m.getattr(builder_name.as_str())?,),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Adds the `Packable`, `Table` and `Struct` base classes and a marker class for every union to `m`,
+/// which must already contain all of the generated classes and builders
+pub fn add_bases(m: &Bound<PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    let packable_base = new_abc(
+        m,
+        "Packable",
+        "Base class of everything that can be packed into a flatbuffer with `pack()`: the generated tables and structs, and their builders.",
+        &PyTuple::empty(py),
+        &PyDict::new(py),
+    )?;
+    let packable_bases = PyTuple::new(py, [&packable_base])?;
+
+    let table_base = new_abc(
+        m,
+        "Table",
+        "Base class of all generated flatbuffer tables.",
+        &packable_bases,
+        &PyDict::new(py),
+    )?;
+    let struct_base = new_abc(
+        m,
+        "Struct",
+        "Base class of all generated flatbuffer structs.",
+        &packable_bases,
+        &PyDict::new(py),
+    )?;
+
+    for item in &TYPES {
+        match item.kind {
+            SchemaKind::Table => {
+                table_base.call_method1(intern!(py, "register"), (m.getattr(item.name)?,))?;
+                register_builder(m, &packable_base, item.name)?;
+            }
+            SchemaKind::Struct => {
+                struct_base.call_method1(intern!(py, "register"), (m.getattr(item.name)?,))?;
+                register_builder(m, &packable_base, item.name)?;
+            }
+            SchemaKind::Union => {
+                let variants = item
+                    .variants
+                    .iter()
+                    .map(|variant| m.getattr(variant.name))
+                    .collect::<PyResult<Vec<_>>>()?;
+                let variants = PyTuple::new(py, variants)?;
+
+                let attrs = PyDict::new(py);
+                attrs.set_item(intern!(py, "variants"), &variants)?;
+
+                let doc = item.doc.map_or_else(
+                    || format!("Marker class of the `{}` union.", item.name),
+                    String::from,
+                );
+                let union = new_abc(m, item.name, &doc, &PyTuple::empty(py), &attrs)?;
+
+                for variant in variants {
+                    union.call_method1(intern!(py, "register"), (variant,))?;
+                }
+            }
+            SchemaKind::Enum => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Tracks the boost pads of the arena, exposed to Python as `rlbot_flatbuffers.boost`.
+//!
+//! `GamePacket.boost_pads` is in the same order as `FieldInfo.boost_pads`,
+//! so the tracker joins them by index.
+
+use crate::{
+    arena::{BIG_PAD_RESPAWN_TIME, SMALL_PAD_RESPAWN_TIME, SOCCAR_BOOST_PADS},
+    math::Vec3,
+    python::{BoostPad, BoostPadState, FieldInfo, GamePacket, Vector3},
+};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PadState {
+    pub location: Vec3,
+    pub is_full_boost: bool,
+    pub is_active: bool,
+    /// The seconds since the pad was picked up, or 0 if it's active
+    pub timer: f64,
+}
+
+impl PadState {
+    #[must_use]
+    pub const fn respawn_time(&self) -> f64 {
+        if self.is_full_boost {
+            BIG_PAD_RESPAWN_TIME
+        } else {
+            SMALL_PAD_RESPAWN_TIME
+        }
+    }
+
+    /// The estimated seconds until the pad is active, or 0 if it's active
+    #[must_use]
+    pub fn time_until_active(&self) -> f64 {
+        if self.is_active {
+            0.0
+        } else {
+            (self.respawn_time() - self.timer).max(0.0)
+        }
+    }
+
+    fn matches(&self, is_full_boost: Option<bool>, within: f64) -> bool {
+        is_full_boost.is_none_or(|is_full_boost| self.is_full_boost == is_full_boost)
+            && self.time_until_active() <= within
+    }
+}
+
+/// A boost pad, with the state from the latest packet
+#[pyclass(module = "rlbot_flatbuffers.boost", frozen, get_all)]
+pub struct TrackedBoostPad {
+    /// The index of the pad in `FieldInfo.boost_pads` and `GamePacket.boost_pads`
+    pub index: usize,
+    pub location: Py<Vector3>,
+    pub is_full_boost: bool,
+    pub is_active: bool,
+    /// The seconds since the pad was picked up, or 0 if it's active
+    pub timer: f64,
+    /// The estimated seconds until the pad is active, or 0 if it's active
+    pub time_until_active: f64,
+}
+
+impl TrackedBoostPad {
+    fn new(py: Python, index: usize, pad: &PadState) -> PyResult<Self> {
+        Ok(Self {
+            index,
+            location: Py::new(py, pad.location.to_py(py))?,
+            is_full_boost: pad.is_full_boost,
+            is_active: pad.is_active,
+            timer: pad.timer,
+            time_until_active: pad.time_until_active(),
+        })
+    }
+}
+
+#[pymethods]
+impl TrackedBoostPad {
+    fn __repr__(&self, py: Python) -> String {
+        format!(
+            "TrackedBoostPad(index={}, location={}, is_full_boost={}, is_active={}, time_until_active={:?})",
+            self.index,
+            self.location.borrow(py).__repr__(py),
+            crate::bool_to_str(self.is_full_boost),
+            crate::bool_to_str(self.is_active),
+            self.time_until_active,
+        )
+    }
+}
+
+/// Joins the boost pads of `FieldInfo` with their state in each `GamePacket`
+#[pyclass(module = "rlbot_flatbuffers.boost")]
+pub struct BoostPadTracker {
+    pub pads: Vec<PadState>,
+}
+
+impl BoostPadTracker {
+    /// The pads that are (or will be) active within `within` seconds
+    pub fn available(
+        &self,
+        is_full_boost: Option<bool>,
+        within: f64,
+    ) -> impl Iterator<Item = (usize, &PadState)> {
+        self.pads
+            .iter()
+            .enumerate()
+            .filter(move |(_, pad)| pad.matches(is_full_boost, within))
+    }
+
+    /// The closest pad to `location` that's available within `within` seconds
+    #[must_use]
+    pub fn nearest_pad(
+        &self,
+        location: Vec3,
+        is_full_boost: Option<bool>,
+        within: f64,
+    ) -> Option<(usize, &PadState)> {
+        self.available(is_full_boost, within)
+            .min_by(|(_, a), (_, b)| {
+                a.location
+                    .distance(location)
+                    .total_cmp(&b.location.distance(location))
+            })
+    }
+}
+
+#[pymethods]
+impl BoostPadTracker {
+    /// Uses the boost pads of `field_info`, or of the standard soccar arena if it's not given
+    #[new]
+    #[pyo3(signature = (field_info=None))]
+    fn new(py: Python, field_info: Option<PyRef<FieldInfo>>) -> PyResult<Self> {
+        let pads = match field_info {
+            Some(field_info) => field_info
+                .boost_pads
+                .bind(py)
+                .iter()
+                .map(|pad| {
+                    let pad = pad.cast_into::<BoostPad>()?;
+                    let pad = pad.get();
+
+                    Ok(PadState {
+                        location: Vec3::from_py(py, &pad.location.borrow(py)),
+                        is_full_boost: pad.is_full_boost,
+                        is_active: true,
+                        timer: 0.0,
+                    })
+                })
+                .collect::<PyResult<_>>()?,
+            None => SOCCAR_BOOST_PADS
+                .iter()
+                .map(|pad| PadState {
+                    location: pad.location,
+                    is_full_boost: pad.is_full_boost,
+                    is_active: true,
+                    timer: 0.0,
+                })
+                .collect(),
+        };
+
+        Ok(Self { pads })
+    }
+
+    /// Updates the state of every pad from `packet`
+    fn update(&mut self, py: Python, packet: PyRef<GamePacket>) -> PyResult<()> {
+        let states = packet.boost_pads.bind(py);
+        if states.len() != self.pads.len() {
+            return Err(PyValueError::new_err(format!(
+                "The packet has {} boost pads, but the tracker has {}",
+                states.len(),
+                self.pads.len()
+            )));
+        }
+
+        for (pad, state) in self.pads.iter_mut().zip(states) {
+            let state = state.cast_into::<BoostPadState>()?;
+            let state = state.get();
+
+            pad.is_active = state.is_active;
+            pad.timer = state.timer.bind(py).value();
+        }
+
+        Ok(())
+    }
+
+    #[getter]
+    fn pads(&self, py: Python) -> PyResult<Vec<TrackedBoostPad>> {
+        self.pads
+            .iter()
+            .enumerate()
+            .map(|(i, pad)| TrackedBoostPad::new(py, i, pad))
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.pads.len()
+    }
+
+    fn __getitem__(&self, py: Python, index: isize) -> PyResult<TrackedBoostPad> {
+        let normalized = if index < 0 {
+            index + self.pads.len() as isize
+        } else {
+            index
+        };
+
+        let pad = usize::try_from(normalized)
+            .ok()
+            .and_then(|index| Some((index, self.pads.get(index)?)))
+            .ok_or_else(|| PyIndexError::new_err("boost pad index out of range"))?;
+
+        TrackedBoostPad::new(py, pad.0, pad.1)
+    }
+
+    /// The closest pad to `location` that's active, or will be within `within` seconds.
+    /// `is_full_boost` limits the search to only big or small pads
+    #[pyo3(signature = (location, is_full_boost=None, within=0.0))]
+    fn nearest(
+        &self,
+        py: Python,
+        location: PyRef<Vector3>,
+        is_full_boost: Option<bool>,
+        within: f64,
+    ) -> PyResult<Option<TrackedBoostPad>> {
+        self.nearest_pad(Vec3::from_py(py, &location), is_full_boost, within)
+            .map(|(i, pad)| TrackedBoostPad::new(py, i, pad))
+            .transpose()
+    }
+
+    /// The pads that are active, or will be within `seconds`.
+    /// `is_full_boost` limits the result to only big or small pads
+    #[pyo3(signature = (seconds=0.0, is_full_boost=None))]
+    fn available_within(
+        &self,
+        py: Python,
+        seconds: f64,
+        is_full_boost: Option<bool>,
+    ) -> PyResult<Vec<TrackedBoostPad>> {
+        self.available(is_full_boost, seconds)
+            .map(|(i, pad)| TrackedBoostPad::new(py, i, pad))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        let active = self.pads.iter().filter(|pad| pad.is_active).count();
+        format!(
+            "BoostPadTracker(active={active}, total={})",
+            self.pads.len()
+        )
+    }
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "boost")?;
+    m.add(
+        "__doc__",
+        "Tracks the boost pads of the arena across game packets.",
+    )?;
+    m.add_class::<BoostPadTracker>()?;
+    m.add_class::<TrackedBoostPad>()?;
+    Ok(m)
+}
@@ -0,0 +1,248 @@
+//! Jump and dodge availability and hitbox geometry of `PlayerInfo`.
+//!
+//! The hitbox is an oriented box around `physics.location + hitbox_offset` (in local coordinates),
+//! and the distance to the ball is found by projecting back and forth between the two convex shapes,
+//! which converges on the closest points of both.
+
+use crate::{
+    math::Vec3,
+    orientation::Orientation,
+    python::{AirState, BallInfo, CollisionShape, PlayerInfo, Vector3},
+};
+use pyo3::prelude::*;
+
+/// The most projections between the hitbox and the ball before settling on the closest points
+const MAX_PROJECTIONS: usize = 64;
+/// How close two projections have to be for the closest points to have converged
+const EPSILON: f64 = 1e-6;
+
+/// A convex shape in its local coordinates, centered on the origin
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Shape {
+    Sphere {
+        radius: f64,
+    },
+    Box {
+        half_extents: Vec3,
+    },
+    /// Upright along the local z axis
+    Cylinder {
+        radius: f64,
+        half_height: f64,
+    },
+}
+
+impl Shape {
+    fn from_py(py: Python, shape: &CollisionShape) -> Self {
+        match shape {
+            CollisionShape::SphereShape(sphere) => Self::Sphere {
+                radius: sphere.get().diameter.bind(py).value() / 2.0,
+            },
+            CollisionShape::BoxShape(box_shape) => {
+                let box_shape = box_shape.get();
+                Self::Box {
+                    half_extents: Vec3::new(
+                        box_shape.length.bind(py).value(),
+                        box_shape.width.bind(py).value(),
+                        box_shape.height.bind(py).value(),
+                    ) / 2.0,
+                }
+            }
+            CollisionShape::CylinderShape(cylinder) => {
+                let cylinder = cylinder.get();
+                Self::Cylinder {
+                    radius: cylinder.diameter.bind(py).value() / 2.0,
+                    half_height: cylinder.height.bind(py).value() / 2.0,
+                }
+            }
+        }
+    }
+
+    /// The closest point in the shape to `point`
+    fn project(self, point: Vec3) -> Vec3 {
+        match self {
+            Self::Sphere { radius } => {
+                if point.length() > radius {
+                    point.normalized() * radius
+                } else {
+                    point
+                }
+            }
+            Self::Box { half_extents } => Vec3::new(
+                point.x.clamp(-half_extents.x, half_extents.x),
+                point.y.clamp(-half_extents.y, half_extents.y),
+                point.z.clamp(-half_extents.z, half_extents.z),
+            ),
+            Self::Cylinder {
+                radius,
+                half_height,
+            } => {
+                let radial = point.x.hypot(point.y);
+                let scale = if radial > radius {
+                    radius / radial
+                } else {
+                    1.0
+                };
+
+                Vec3::new(
+                    point.x * scale,
+                    point.y * scale,
+                    point.z.clamp(-half_height, half_height),
+                )
+            }
+        }
+    }
+}
+
+/// A shape placed in the world
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Body {
+    center: Vec3,
+    orientation: Orientation,
+    shape: Shape,
+}
+
+impl Body {
+    fn ball(py: Python, ball: &BallInfo) -> PyResult<Self> {
+        let physics = ball.physics.get();
+
+        Ok(Self {
+            center: Vec3::from_py(py, &physics.location.borrow(py)),
+            orientation: physics.rotation.borrow(py).orientation(py),
+            shape: Shape::from_py(py, &ball.shape.extract(py)?),
+        })
+    }
+
+    fn project(&self, point: Vec3) -> Vec3 {
+        let local = self.orientation.to_local(point - self.center);
+        self.center + self.orientation.to_world(self.shape.project(local))
+    }
+
+    /// The closest points of both bodies, which are the same if they overlap
+    fn closest_points(&self, other: &Self) -> (Vec3, Vec3) {
+        let mut point = self.project(other.center);
+        let mut other_point = other.project(point);
+
+        for _ in 0..MAX_PROJECTIONS {
+            let next = self.project(other_point);
+            let converged = next.distance(point) < EPSILON;
+            point = next;
+            other_point = other.project(point);
+
+            if converged {
+                break;
+            }
+        }
+
+        (point, other_point)
+    }
+}
+
+impl PlayerInfo {
+    /// Whether the player is demolished, going by `demolished_timeout`,
+    /// which is -1 while they aren't and counts down to 0 while they are
+    pub fn is_demolished(&self, py: Python) -> bool {
+        self.demolished_timeout.bind(py).value() >= 0.0
+    }
+
+    /// Whether the car is holding a flip reset, so it can dodge until it lands
+    fn has_flip_reset(&self) -> bool {
+        self.air_state == AirState::InAir
+            && !self.has_jumped
+            && !self.has_double_jumped
+            && !self.has_dodged
+    }
+
+    fn half_extents(&self, py: Python) -> Vec3 {
+        let hitbox = self.hitbox.get();
+
+        Vec3::new(
+            hitbox.length.bind(py).value(),
+            hitbox.width.bind(py).value(),
+            hitbox.height.bind(py).value(),
+        ) / 2.0
+    }
+
+    fn hitbox_body(&self, py: Python) -> Body {
+        let physics = self.physics.get();
+        let orientation = physics.rotation.borrow(py).orientation(py);
+        let offset = Vec3::from_py(py, &self.hitbox_offset.borrow(py));
+
+        Body {
+            center: Vec3::from_py(py, &physics.location.borrow(py)) + orientation.to_world(offset),
+            orientation,
+            shape: Shape::Box {
+                half_extents: self.half_extents(py),
+            },
+        }
+    }
+}
+
+#[pymethods]
+impl PlayerInfo {
+    /// Whether the car can jump, which is when it's on the ground and not demolished.
+    /// A `demolished_timeout` of 0 or more counts as demolished, so a `PlayerInfo()` with the default of 0 can't jump
+    fn can_jump(&self, py: Python) -> bool {
+        self.air_state == AirState::OnGround && !self.is_demolished(py)
+    }
+
+    /// Whether the car can dodge or double jump,
+    /// which is until `dodge_timeout` runs out after the first jump, or until landing with a flip reset.
+    /// Like `can_jump`, it's false while `demolished_timeout` is 0 or more
+    fn can_dodge(&self, py: Python) -> bool {
+        if self.is_demolished(py) || self.has_double_jumped || self.has_dodged {
+            return false;
+        }
+
+        match self.air_state {
+            AirState::Jumping => true,
+            AirState::InAir => !self.has_jumped || self.dodge_timeout.bind(py).value() > 0.0,
+            _ => false,
+        }
+    }
+
+    /// The seconds left to dodge or double jump, 0 if the car can't,
+    /// or infinity if it's holding a flip reset
+    fn dodge_time_remaining(&self, py: Python) -> f64 {
+        if !self.can_dodge(py) {
+            0.0
+        } else if self.has_flip_reset() {
+            f64::INFINITY
+        } else {
+            self.dodge_timeout.bind(py).value().max(0.0)
+        }
+    }
+
+    /// The 8 corners of the hitbox in world coordinates,
+    /// with the front corners first, then the right corners before the left, then the top corners before the bottom
+    fn hitbox_corners(&self, py: Python) -> Vec<Vector3> {
+        let hitbox = self.hitbox_body(py);
+        let half_extents = self.half_extents(py);
+
+        let mut corners = Vec::with_capacity(8);
+        for x in [1.0, -1.0] {
+            for y in [1.0, -1.0] {
+                for z in [1.0, -1.0] {
+                    let local =
+                        Vec3::new(x * half_extents.x, y * half_extents.y, z * half_extents.z);
+                    corners.push((hitbox.center + hitbox.orientation.to_world(local)).to_py(py));
+                }
+            }
+        }
+
+        corners
+    }
+
+    /// The closest point of the hitbox to the ball's `CollisionShape`,
+    /// which is inside the ball if they overlap
+    fn closest_point(&self, py: Python, ball: PyRef<BallInfo>) -> PyResult<Vector3> {
+        let (point, _) = self.hitbox_body(py).closest_points(&Body::ball(py, &ball)?);
+        Ok(point.to_py(py))
+    }
+
+    /// The distance between the hitbox and the ball's `CollisionShape`, or 0 if they overlap
+    fn distance_to(&self, py: Python, ball: PyRef<BallInfo>) -> PyResult<f64> {
+        let (point, ball_point) = self.hitbox_body(py).closest_points(&Body::ball(py, &ball)?);
+        Ok(point.distance(ball_point))
+    }
+}
@@ -0,0 +1,338 @@
+//! Delta compression of consecutive `GamePacket`s, exposed to Python as `rlbot_flatbuffers.delta`.
+//!
+//! Every frame is either a keyframe, which is the packed `GamePacket`,
+//! or a delta against the packed bytes of the previous frame.
+//! A frame starts with its [`FrameKind`], and a delta continues with:
+//!
+//! - The [`crc32`] of the packed packet it decodes to (u32, little-endian)
+//! - The length of the packed packet (varint)
+//! - Pairs of the number of unchanged bytes and the number of changed bytes (both varints),
+//!   with the changed bytes XOR'd with the previous packet
+//!
+//! Flatbuffers are built back to front, so when the length of the packet changes
+//! (e.g. a player joined) the bytes are compared from the end instead of the start.
+//! Decoding always reconstructs the exact bytes that were encoded.
+
+use crate::{FromGil, flat, flat_err_to_py, python::GamePacket, recording::crc32};
+use planus::{Builder, ReadAsRoot};
+use pyo3::{exceptions::PyValueError, prelude::*, types::*};
+
+/// The number of unchanged bytes that are cheaper to store in a run of changed bytes
+/// than to start a new run for
+const MIN_UNCHANGED_RUN: usize = 3;
+
+/// The longest packed packet a delta can decode to, far beyond any real `GamePacket`,
+/// so that a corrupted length can't allocate an unbounded amount of memory
+const MAX_PACKED_LEN: usize = 1 << 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FrameKind {
+    Keyframe = 0,
+    /// The bytes are compared from the start of the previous packet
+    Front = 1,
+    /// The bytes are compared from the end of the previous packet
+    Back = 2,
+}
+
+impl FrameKind {
+    fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Keyframe),
+            1 => Some(Self::Front),
+            2 => Some(Self::Back),
+            _ => None,
+        }
+    }
+
+    /// The byte of `previous` that's compared to byte `i` of a packet that's `len` bytes long
+    fn previous_byte(self, previous: &[u8], len: usize, i: usize) -> u8 {
+        let index = match self {
+            Self::Keyframe | Self::Front => Some(i),
+            Self::Back => (i + previous.len()).checked_sub(len),
+        };
+
+        index
+            .and_then(|index| previous.get(index))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(data: &mut &[u8]) -> Option<usize> {
+    let mut value = 0usize;
+
+    for shift in (0..usize::BITS).step_by(7) {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+
+        value |= usize::from(byte & 0x7F).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+fn encode_delta(kind: FrameKind, previous: &[u8], packed: &[u8]) -> Vec<u8> {
+    let mut out = vec![kind as u8];
+    out.extend_from_slice(&crc32(packed).to_le_bytes());
+    write_varint(&mut out, packed.len());
+
+    let changed: Vec<u8> = packed
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ kind.previous_byte(previous, packed.len(), i))
+        .collect();
+
+    let mut i = 0;
+    while i < changed.len() {
+        let start = i;
+        while i < changed.len() && changed[i] == 0 {
+            i += 1;
+        }
+        if i == changed.len() {
+            break;
+        }
+        write_varint(&mut out, i - start);
+
+        // a run ends at the first few unchanged bytes in a row
+        let run_start = i;
+        let mut run_end = i;
+        while i < changed.len() {
+            if changed[i] != 0 {
+                i += 1;
+                run_end = i;
+            } else if changed[i..]
+                .iter()
+                .take(MIN_UNCHANGED_RUN)
+                .all(|&byte| byte == 0)
+            {
+                break;
+            } else {
+                i += 1;
+            }
+        }
+
+        write_varint(&mut out, run_end - run_start);
+        out.extend_from_slice(&changed[run_start..run_end]);
+        i = run_end;
+    }
+
+    out
+}
+
+fn decode_delta(kind: FrameKind, previous: &[u8], mut data: &[u8]) -> Option<Vec<u8>> {
+    let checksum = u32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+    data = &data[4..];
+
+    let len = read_varint(&mut data)?;
+    if len > MAX_PACKED_LEN {
+        return None;
+    }
+
+    let mut packed = Vec::new();
+    packed.try_reserve_exact(len).ok()?;
+    packed.extend((0..len).map(|i| kind.previous_byte(previous, len, i)));
+
+    let mut i = 0usize;
+    while !data.is_empty() {
+        i = i.checked_add(read_varint(&mut data)?)?;
+        let run_len = read_varint(&mut data)?;
+
+        let run = data.get(..run_len)?;
+        data = &data[run_len..];
+
+        for (byte, change) in packed
+            .get_mut(i..i.checked_add(run_len)?)?
+            .iter_mut()
+            .zip(run)
+        {
+            *byte ^= change;
+        }
+        i += run_len;
+    }
+
+    (crc32(&packed) == checksum).then_some(packed)
+}
+
+/// Whether `data` is a keyframe, which can be decoded without the frames before it
+#[pyfunction]
+#[must_use]
+pub fn is_keyframe(data: &[u8]) -> bool {
+    data.first() == Some(&(FrameKind::Keyframe as u8))
+}
+
+/// Encodes packed `GamePacket`s as keyframes and deltas against the previous packet
+#[pyclass(module = "rlbot_flatbuffers.delta")]
+pub struct GamePacketEncoder {
+    previous: Option<Vec<u8>>,
+    keyframe_interval: u32,
+    since_keyframe: u32,
+}
+
+impl GamePacketEncoder {
+    /// Encodes a packed `GamePacket` into a frame
+    pub fn encode_packed(&mut self, packed: &[u8]) -> Vec<u8> {
+        let delta = match &self.previous {
+            Some(previous) if self.since_keyframe < self.keyframe_interval => {
+                let front = encode_delta(FrameKind::Front, previous, packed);
+                if previous.len() == packed.len() {
+                    Some(front)
+                } else {
+                    let back = encode_delta(FrameKind::Back, previous, packed);
+                    Some(if back.len() < front.len() {
+                        back
+                    } else {
+                        front
+                    })
+                }
+            }
+            _ => None,
+        };
+
+        let frame = match delta {
+            Some(delta) if delta.len() <= packed.len() => {
+                self.since_keyframe += 1;
+                delta
+            }
+            _ => {
+                self.since_keyframe = 1;
+                let mut frame = Vec::with_capacity(packed.len() + 1);
+                frame.push(FrameKind::Keyframe as u8);
+                frame.extend_from_slice(packed);
+                frame
+            }
+        };
+
+        self.previous = Some(packed.to_vec());
+        frame
+    }
+}
+
+#[pymethods]
+impl GamePacketEncoder {
+    /// A keyframe is written at least every `keyframe_interval` frames,
+    /// so decoding can start from any of them
+    ///
+    /// :raises ValueError: If `keyframe_interval` is 0
+    #[new]
+    #[pyo3(signature = (keyframe_interval=120))]
+    fn new(keyframe_interval: u32) -> PyResult<Self> {
+        if keyframe_interval == 0 {
+            return Err(PyValueError::new_err(
+                "keyframe_interval must be at least 1",
+            ));
+        }
+
+        Ok(Self {
+            previous: None,
+            keyframe_interval,
+            since_keyframe: 0,
+        })
+    }
+
+    #[getter]
+    fn keyframe_interval(&self) -> u32 {
+        self.keyframe_interval
+    }
+
+    /// Encodes `packet` as a keyframe, or as a delta against the previous packet
+    fn encode(&mut self, py: Python, packet: PyRef<GamePacket>) -> Py<PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+        let flat_t = flat::GamePacket::from_gil(py, &*packet);
+        let packed = builder.finish(flat_t, None);
+
+        PyBytes::new(py, &self.encode_packed(packed)).unbind()
+    }
+
+    /// Forgets the previous packet, so the next frame is a keyframe
+    fn reset(&mut self) {
+        self.previous = None;
+        self.since_keyframe = 0;
+    }
+}
+
+/// Decodes the frames of a `GamePacketEncoder`, in the same order they were encoded
+#[pyclass(module = "rlbot_flatbuffers.delta")]
+#[derive(Default)]
+pub struct GamePacketDecoder {
+    previous: Option<Vec<u8>>,
+}
+
+impl GamePacketDecoder {
+    /// Decodes a frame into the packed `GamePacket`
+    pub fn decode_packed(&mut self, frame: &[u8]) -> PyResult<&[u8]> {
+        let (&kind, data) = frame
+            .split_first()
+            .ok_or_else(|| PyValueError::new_err("The frame is empty"))?;
+        let kind = FrameKind::from_u8(kind)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown frame kind {kind}")))?;
+
+        let packed = match kind {
+            FrameKind::Keyframe => data.to_vec(),
+            FrameKind::Front | FrameKind::Back => {
+                let previous = self.previous.as_deref().ok_or_else(|| {
+                    PyValueError::new_err(
+                        "A delta frame needs the previous frame, decoding must start from a keyframe",
+                    )
+                })?;
+
+                decode_delta(kind, previous, data).ok_or_else(|| {
+                    PyValueError::new_err(
+                        "The delta frame is corrupted or wasn't encoded against the previous frame",
+                    )
+                })?
+            }
+        };
+
+        Ok(self.previous.insert(packed))
+    }
+
+    /// Decodes a frame into the exact `GamePacket` that was encoded
+    pub fn decode_packet(&mut self, frame: &[u8]) -> PyResult<flat::GamePacket> {
+        let packed = self.decode_packed(frame)?;
+        let flat_t_ref = flat::GamePacketRef::read_as_root(packed).map_err(flat_err_to_py)?;
+        flat::GamePacket::try_from(flat_t_ref).map_err(flat_err_to_py)
+    }
+}
+
+#[pymethods]
+impl GamePacketDecoder {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decodes a frame into the `GamePacket` that was encoded
+    ///
+    /// :raises ValueError: If the frame is a corrupted delta, or doesn't follow the frame it was encoded against
+    /// :raises InvalidFlatbuffer: If the frame doesn't contain a valid `GamePacket`
+    fn decode(&mut self, py: Python, data: &[u8]) -> PyResult<Py<GamePacket>> {
+        let flat_t = self.decode_packet(data)?;
+        Ok(crate::into_py_from(py, &flat_t))
+    }
+
+    /// Forgets the previous packet, so the next frame must be a keyframe
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "delta")?;
+    m.add("__doc__", "Delta compression of consecutive game packets.")?;
+    m.add_class::<GamePacketEncoder>()?;
+    m.add_class::<GamePacketDecoder>()?;
+    m.add_function(wrap_pyfunction!(is_keyframe, &m)?)?;
+    Ok(m)
+}
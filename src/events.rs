@@ -0,0 +1,417 @@
+//! Detects game events by comparing consecutive `GamePacket`s,
+//! exposed to Python as `rlbot_flatbuffers.events`.
+
+use crate::python::{AirState, GamePacket, MatchPhase, PlayerInfo, TeamInfo, Touch, Vector2};
+use pyo3::{intern, prelude::*, types::*};
+
+/// The base class of every event
+#[pyclass(module = "rlbot_flatbuffers.events", subclass, frozen, get_all)]
+pub struct Event {
+    /// `MatchInfo.frame_num` of the packet the event was detected in
+    pub frame: u32,
+    /// `MatchInfo.seconds_elapsed` of the packet the event was detected in
+    pub seconds_elapsed: f64,
+}
+
+#[pymethods]
+impl Event {
+    /// Lists `frame` and then the fields in `__match_args__` of the subclass
+    fn __repr__(slf: &Bound<Self>) -> PyResult<String> {
+        let py = slf.py();
+        let event = slf.get();
+
+        let mut fields = vec![format!("frame={}", event.frame)];
+        if let Ok(match_args) = slf.getattr(intern!(py, "__match_args__")) {
+            for name in match_args.cast_into::<PyTuple>()? {
+                let value = slf.getattr(name.cast::<PyString>()?)?;
+                fields.push(format!("{name}={}", value.repr()?));
+            }
+        }
+
+        Ok(format!("{}({})", slf.get_type().name()?, fields.join(", ")))
+    }
+}
+
+/// A team scored a goal
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct GoalScored {
+    /// The team that was awarded the goal
+    pub team: u32,
+    /// The index in `GamePacket.players` of the player that scored, if known
+    pub player_index: Option<usize>,
+    pub player_id: Option<i32>,
+    /// If the player scored into their own goal
+    pub is_own_goal: bool,
+}
+
+/// A player was demolished
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct Demolition {
+    pub victim_index: usize,
+    pub victim_id: i32,
+    /// The index in `GamePacket.players` of the player that caused the demolition, if known
+    pub attacker_index: Option<usize>,
+    pub attacker_id: Option<i32>,
+}
+
+/// A player touched a ball
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct BallTouch {
+    pub player_index: usize,
+    pub player_id: i32,
+    pub touch: Py<Touch>,
+}
+
+/// A player jumped or double jumped
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct Jump {
+    pub player_index: usize,
+    pub player_id: i32,
+    pub is_double_jump: bool,
+}
+
+/// A player dodged
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct Dodge {
+    pub player_index: usize,
+    pub player_id: i32,
+    pub direction: Py<Vector2>,
+}
+
+/// `MatchInfo.match_phase` changed, e.g. to `MatchPhase.Kickoff`
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct PhaseChanged {
+    pub previous: MatchPhase,
+    pub phase: MatchPhase,
+}
+
+/// A player was added to `GamePacket.players`
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct PlayerJoined {
+    pub player_index: usize,
+    pub player_id: i32,
+    pub name: String,
+    pub team: u32,
+}
+
+/// A player was removed from `GamePacket.players`
+#[pyclass(module = "rlbot_flatbuffers.events", extends = Event, frozen, get_all)]
+pub struct PlayerLeft {
+    /// The index the player had in the previous packet
+    pub player_index: usize,
+    pub player_id: i32,
+    pub name: String,
+    pub team: u32,
+}
+
+macro_rules! impl_match_args {
+    ($($name:ident: ($($field:literal),+)),+ $(,)?) => {
+        $(
+            #[pymethods]
+            impl $name {
+                #[classattr]
+                fn __match_args__(py: Python) -> Py<PyTuple> {
+                    PyTuple::new(py, [$($field),+]).unwrap().unbind()
+                }
+            }
+        )+
+    };
+}
+
+impl_match_args! {
+    GoalScored: ("team", "player_index", "player_id", "is_own_goal"),
+    Demolition: ("victim_index", "victim_id", "attacker_index", "attacker_id"),
+    BallTouch: ("player_index", "player_id", "touch"),
+    Jump: ("player_index", "player_id", "is_double_jump"),
+    Dodge: ("player_index", "player_id", "direction"),
+    PhaseChanged: ("previous", "phase"),
+    PlayerJoined: ("player_index", "player_id", "name", "team"),
+    PlayerLeft: ("player_index", "player_id", "name", "team"),
+}
+
+/// The parts of a player that events are detected from
+#[derive(Clone, Debug)]
+struct PlayerSnapshot {
+    player_id: i32,
+    name: String,
+    team: u32,
+    goals: u32,
+    own_goals: u32,
+    demolitions: u32,
+    is_demolished: bool,
+    touch_seconds: Option<f64>,
+    air_state: AirState,
+}
+
+impl PlayerSnapshot {
+    fn new(py: Python, player: &PlayerInfo) -> PyResult<Self> {
+        let score_info = player.score_info.get();
+
+        Ok(Self {
+            player_id: player.player_id,
+            name: player.name.bind(py).to_str()?.to_string(),
+            team: player.team,
+            goals: score_info.goals,
+            own_goals: score_info.own_goals,
+            demolitions: score_info.demolitions,
+            is_demolished: player.is_demolished(py),
+            touch_seconds: player
+                .latest_touch
+                .as_ref()
+                .map(|touch| touch.get().game_seconds.bind(py).value()),
+            air_state: player.air_state,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Snapshot {
+    phase: MatchPhase,
+    team_scores: Vec<u32>,
+    players: Vec<PlayerSnapshot>,
+}
+
+impl Snapshot {
+    fn find_player(&self, player_id: i32) -> Option<&PlayerSnapshot> {
+        self.players
+            .iter()
+            .find(|player| player.player_id == player_id)
+    }
+}
+
+/// Detects events by comparing each `GamePacket` to the previous one.
+/// The first packet is only used as the starting point, so it never has any events
+#[pyclass(module = "rlbot_flatbuffers.events")]
+#[derive(Default)]
+pub struct EventDetector {
+    previous: Option<Snapshot>,
+}
+
+impl EventDetector {
+    fn detect(
+        py: Python,
+        packet: &GamePacket,
+        previous: &Snapshot,
+        current: &Snapshot,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let match_info = packet.match_info.get();
+        let event = || Event {
+            frame: match_info.frame_num,
+            seconds_elapsed: match_info.seconds_elapsed.bind(py).value(),
+        };
+
+        macro_rules! emit {
+            ($events:ident, $value:expr) => {
+                $events.push(
+                    Py::new(py, PyClassInitializer::from(event()).add_subclass($value))?.into_any(),
+                )
+            };
+        }
+
+        let mut events = Vec::new();
+
+        if previous.phase != current.phase {
+            emit!(
+                events,
+                PhaseChanged {
+                    previous: previous.phase,
+                    phase: current.phase,
+                }
+            );
+        }
+
+        for (i, player) in previous.players.iter().enumerate() {
+            if current.find_player(player.player_id).is_none() {
+                emit!(
+                    events,
+                    PlayerLeft {
+                        player_index: i,
+                        player_id: player.player_id,
+                        name: player.name.clone(),
+                        team: player.team,
+                    }
+                );
+            }
+        }
+
+        // players that caused a demolition this frame, once per demolition
+        let mut attackers = Vec::new();
+        // players that scored this frame, once per goal, and whether it was an own goal
+        let mut scorers = Vec::new();
+        let players = packet.players.bind(py);
+
+        for (i, player) in current.players.iter().enumerate() {
+            let Some(old) = previous.find_player(player.player_id) else {
+                emit!(
+                    events,
+                    PlayerJoined {
+                        player_index: i,
+                        player_id: player.player_id,
+                        name: player.name.clone(),
+                        team: player.team,
+                    }
+                );
+                continue;
+            };
+
+            let info = players.get_item(i)?.cast_into::<PlayerInfo>()?;
+            let info = info.get();
+
+            if player.air_state != old.air_state {
+                match player.air_state {
+                    AirState::Jumping | AirState::DoubleJumping => emit!(
+                        events,
+                        Jump {
+                            player_index: i,
+                            player_id: player.player_id,
+                            is_double_jump: player.air_state == AirState::DoubleJumping,
+                        }
+                    ),
+                    AirState::Dodging => emit!(
+                        events,
+                        Dodge {
+                            player_index: i,
+                            player_id: player.player_id,
+                            direction: info.dodge_dir.clone_ref(py),
+                        }
+                    ),
+                    _ => {}
+                }
+            }
+
+            if player.touch_seconds != old.touch_seconds
+                && let Some(touch) = &info.latest_touch
+            {
+                emit!(
+                    events,
+                    BallTouch {
+                        player_index: i,
+                        player_id: player.player_id,
+                        touch: touch.clone_ref(py),
+                    }
+                );
+            }
+
+            for _ in old.demolitions..player.demolitions {
+                attackers.push((i, player));
+            }
+            for _ in old.goals..player.goals {
+                scorers.push((i, player, false));
+            }
+            for _ in old.own_goals..player.own_goals {
+                scorers.push((i, player, true));
+            }
+        }
+
+        for (i, player) in current.players.iter().enumerate() {
+            let was_demolished = previous
+                .find_player(player.player_id)
+                .is_some_and(|old| old.is_demolished);
+            if !player.is_demolished || was_demolished {
+                continue;
+            }
+
+            let attacker = attackers
+                .iter()
+                .position(|(_, attacker)| attacker.team != player.team)
+                .map(|idx| attackers.remove(idx));
+
+            emit!(
+                events,
+                Demolition {
+                    victim_index: i,
+                    victim_id: player.player_id,
+                    attacker_index: attacker.map(|(idx, _)| idx),
+                    attacker_id: attacker.map(|(_, attacker)| attacker.player_id),
+                }
+            );
+        }
+
+        for (team, &score) in current.team_scores.iter().enumerate() {
+            let old_score = previous.team_scores.get(team).copied().unwrap_or_default();
+            let team = team as u32;
+
+            for _ in old_score..score {
+                let scorer = scorers
+                    .iter()
+                    .position(|&(_, player, is_own_goal)| (player.team == team) != is_own_goal)
+                    .map(|idx| scorers.remove(idx));
+
+                emit!(
+                    events,
+                    GoalScored {
+                        team,
+                        player_index: scorer.map(|(i, _, _)| i),
+                        player_id: scorer.map(|(_, player, _)| player.player_id),
+                        is_own_goal: scorer.is_some_and(|(_, _, is_own_goal)| is_own_goal),
+                    }
+                );
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[pymethods]
+impl EventDetector {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events that happened since the previous packet
+    fn update(&mut self, py: Python, packet: PyRef<GamePacket>) -> PyResult<Vec<Py<PyAny>>> {
+        let players = packet
+            .players
+            .bind(py)
+            .iter()
+            .map(|player| PlayerSnapshot::new(py, player.cast_into::<PlayerInfo>()?.get()))
+            .collect::<PyResult<_>>()?;
+
+        let team_scores = packet
+            .teams
+            .bind(py)
+            .iter()
+            .map(|team| Ok(team.cast_into::<TeamInfo>()?.get().score))
+            .collect::<PyResult<_>>()?;
+
+        let current = Snapshot {
+            phase: packet.match_info.get().match_phase,
+            team_scores,
+            players,
+        };
+
+        let events = match &self.previous {
+            Some(previous) => Self::detect(py, &packet, previous, &current)?,
+            None => Vec::new(),
+        };
+
+        self.previous = Some(current);
+        Ok(events)
+    }
+
+    /// Forgets the previous packet, so the next packet is used as a new starting point
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "events")?;
+    m.add(
+        "__doc__",
+        "Detects game events by comparing consecutive game packets.",
+    )?;
+    m.add_class::<EventDetector>()?;
+    m.add_class::<Event>()?;
+    m.add_class::<GoalScored>()?;
+    m.add_class::<Demolition>()?;
+    m.add_class::<BallTouch>()?;
+    m.add_class::<Jump>()?;
+    m.add_class::<Dodge>()?;
+    m.add_class::<PhaseChanged>()?;
+    m.add_class::<PlayerJoined>()?;
+    m.add_class::<PlayerLeft>()?;
+    Ok(m)
+}
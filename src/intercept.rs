@@ -0,0 +1,256 @@
+//! Finds where a car can reach the ball, exposed to Python as `rlbot_flatbuffers.intercept`.
+//!
+//! The car is simulated driving in a straight line towards each slice at the same 120Hz as the ball prediction.
+//! It starts with only the part of its velocity that's already towards the ball,
+//! since turning, jumping and the car's current heading aren't modeled.
+
+use crate::{
+    arena::{BALL_RADIUS, HITBOXES},
+    math::Vec3,
+    prediction::{game_seconds, location, slice_at},
+    python::{BallPrediction, Physics, PlayerInfo, PredictionSlice, Vector3},
+};
+use pyo3::{exceptions::PyTypeError, prelude::*};
+
+/// The distance between the center of an Octane and the center of the ball when they touch head-on
+const DEFAULT_REACH: f64 = BALL_RADIUS + HITBOXES[0].1.length / 2.0;
+
+/// A simple model of a car driving in a straight line,
+/// with constant accelerations from the throttle and from boosting
+#[pyclass(
+    module = "rlbot_flatbuffers.intercept",
+    frozen,
+    get_all,
+    skip_from_py_object
+)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DriveModel {
+    /// The top speed while boosting
+    pub max_speed: f64,
+    /// The top speed that the throttle alone can reach
+    pub throttle_max_speed: f64,
+    pub throttle_acceleration: f64,
+    /// The acceleration that's added while boosting
+    pub boost_acceleration: f64,
+    /// The boost used for each second of boosting
+    pub boost_per_second: f64,
+    pub use_boost: bool,
+    /// The distance between the center of the car and the center of the ball when they touch
+    pub reach: f64,
+}
+
+impl Default for DriveModel {
+    fn default() -> Self {
+        Self {
+            max_speed: 2300.0,
+            throttle_max_speed: 1410.0,
+            throttle_acceleration: 1600.0,
+            boost_acceleration: 991.666,
+            boost_per_second: 33.333,
+            use_boost: true,
+            reach: DEFAULT_REACH,
+        }
+    }
+}
+
+impl DriveModel {
+    /// Advances the car by `dt` seconds, returning the new speed and boost
+    /// and the distance that was covered
+    #[must_use]
+    pub fn step(&self, speed: f64, boost: f64, dt: f64) -> (f64, f64, f64) {
+        let boosting = self.use_boost && boost > 0.0 && speed < self.max_speed;
+
+        let mut acceleration = 0.0;
+        let mut top_speed = speed.max(self.throttle_max_speed);
+        if speed < self.throttle_max_speed {
+            acceleration += self.throttle_acceleration;
+        }
+        if boosting {
+            acceleration += self.boost_acceleration;
+            top_speed = self.max_speed;
+        }
+
+        let new_speed = (speed + acceleration * dt)
+            .min(top_speed)
+            .min(self.max_speed);
+        let new_boost = if boosting {
+            (boost - self.boost_per_second * dt).max(0.0)
+        } else {
+            boost
+        };
+
+        (new_speed, new_boost, (speed + new_speed) / 2.0 * dt)
+    }
+
+    /// The distance covered from the first of `times` until the last,
+    /// stepping from one to the next
+    #[must_use]
+    pub fn distance(&self, mut speed: f64, mut boost: f64, times: &[f64]) -> f64 {
+        let mut distance = 0.0;
+
+        for pair in times.windows(2) {
+            let dt = pair[1] - pair[0];
+            if dt > 0.0 {
+                let covered;
+                (speed, boost, covered) = self.step(speed, boost, dt);
+                distance += covered;
+            }
+        }
+
+        distance
+    }
+}
+
+#[pymethods]
+impl DriveModel {
+    #[new]
+    #[pyo3(signature = (
+        max_speed=2300.0,
+        throttle_max_speed=1410.0,
+        throttle_acceleration=1600.0,
+        boost_acceleration=991.666,
+        boost_per_second=33.333,
+        use_boost=true,
+        reach=DEFAULT_REACH,
+    ))]
+    fn new(
+        max_speed: f64,
+        throttle_max_speed: f64,
+        throttle_acceleration: f64,
+        boost_acceleration: f64,
+        boost_per_second: f64,
+        use_boost: bool,
+        reach: f64,
+    ) -> Self {
+        Self {
+            max_speed,
+            throttle_max_speed,
+            throttle_acceleration,
+            boost_acceleration,
+            boost_per_second,
+            use_boost,
+            reach,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DriveModel(max_speed={:?}, throttle_max_speed={:?}, throttle_acceleration={:?}, boost_acceleration={:?}, boost_per_second={:?}, use_boost={}, reach={:?})",
+            self.max_speed,
+            self.throttle_max_speed,
+            self.throttle_acceleration,
+            self.boost_acceleration,
+            self.boost_per_second,
+            crate::bool_to_str(self.use_boost),
+            self.reach,
+        )
+    }
+}
+
+/// The earliest slice of a ball prediction that a car can reach
+#[pyclass(module = "rlbot_flatbuffers.intercept", frozen, get_all)]
+pub struct Intercept {
+    /// The index of the slice in `BallPrediction.slices`
+    pub index: usize,
+    pub slice: Py<PredictionSlice>,
+    /// The seconds from the first slice until the intercept
+    pub time: f64,
+    /// The average speed the car needs to arrive in time
+    pub required_speed: f64,
+    /// Where the car arrives, `reach` short of the ball on the line from the car
+    pub arrival_location: Py<Vector3>,
+}
+
+#[pymethods]
+impl Intercept {
+    fn __repr__(&self, py: Python) -> String {
+        format!(
+            "Intercept(index={}, time={:?}, required_speed={:?}, arrival_location={})",
+            self.index,
+            self.time,
+            self.required_speed,
+            self.arrival_location.borrow(py).__repr__(py),
+        )
+    }
+}
+
+/// Finds the earliest slice of `ball_prediction` that the car can reach, or `None` if there isn't one.
+/// `car` is a `PlayerInfo`, or a `Physics` with `boost` (100 by default),
+/// and slices where the ball is higher than `max_height` are skipped.
+/// The car starts with only the part of its velocity that's towards each slice
+///
+/// :raises TypeError: If `car` isn't a `PlayerInfo` or `Physics`
+#[pyfunction]
+#[pyo3(signature = (car, ball_prediction, model=None, boost=None, max_height=None))]
+pub fn find_intercept(
+    py: Python,
+    car: &Bound<PyAny>,
+    ball_prediction: PyRef<BallPrediction>,
+    model: Option<PyRef<DriveModel>>,
+    boost: Option<f64>,
+    max_height: Option<f64>,
+) -> PyResult<Option<Intercept>> {
+    let model = model.map_or_else(DriveModel::default, |model| *model);
+
+    let (physics, player_boost) = if let Ok(player) = car.cast::<PlayerInfo>() {
+        let player = player.get();
+        (player.physics.get(), player.boost.bind(py).value())
+    } else if let Ok(physics) = car.cast::<Physics>() {
+        (physics.get(), 100.0)
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected a PlayerInfo or Physics, got {}",
+            car.get_type().name()?
+        )));
+    };
+
+    let car_location = Vec3::from_py(py, &physics.location.borrow(py));
+    let velocity = Vec3::from_py(py, &physics.velocity.borrow(py));
+    let boost = boost.unwrap_or(player_boost);
+
+    let slices = ball_prediction.slices.bind(py);
+    let times = (0..slices.len())
+        .map(|index| Ok(game_seconds(&slice_at(slices, index)?)))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    for (index, &seconds) in times.iter().enumerate() {
+        let slice = slice_at(slices, index)?;
+        let ball_location = location(&slice);
+        if max_height.is_some_and(|max_height| ball_location.z > max_height) {
+            continue;
+        }
+
+        let to_ball = ball_location - car_location;
+        let needed = (to_ball.length() - model.reach).max(0.0);
+
+        let speed = velocity.dot(to_ball.normalized()).max(0.0);
+        if needed > model.distance(speed, boost, &times[..=index]) {
+            continue;
+        }
+
+        let time = seconds - times[0];
+        let arrival_location = car_location + to_ball.normalized() * needed;
+
+        return Ok(Some(Intercept {
+            index,
+            slice: slice.unbind(),
+            time,
+            required_speed: if time > 0.0 { needed / time } else { 0.0 },
+            arrival_location: Py::new(py, arrival_location.to_py(py))?,
+        }));
+    }
+
+    Ok(None)
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "intercept")?;
+    m.add(
+        "__doc__",
+        "Finds the earliest point in a ball prediction that a car can reach.",
+    )?;
+    m.add_class::<DriveModel>()?;
+    m.add_class::<Intercept>()?;
+    m.add_function(wrap_pyfunction!(find_intercept, &m)?)?;
+    Ok(m)
+}
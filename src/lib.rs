@@ -4,7 +4,28 @@ pub use planus_flat::rlbot::flat;
 #[allow(clippy::enum_variant_names, unused_imports)]
 mod python;
 
-use pyo3::{PyClass, create_exception, exceptions::PyValueError, prelude::*, types::*};
+mod arena;
+mod array;
+mod arrow;
+mod ballistic;
+mod bases;
+mod boost;
+mod car;
+mod delta;
+mod events;
+mod intercept;
+mod math;
+mod mirror;
+mod orientation;
+mod path;
+mod prediction;
+mod recording;
+mod schema;
+mod state;
+
+use pyo3::{
+    PyClass, basic::CompareOp, create_exception, exceptions::PyValueError, prelude::*, types::*,
+};
 use python::*;
 use std::{panic::Location, path::MAIN_SEPARATOR};
 
@@ -141,15 +162,55 @@ pub fn pydefault_string(py: Python) -> Py<PyString> {
     PyString::intern(py, "").unbind()
 }
 
+/// The key used by the generated `from_name` methods of enums,
+/// which makes the lookup case-insensitive and ignores underscores
+#[must_use]
+pub fn enum_name_key(name: &str) -> String {
+    name.chars()
+        .filter(|&c| c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Compares an enum to anything that can be converted to an int, like `enum.IntEnum`
+pub fn enum_richcmp(value: u8, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+    let py = other.py();
+    match other.extract::<i64>() {
+        Ok(other) => PyBool::new(py, op.matches(i64::from(value).cmp(&other)))
+            .to_owned()
+            .into_any()
+            .unbind(),
+        Err(_) => py.NotImplemented(),
+    }
+}
+
+/// Adds `submodule` to `m`, and registers it so that `import rlbot_flatbuffers.submodule` works.
+/// The installed package imports the extension as `rlbot_flatbuffers.rlbot_flatbuffers`,
+/// so the submodule is registered under the package's name rather than the extension's
+fn add_submodule(py: Python, m: &Bound<PyModule>, submodule: &Bound<PyModule>) -> PyResult<()> {
+    m.add_submodule(submodule)?;
+
+    let name = m.name()?;
+    let package = name.to_str()?.split('.').next().unwrap_or_default();
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item(format!("{package}.{}", submodule.name()?), submodule)
+}
+
 macro_rules! pynamedmodule {
-    (doc: $doc:literal, name: $name:tt, classes: [$($class_name:ident),*], vars: [$(($var_name:literal, $value:expr)),*], exceptions: [$($except:expr),*]) => {
+    (doc: $doc:literal, name: $name:tt, classes: [$($class_name:ident),*], enums: [$($enum_name:ident),*], vars: [$(($var_name:literal, $value:expr)),*], exceptions: [$($except:expr),*], submodules: [$($submodule:path),*]) => {
         #[doc = $doc]
         #[pymodule]
         #[allow(redundant_semicolons)]
         fn $name(py: Python, m: Bound<PyModule>) -> PyResult<()> {
             $(m.add_class::<$class_name>()?);*;
+            $(m.add_class::<$enum_name>()?);*;
+
+            bases::add_bases(&m)?;
+
             $(m.add($var_name, $value)?);*;
             $(m.add(stringify!($except), py.get_type::<$except>())?);*;
+            $(add_submodule(py, &m, &$submodule(py)?)?);*;
             Ok(())
         }
     };
@@ -159,83 +220,68 @@ pynamedmodule! {
     doc: "rlbot_flatbuffers is a Python module implemented in Rust for serializing and deserializing RLBot's flatbuffers.",
     name: rlbot_flatbuffers,
     classes: [
-        AerialGoalScoreMutator,
-        AirState,
-        AssistGoalScoreMutator,
-        AudioMutator,
         BallAnchor,
-        BallBouncinessMutator,
-        BallGravityMutator,
         BallInfo,
-        BallMaxSpeedMutator,
+        BallInfoBuilder,
         BallPrediction,
-        BallSizeMutator,
-        BallTypeMutator,
-        BallWeightMutator,
-        BoostAmountMutator,
+        BallPredictionBuilder,
         BoostPad,
+        BoostPadBuilder,
         BoostPadState,
-        BoostStrengthMutator,
+        BoostPadStateBuilder,
         BoxShape,
+        BoxShapeBuilder,
         CarAnchor,
         Color,
         ConnectionSettings,
         ConsoleCommand,
         ControllableInfo,
+        ControllableInfoBuilder,
         ControllableTeamInfo,
+        ControllableTeamInfoBuilder,
         ControllerState,
         CorePacket,
+        CorePacketBuilder,
         CustomBot,
         CylinderShape,
-        DebugRendering,
-        DemolishMutator,
-        DemolishScoreMutator,
+        CylinderShapeBuilder,
         DesiredBallState,
         DesiredCarState,
         DesiredGameState,
         DesiredMatchInfo,
         DesiredPhysics,
         DisconnectSignal,
-        DodgeTimerMutator,
-        ExistingMatchBehavior,
         FieldInfo,
-        GameEventMutator,
-        GameMode,
+        FieldInfoBuilder,
         GamePacket,
-        GameSpeedMutator,
+        GamePacketBuilder,
         GoalInfo,
-        GravityMutator,
+        GoalInfoBuilder,
         Human,
         InitComplete,
-        InputRestrictionMutator,
         InterfacePacket,
-        JumpMutator,
-        Launcher,
+        InterfacePacketBuilder,
         Line3D,
         LoadoutPaint,
         MatchComm,
         MatchConfiguration,
         MatchInfo,
-        MatchLengthMutator,
-        MatchPhase,
-        MaxScoreMutator,
-        MaxTimeMutator,
-        MultiBallMutator,
+        MatchInfoBuilder,
         MutatorSettings,
-        NormalGoalScoreMutator,
-        OvertimeMutator,
         Physics,
+        PhysicsBuilder,
         PingRequest,
         PingResponse,
         PlayerConfiguration,
         PlayerInfo,
+        PlayerInfoBuilder,
         PlayerInput,
+        PlayerInputBuilder,
         PlayerLoadout,
         PolyLine3D,
-        PossessionScoreMutator,
         PredictionSlice,
+        PredictionSliceBuilder,
         PsyonixBot,
-        PsyonixSkill,
         Rect2D,
         Rect3D,
         RemoveRenderGroup,
@@ -243,34 +289,85 @@ pynamedmodule! {
         RenderGroup,
         RenderMessage,
         RenderingStatus,
-        RespawnTimeMutator,
         Rotator,
         RotatorPartial,
-        RumbleMutator,
         ScoreInfo,
-        ScoringRuleMutator,
+        ScoreInfoBuilder,
         ScriptConfiguration,
-        SeriesLengthMutator,
         SetLoadout,
         SphereShape,
-        StaleBallMutator,
+        SphereShapeBuilder,
         StartCommand,
         StopCommand,
         String2D,
         String3D,
         TeamInfo,
-        TerritoryMutator,
-        TextHAlign,
-        TextVAlign,
+        TeamInfoBuilder,
         Touch,
+        TouchBuilder,
         Vector2,
+        Vector2Builder,
         Vector3,
         Vector3Partial
     ],
+    enums: [
+        AerialGoalScoreMutator,
+        AirState,
+        AssistGoalScoreMutator,
+        AudioMutator,
+        BallBouncinessMutator,
+        BallGravityMutator,
+        BallMaxSpeedMutator,
+        BallSizeMutator,
+        BallTypeMutator,
+        BallWeightMutator,
+        BoostAmountMutator,
+        BoostStrengthMutator,
+        DebugRendering,
+        DemolishMutator,
+        DemolishScoreMutator,
+        DodgeTimerMutator,
+        ExistingMatchBehavior,
+        GameEventMutator,
+        GameMode,
+        GameSpeedMutator,
+        GravityMutator,
+        InputRestrictionMutator,
+        JumpMutator,
+        Launcher,
+        MatchLengthMutator,
+        MatchPhase,
+        MaxScoreMutator,
+        MaxTimeMutator,
+        MultiBallMutator,
+        NormalGoalScoreMutator,
+        OvertimeMutator,
+        PossessionScoreMutator,
+        PsyonixSkill,
+        RespawnTimeMutator,
+        RumbleMutator,
+        ScoringRuleMutator,
+        SeriesLengthMutator,
+        StaleBallMutator,
+        TerritoryMutator,
+        TextHAlign,
+        TextVAlign
+    ],
     vars: [
         ("__version__", env!("CARGO_PKG_VERSION"))
     ],
     exceptions: [
         InvalidFlatbuffer
+    ],
+    submodules: [
+        arena::module,
+        arrow::module,
+        boost::module,
+        delta::module,
+        events::module,
+        intercept::module,
+        path::module,
+        recording::module,
+        schema::module
     ]
 }
@@ -0,0 +1,341 @@
+//! Native vector math for `Vector3`, `Vector2` and `Vector3Partial`.
+//!
+//! The Python methods are thin wrappers around [`Vec3`] and [`Vec2`],
+//! which can also be used directly by other Rust code in this crate.
+
+use crate::python::{Vector2, Vector3, Vector3Partial};
+use pyo3::{
+    exceptions::{PyIndexError, PyZeroDivisionError},
+    prelude::*,
+    types::*,
+};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+macro_rules! impl_vec_ops {
+    ($name:ident { $($field:ident),+ }) => {
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl $name {
+            #[must_use]
+            pub fn dot(self, other: Self) -> f64 {
+                0.0 $(+ self.$field * other.$field)+
+            }
+
+            #[must_use]
+            pub fn length(self) -> f64 {
+                self.dot(self).sqrt()
+            }
+
+            #[must_use]
+            pub fn distance(self, other: Self) -> f64 {
+                (other - self).length()
+            }
+
+            /// Returns a unit vector in the same direction,
+            /// or a zero vector if this vector has no length
+            #[must_use]
+            pub fn normalized(self) -> Self {
+                let length = self.length();
+                if length == 0.0 {
+                    Self::default()
+                } else {
+                    self / length
+                }
+            }
+
+            #[must_use]
+            pub fn lerp(self, other: Self, t: f64) -> Self {
+                self + (other - self) * t
+            }
+
+            /// The angle between the two vectors in radians,
+            /// or 0 if either vector has no length
+            #[must_use]
+            pub fn angle_to(self, other: Self) -> f64 {
+                let lengths = self.length() * other.length();
+                if lengths == 0.0 {
+                    0.0
+                } else {
+                    (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+                }
+            }
+        }
+    };
+}
+
+impl_vec_ops!(Vec3 { x, y, z });
+impl_vec_ops!(Vec2 { x, y });
+
+impl Vec3 {
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    #[must_use]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    #[must_use]
+    pub fn from_py(py: Python, vec: &Vector3) -> Self {
+        Self {
+            x: vec.x.bind(py).value(),
+            y: vec.y.bind(py).value(),
+            z: vec.z.bind(py).value(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_py(self, py: Python) -> Vector3 {
+        Vector3 {
+            x: PyFloat::new(py, self.x).unbind(),
+            y: PyFloat::new(py, self.y).unbind(),
+            z: PyFloat::new(py, self.z).unbind(),
+        }
+    }
+}
+
+impl Vec2 {
+    #[must_use]
+    pub fn from_py(py: Python, vec: &Vector2) -> Self {
+        Self {
+            x: vec.x.bind(py).value(),
+            y: vec.y.bind(py).value(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_py(self, py: Python) -> Vector2 {
+        Vector2 {
+            x: PyFloat::new(py, self.x).unbind(),
+            y: PyFloat::new(py, self.y).unbind(),
+        }
+    }
+}
+
+fn component<T, const N: usize>(components: [T; N], index: isize) -> PyResult<T> {
+    let normalized = if index < 0 { index + N as isize } else { index };
+
+    usize::try_from(normalized)
+        .ok()
+        .and_then(|index| components.into_iter().nth(index))
+        .ok_or_else(|| PyIndexError::new_err("vector index out of range"))
+}
+
+fn check_divisor(scalar: f64) -> PyResult<()> {
+    if scalar == 0.0 {
+        return Err(PyZeroDivisionError::new_err("division by zero"));
+    }
+
+    Ok(())
+}
+
+fn iter_components<'py, T: IntoPyObject<'py>, const N: usize>(
+    py: Python<'py>,
+    components: [T; N],
+) -> PyResult<Bound<'py, PyIterator>> {
+    PyTuple::new(py, components)?.as_any().try_iter()
+}
+
+macro_rules! impl_py_vec_methods {
+    ($py_type:ident, $vec:ident, $len:literal) => {
+        #[pymethods]
+        impl $py_type {
+            fn __add__(&self, py: Python, other: PyRef<Self>) -> Self {
+                ($vec::from_py(py, self) + $vec::from_py(py, &other)).to_py(py)
+            }
+
+            fn __sub__(&self, py: Python, other: PyRef<Self>) -> Self {
+                ($vec::from_py(py, self) - $vec::from_py(py, &other)).to_py(py)
+            }
+
+            fn __mul__(&self, py: Python, scalar: f64) -> Self {
+                ($vec::from_py(py, self) * scalar).to_py(py)
+            }
+
+            fn __rmul__(&self, py: Python, scalar: f64) -> Self {
+                self.__mul__(py, scalar)
+            }
+
+            fn __truediv__(&self, py: Python, scalar: f64) -> PyResult<Self> {
+                check_divisor(scalar)?;
+                Ok(($vec::from_py(py, self) / scalar).to_py(py))
+            }
+
+            fn __neg__(&self, py: Python) -> Self {
+                (-$vec::from_py(py, self)).to_py(py)
+            }
+
+            fn dot(&self, py: Python, other: PyRef<Self>) -> f64 {
+                $vec::from_py(py, self).dot($vec::from_py(py, &other))
+            }
+
+            fn length(&self, py: Python) -> f64 {
+                $vec::from_py(py, self).length()
+            }
+
+            fn normalized(&self, py: Python) -> Self {
+                $vec::from_py(py, self).normalized().to_py(py)
+            }
+
+            fn distance(&self, py: Python, other: PyRef<Self>) -> f64 {
+                $vec::from_py(py, self).distance($vec::from_py(py, &other))
+            }
+
+            fn lerp(&self, py: Python, other: PyRef<Self>, t: f64) -> Self {
+                $vec::from_py(py, self)
+                    .lerp($vec::from_py(py, &other), t)
+                    .to_py(py)
+            }
+
+            fn angle_to(&self, py: Python, other: PyRef<Self>) -> f64 {
+                $vec::from_py(py, self).angle_to($vec::from_py(py, &other))
+            }
+
+            fn __len__(&self) -> usize {
+                $len
+            }
+
+            fn __getitem__(&self, py: Python, index: isize) -> PyResult<Py<PyFloat>> {
+                component(self.components(py), index)
+            }
+
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                iter_components(py, self.components(py))
+            }
+        }
+    };
+}
+
+impl Vector3 {
+    fn components(&self, py: Python) -> [Py<PyFloat>; 3] {
+        [
+            self.x.clone_ref(py),
+            self.y.clone_ref(py),
+            self.z.clone_ref(py),
+        ]
+    }
+}
+
+impl Vector2 {
+    fn components(&self, py: Python) -> [Py<PyFloat>; 2] {
+        [self.x.clone_ref(py), self.y.clone_ref(py)]
+    }
+}
+
+impl_py_vec_methods!(Vector3, Vec3, 3);
+impl_py_vec_methods!(Vector2, Vec2, 2);
+
+#[pymethods]
+impl Vector3 {
+    fn cross(&self, py: Python, other: PyRef<Self>) -> Self {
+        Vec3::from_py(py, self)
+            .cross(Vec3::from_py(py, &other))
+            .to_py(py)
+    }
+}
+
+impl Vector3Partial {
+    fn components(&self, py: Python) -> [Option<Py<PyFloat>>; 3] {
+        [&self.x, &self.y, &self.z].map(|value| value.as_ref().map(|value| value.clone_ref(py)))
+    }
+
+    /// Applies `f` to each component that is set, leaving unset components as `None`
+    fn map(&self, py: Python, f: impl Fn(f64) -> f64) -> Self {
+        let [x, y, z] = [&self.x, &self.y, &self.z].map(|value| {
+            value
+                .as_ref()
+                .map(|value| PyFloat::new(py, f(value.bind(py).value())).unbind())
+        });
+        Self { x, y, z }
+    }
+}
+
+/// Only the operations that keep unset components unset make sense for `Vector3Partial`
+#[pymethods]
+impl Vector3Partial {
+    fn __mul__(&self, py: Python, scalar: f64) -> Self {
+        self.map(py, |value| value * scalar)
+    }
+
+    fn __rmul__(&self, py: Python, scalar: f64) -> Self {
+        self.__mul__(py, scalar)
+    }
+
+    fn __truediv__(&self, py: Python, scalar: f64) -> PyResult<Self> {
+        check_divisor(scalar)?;
+        Ok(self.map(py, |value| value / scalar))
+    }
+
+    fn __neg__(&self, py: Python) -> Self {
+        self.map(py, |value| -value)
+    }
+
+    fn __len__(&self) -> usize {
+        3
+    }
+
+    fn __getitem__(&self, py: Python, index: isize) -> PyResult<Option<Py<PyFloat>>> {
+        component(self.components(py), index)
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        iter_components(py, self.components(py))
+    }
+}
@@ -0,0 +1,221 @@
+//! Mirroring to the other team's perspective, by rotating the field 180° around the z axis.
+//!
+//! This swaps the blue (-y) and orange (+y) halves of the field, so a bot on the orange team
+//! can work in a frame where it always attacks towards +y, like the blue team.
+//! Mirroring twice gives back the original, except that turning a yaw around twice
+//! can be off by the rounding of an `f32`. So the same transform turns a `DesiredGameState`
+//! that was made in the mirrored frame back into the real one.
+//!
+//! The boost pads of `FieldInfo` and `GamePacket` are sorted by their location,
+//! so mirroring them reverses their order, which keeps them sorted for symmetric arenas like soccar.
+
+use crate::{
+    FromGil, flat,
+    python::{BallPrediction, DesiredGameState, FieldInfo, GamePacket},
+};
+use pyo3::prelude::*;
+use std::f32::consts::PI;
+
+trait Mirror {
+    fn mirror(&mut self);
+}
+
+impl<T: Mirror> Mirror for Vec<T> {
+    fn mirror(&mut self) {
+        self.iter_mut().for_each(Mirror::mirror);
+    }
+}
+
+impl<T: Mirror> Mirror for Option<T> {
+    fn mirror(&mut self) {
+        if let Some(value) = self {
+            value.mirror();
+        }
+    }
+}
+
+impl<T: Mirror> Mirror for Box<T> {
+    fn mirror(&mut self) {
+        (**self).mirror();
+    }
+}
+
+/// Turns a yaw around, keeping it in the range (-pi, pi]
+fn mirror_yaw(yaw: f32) -> f32 {
+    if yaw > 0.0 { yaw - PI } else { yaw + PI }
+}
+
+impl Mirror for flat::Vector3 {
+    fn mirror(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+    }
+}
+
+impl Mirror for flat::Rotator {
+    fn mirror(&mut self) {
+        self.yaw = mirror_yaw(self.yaw);
+    }
+}
+
+impl Mirror for flat::Physics {
+    fn mirror(&mut self) {
+        self.location.mirror();
+        self.rotation.mirror();
+        self.velocity.mirror();
+        self.angular_velocity.mirror();
+    }
+}
+
+impl Mirror for flat::Touch {
+    fn mirror(&mut self) {
+        self.location.mirror();
+        self.normal.mirror();
+    }
+}
+
+impl Mirror for flat::PlayerInfo {
+    fn mirror(&mut self) {
+        self.physics.mirror();
+        self.latest_touch.mirror();
+    }
+}
+
+impl Mirror for flat::BallInfo {
+    fn mirror(&mut self) {
+        self.physics.mirror();
+    }
+}
+
+impl Mirror for flat::GamePacket {
+    fn mirror(&mut self) {
+        self.players.mirror();
+        self.balls.mirror();
+        self.boost_pads.reverse();
+    }
+}
+
+impl Mirror for flat::PredictionSlice {
+    fn mirror(&mut self) {
+        self.physics.mirror();
+    }
+}
+
+impl Mirror for flat::BallPrediction {
+    fn mirror(&mut self) {
+        self.slices.mirror();
+    }
+}
+
+impl Mirror for flat::BoostPad {
+    fn mirror(&mut self) {
+        self.location.mirror();
+    }
+}
+
+impl Mirror for flat::GoalInfo {
+    fn mirror(&mut self) {
+        self.location.mirror();
+        self.direction.mirror();
+    }
+}
+
+impl Mirror for flat::FieldInfo {
+    fn mirror(&mut self) {
+        self.boost_pads.mirror();
+        self.boost_pads.reverse();
+        self.goals.mirror();
+    }
+}
+
+impl Mirror for flat::Vector3Partial {
+    fn mirror(&mut self) {
+        for value in [&mut self.x, &mut self.y].into_iter().flatten() {
+            value.val = -value.val;
+        }
+    }
+}
+
+impl Mirror for flat::RotatorPartial {
+    fn mirror(&mut self) {
+        if let Some(yaw) = &mut self.yaw {
+            yaw.val = mirror_yaw(yaw.val);
+        }
+    }
+}
+
+impl Mirror for flat::DesiredPhysics {
+    fn mirror(&mut self) {
+        self.location.mirror();
+        self.rotation.mirror();
+        self.velocity.mirror();
+        self.angular_velocity.mirror();
+    }
+}
+
+impl Mirror for flat::DesiredBallState {
+    fn mirror(&mut self) {
+        self.physics.mirror();
+    }
+}
+
+impl Mirror for flat::DesiredCarState {
+    fn mirror(&mut self) {
+        self.physics.mirror();
+    }
+}
+
+impl Mirror for flat::DesiredGameState {
+    fn mirror(&mut self) {
+        self.ball_states.mirror();
+        self.car_states.mirror();
+    }
+}
+
+/// Converts `py_type` into its flat representation, mirrors it, and converts it back
+fn mirrored<T, U>(py: Python, py_type: &T) -> Py<T>
+where
+    T: pyo3::PyClass + for<'a> FromGil<&'a U> + Into<PyClassInitializer<T>>,
+    U: Mirror + for<'a> FromGil<&'a T>,
+{
+    let mut flat_t = U::from_gil(py, py_type);
+    flat_t.mirror();
+    crate::into_py_from(py, &flat_t)
+}
+
+#[pymethods]
+impl GamePacket {
+    /// A copy of the packet from the other team's perspective, with the field rotated 180° around the z axis.
+    /// Locations, velocities and angular velocities have their x and y negated,
+    /// yaws are turned around, and the boost pads are in reverse order
+    fn mirrored(&self, py: Python) -> Py<Self> {
+        mirrored::<_, flat::GamePacket>(py, self)
+    }
+}
+
+#[pymethods]
+impl BallPrediction {
+    /// A copy of the prediction from the other team's perspective, like `GamePacket.mirrored`
+    fn mirrored(&self, py: Python) -> Py<Self> {
+        mirrored::<_, flat::BallPrediction>(py, self)
+    }
+}
+
+#[pymethods]
+impl FieldInfo {
+    /// A copy of the field info from the other team's perspective, like `GamePacket.mirrored`.
+    /// The boost pads are in reverse order, so they stay in the same order as the mirrored `GamePacket.boost_pads`
+    fn mirrored(&self, py: Python) -> Py<Self> {
+        mirrored::<_, flat::FieldInfo>(py, self)
+    }
+}
+
+#[pymethods]
+impl DesiredGameState {
+    /// A copy of the state from the other team's perspective, like `GamePacket.mirrored`.
+    /// Mirroring is its own inverse up to the `f32` rounding of yaws, so this also turns a state made in the mirrored frame back into the real one.
+    /// Only the values that are set are changed
+    fn mirrored(&self, py: Python) -> Py<Self> {
+        mirrored::<_, flat::DesiredGameState>(py, self)
+    }
+}
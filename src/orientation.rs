@@ -0,0 +1,241 @@
+//! Conversions between `Rotator`'s Euler angles, rotation matrices and quaternions,
+//! plus the car-relative coordinate helpers of `Physics`.
+//!
+//! Rocket League's local axes are forward (+x), right (+y) and up (+z),
+//! so a car with a yaw of `pi/2` faces +y and has its right side towards -x.
+
+use crate::{
+    math::Vec3,
+    python::{Physics, Rotator, Vector3},
+};
+use pyo3::{exceptions::PyValueError, prelude::*, types::*};
+
+/// A unit quaternion, in `(w, x, y, z)` order
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quat {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// A rotation matrix, stored as its columns:
+/// the forward, right and up vectors of the rotated object
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Orientation {
+    pub forward: Vec3,
+    pub right: Vec3,
+    pub up: Vec3,
+}
+
+impl Orientation {
+    #[must_use]
+    pub fn from_euler(pitch: f64, yaw: f64, roll: f64) -> Self {
+        let (sp, cp) = pitch.sin_cos();
+        let (sy, cy) = yaw.sin_cos();
+        let (sr, cr) = roll.sin_cos();
+
+        Self {
+            forward: Vec3::new(cp * cy, cp * sy, sp),
+            right: Vec3::new(cy * sp * sr - cr * sy, sy * sp * sr + cr * cy, -cp * sr),
+            up: Vec3::new(-cr * cy * sp - sr * sy, -cr * sy * sp + sr * cy, cp * cr),
+        }
+    }
+
+    /// Returns `(pitch, yaw, roll)`.
+    /// When pointing straight up or down, yaw and roll are the same axis,
+    /// so the roll is 0 and the yaw comes from the right vector
+    #[must_use]
+    pub fn to_euler(self) -> (f64, f64, f64) {
+        const GIMBAL_LOCK_EPSILON: f64 = 1e-6;
+
+        let horizontal = self.forward.x.hypot(self.forward.y);
+        let pitch = self.forward.z.atan2(horizontal);
+
+        if horizontal < GIMBAL_LOCK_EPSILON {
+            return (pitch, (-self.right.x).atan2(self.right.y), 0.0);
+        }
+
+        let yaw = self.forward.y.atan2(self.forward.x);
+        let roll = (-self.right.z).atan2(self.up.z);
+
+        (pitch, yaw, roll)
+    }
+
+    /// `quat` must already be normalized
+    #[must_use]
+    pub fn from_quat(quat: Quat) -> Self {
+        let Quat { w, x, y, z } = quat;
+
+        Self {
+            forward: Vec3::new(
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y + z * w),
+                2.0 * (x * z - y * w),
+            ),
+            right: Vec3::new(
+                2.0 * (x * y - z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z + x * w),
+            ),
+            up: Vec3::new(
+                2.0 * (x * z + y * w),
+                2.0 * (y * z - x * w),
+                1.0 - 2.0 * (x * x + y * y),
+            ),
+        }
+    }
+
+    #[must_use]
+    pub fn to_quat(self) -> Quat {
+        let Self { forward, right, up } = self;
+        let trace = forward.x + right.y + up.z;
+
+        // pick the largest divisor to stay numerically stable
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quat {
+                w: s / 4.0,
+                x: (right.z - up.y) / s,
+                y: (up.x - forward.z) / s,
+                z: (forward.y - right.x) / s,
+            }
+        } else if forward.x > right.y && forward.x > up.z {
+            let s = (1.0 + forward.x - right.y - up.z).sqrt() * 2.0;
+            Quat {
+                w: (right.z - up.y) / s,
+                x: s / 4.0,
+                y: (right.x + forward.y) / s,
+                z: (up.x + forward.z) / s,
+            }
+        } else if right.y > up.z {
+            let s = (1.0 + right.y - forward.x - up.z).sqrt() * 2.0;
+            Quat {
+                w: (up.x - forward.z) / s,
+                x: (right.x + forward.y) / s,
+                y: s / 4.0,
+                z: (up.y + right.z) / s,
+            }
+        } else {
+            let s = (1.0 + up.z - forward.x - right.y).sqrt() * 2.0;
+            Quat {
+                w: (forward.y - right.x) / s,
+                x: (up.x + forward.z) / s,
+                y: (up.y + right.z) / s,
+                z: s / 4.0,
+            }
+        }
+    }
+
+    /// Converts a direction from world coordinates into local coordinates
+    #[must_use]
+    pub fn to_local(self, vec: Vec3) -> Vec3 {
+        Vec3::new(vec.dot(self.forward), vec.dot(self.right), vec.dot(self.up))
+    }
+
+    /// Converts a direction from local coordinates into world coordinates
+    #[must_use]
+    pub fn to_world(self, vec: Vec3) -> Vec3 {
+        self.forward * vec.x + self.right * vec.y + self.up * vec.z
+    }
+}
+
+impl Rotator {
+    #[must_use]
+    pub fn orientation(&self, py: Python) -> Orientation {
+        Orientation::from_euler(
+            self.pitch.bind(py).value(),
+            self.yaw.bind(py).value(),
+            self.roll.bind(py).value(),
+        )
+    }
+
+    #[must_use]
+    pub fn from_orientation(py: Python, orientation: Orientation) -> Self {
+        let (pitch, yaw, roll) = orientation.to_euler();
+
+        Self {
+            pitch: PyFloat::new(py, pitch).unbind(),
+            yaw: PyFloat::new(py, yaw).unbind(),
+            roll: PyFloat::new(py, roll).unbind(),
+        }
+    }
+}
+
+#[pymethods]
+impl Rotator {
+    /// The direction the nose of the object is pointing
+    fn forward(&self, py: Python) -> Vector3 {
+        self.orientation(py).forward.to_py(py)
+    }
+
+    fn right(&self, py: Python) -> Vector3 {
+        self.orientation(py).right.to_py(py)
+    }
+
+    fn up(&self, py: Python) -> Vector3 {
+        self.orientation(py).up.to_py(py)
+    }
+
+    /// Returns the forward, right and up vectors,
+    /// which are the columns of the matrix that rotates local coordinates into world coordinates
+    fn to_matrix(&self, py: Python) -> (Vector3, Vector3, Vector3) {
+        let orientation = self.orientation(py);
+
+        (
+            orientation.forward.to_py(py),
+            orientation.right.to_py(py),
+            orientation.up.to_py(py),
+        )
+    }
+
+    /// Returns the rotation as a unit quaternion in `(w, x, y, z)` order
+    fn to_quaternion(&self, py: Python) -> (f64, f64, f64, f64) {
+        let Quat { w, x, y, z } = self.orientation(py).to_quat();
+        (w, x, y, z)
+    }
+
+    /// Creates a rotator from a quaternion in `(w, x, y, z)` order,
+    /// which doesn't need to be normalized
+    #[staticmethod]
+    fn from_quaternion(py: Python, w: f64, x: f64, y: f64, z: f64) -> PyResult<Self> {
+        let length = (w * w + x * x + y * y + z * z).sqrt();
+        if length == 0.0 || !length.is_finite() {
+            return Err(PyValueError::new_err(
+                "A quaternion must have a finite, non-zero length",
+            ));
+        }
+
+        let quat = Quat {
+            w: w / length,
+            x: x / length,
+            y: y / length,
+            z: z / length,
+        };
+
+        Ok(Self::from_orientation(py, Orientation::from_quat(quat)))
+    }
+}
+
+#[pymethods]
+impl Physics {
+    /// Converts a point in world coordinates into coordinates relative to this object,
+    /// where +x is forward, +y is right and +z is up
+    fn to_local(&self, py: Python, point: PyRef<Vector3>) -> Vector3 {
+        let location = Vec3::from_py(py, &self.location.borrow(py));
+        let orientation = self.rotation.borrow(py).orientation(py);
+
+        orientation
+            .to_local(Vec3::from_py(py, &point) - location)
+            .to_py(py)
+    }
+
+    /// Converts a point relative to this object into world coordinates,
+    /// where +x is forward, +y is right and +z is up
+    fn to_world(&self, py: Python, local: PyRef<Vector3>) -> Vector3 {
+        let location = Vec3::from_py(py, &self.location.borrow(py));
+        let orientation = self.rotation.borrow(py).orientation(py);
+
+        (location + orientation.to_world(Vec3::from_py(py, &local))).to_py(py)
+    }
+}
@@ -0,0 +1,303 @@
+use pyo3::{
+    exceptions::{PyAttributeError, PyIndexError, PyTypeError, PyValueError},
+    intern,
+    prelude::*,
+    types::*,
+};
+
+/// Implementation of the generated `replace` methods.
+///
+/// Builds a new instance of `obj`'s class from the current field values with `changes` applied.
+/// Keys can be dotted paths (e.g. `match_info.game_speed`),
+/// in which case the nested value is replaced recursively.
+/// Like `dataclasses.replace`, unchanged fields are shared with the original instance.
+pub fn replace<'py>(
+    obj: &Bound<'py, PyAny>,
+    field_names: &[&str],
+    changes: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = obj.py();
+    let cls = obj.get_type();
+
+    let kwargs = PyDict::new(py);
+    let mut nested: Vec<(&str, Bound<'py, PyDict>)> = Vec::new();
+
+    if let Some(changes) = changes {
+        for (key, value) in changes.iter() {
+            let key = key.cast_into::<PyString>()?;
+            let key = key.to_str()?;
+            let (head, rest) = match key.split_once('.') {
+                Some((head, rest)) => (head, Some(rest)),
+                None => (key, None),
+            };
+
+            let Some(&field_name) = field_names.iter().find(|&&name| name == head) else {
+                return Err(PyTypeError::new_err(format!(
+                    "{}.replace() got an unexpected field '{head}'",
+                    cls.name()?
+                )));
+            };
+
+            let Some(rest) = rest else {
+                kwargs.set_item(field_name, value)?;
+                continue;
+            };
+
+            let nested_changes = match nested.iter().find(|(name, _)| *name == field_name) {
+                Some((_, nested_changes)) => nested_changes,
+                None => {
+                    nested.push((field_name, PyDict::new(py)));
+                    &nested.last().unwrap().1
+                }
+            };
+            nested_changes.set_item(rest, value)?;
+        }
+    }
+
+    for &field_name in field_names {
+        if !kwargs.contains(field_name)? {
+            kwargs.set_item(field_name, obj.getattr(field_name)?)?;
+        }
+    }
+
+    for (field_name, nested_changes) in nested {
+        let current = kwargs.get_item(field_name)?.unwrap();
+        if !current.hasattr(intern!(py, "__match_args__"))? {
+            return Err(PyTypeError::new_err(format!(
+                "Can't replace nested fields of '{field_name}' in {}, it is {}",
+                cls.name()?,
+                current.repr()?
+            )));
+        }
+
+        let new_value = current.call_method(intern!(py, "replace"), (), Some(&nested_changes))?;
+        kwargs.set_item(field_name, new_value)?;
+    }
+
+    cls.call((), Some(&kwargs))
+}
+
+enum Segment<'a> {
+    Field(&'a str),
+    Index(isize),
+}
+
+fn invalid_path(path: &str) -> PyErr {
+    PyValueError::new_err(format!("Invalid path '{path}'"))
+}
+
+/// Parses paths like `players[2].physics.location.x`
+fn parse(path: &str) -> PyResult<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    let mut rest = path;
+    let mut first = true;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']').ok_or_else(|| invalid_path(path))?;
+            let index = after[..end]
+                .trim()
+                .parse()
+                .map_err(|_| invalid_path(path))?;
+            segments.push(Segment::Index(index));
+            rest = &after[end + 1..];
+        } else {
+            let field = if first {
+                rest
+            } else {
+                rest.strip_prefix('.').ok_or_else(|| invalid_path(path))?
+            };
+
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            if end == 0 {
+                return Err(invalid_path(path));
+            }
+
+            segments.push(Segment::Field(&field[..end]));
+            rest = &field[end..];
+        }
+
+        first = false;
+    }
+
+    if segments.is_empty() {
+        return Err(invalid_path(path));
+    }
+
+    Ok(segments)
+}
+
+fn has_field(obj: &Bound<PyAny>, name: &str) -> PyResult<bool> {
+    let py = obj.py();
+    match obj.getattr_opt(intern!(py, "__match_args__"))? {
+        Some(field_names) => field_names.contains(name),
+        None => Ok(false),
+    }
+}
+
+fn get_segment<'py>(obj: &Bound<'py, PyAny>, segment: &Segment) -> PyResult<Bound<'py, PyAny>> {
+    match *segment {
+        Segment::Field(name) => {
+            if !has_field(obj, name)? {
+                return Err(PyAttributeError::new_err(format!(
+                    "{} has no field '{name}'",
+                    obj.get_type().name()?
+                )));
+            }
+
+            obj.getattr(name)
+        }
+        Segment::Index(index) => {
+            let list = as_list(obj)?;
+            list.get_item(normalize_index(list, index)?)
+        }
+    }
+}
+
+fn as_list<'a, 'py>(obj: &'a Bound<'py, PyAny>) -> PyResult<&'a Bound<'py, PyList>> {
+    match obj.cast::<PyList>() {
+        Ok(list) => Ok(list),
+        Err(_) => Err(PyTypeError::new_err(format!(
+            "Can't index into {}, it is not a list",
+            obj.get_type().name()?
+        ))),
+    }
+}
+
+fn normalize_index(list: &Bound<PyList>, index: isize) -> PyResult<usize> {
+    let len = list.len() as isize;
+    let normalized = if index < 0 { index + len } else { index };
+
+    if normalized < 0 || normalized >= len {
+        return Err(PyIndexError::new_err(format!(
+            "list index {index} out of range"
+        )));
+    }
+
+    Ok(normalized as usize)
+}
+
+/// Gets the value at `path` in `obj`, e.g. `get(packet, "players[2].physics.location.x")`
+#[pyfunction]
+fn get<'py>(obj: &Bound<'py, PyAny>, path: &str) -> PyResult<Bound<'py, PyAny>> {
+    let mut current = obj.clone();
+    for segment in parse(path)? {
+        current = get_segment(&current, &segment)?;
+    }
+
+    Ok(current)
+}
+
+/// Sets the value at `path` in `obj`, e.g. `set(state, "match_info.game_speed", 2)`
+///
+/// The value must be owned by a mutable type,
+/// frozen types must be changed with `replace` or their builder instead
+#[pyfunction]
+fn set(obj: &Bound<PyAny>, path: &str, value: &Bound<PyAny>) -> PyResult<()> {
+    let py = obj.py();
+    let segments = parse(path)?;
+    let (last, parents) = segments.split_last().unwrap();
+
+    // the closest object that owns the value, lists are owned by the object that contains them
+    let mut owner = obj.clone();
+    let mut parent = obj.clone();
+    for segment in parents {
+        parent = get_segment(&parent, segment)?;
+        if !parent.is_instance_of::<PyList>() {
+            owner = parent.clone();
+        }
+    }
+
+    if owner.hasattr(intern!(py, "to_builder"))? {
+        return Err(PyTypeError::new_err(format!(
+            "Can't set '{path}', {} is frozen. Use `replace` or `to_builder` instead",
+            owner.get_type().name()?
+        )));
+    }
+
+    match *last {
+        Segment::Field(name) => {
+            if !has_field(&parent, name)? {
+                return Err(PyAttributeError::new_err(format!(
+                    "{} has no field '{name}'",
+                    parent.get_type().name()?
+                )));
+            }
+
+            parent.setattr(name, value)
+        }
+        Segment::Index(index) => {
+            let list = as_list(&parent)?;
+            list.set_item(normalize_index(list, index)?, value)
+        }
+    }
+}
+
+/// Lazily yields `(path, value)` for every leaf of a generated type
+#[pyclass(module = "rlbot_flatbuffers.path")]
+pub struct Walk {
+    stack: Vec<(String, Py<PyAny>)>,
+}
+
+#[pymethods]
+impl Walk {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<(String, Py<PyAny>)>> {
+        while let Some((path, value)) = self.stack.pop() {
+            let value = value.into_bound(py);
+
+            if let Ok(list) = value.cast::<PyList>() {
+                if list.is_empty() {
+                    return Ok(Some((path, value.unbind())));
+                }
+
+                for (i, item) in list.iter().enumerate().rev() {
+                    self.stack.push((format!("{path}[{i}]"), item.unbind()));
+                }
+                continue;
+            }
+
+            let Some(field_names) = value.getattr_opt(intern!(py, "__match_args__"))? else {
+                return Ok(Some((path, value.unbind())));
+            };
+
+            let field_names: Vec<String> = field_names.extract()?;
+            for name in field_names.into_iter().rev() {
+                let item = value.getattr(name.as_str())?.unbind();
+                let item_path = if path.is_empty() {
+                    name
+                } else {
+                    format!("{path}.{name}")
+                };
+                self.stack.push((item_path, item));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Walks through every leaf of `obj`, yielding `(path, value)` in field order.
+/// Empty lists are leaves too, so they're yielded as themselves
+#[pyfunction]
+fn walk(obj: Bound<PyAny>) -> Walk {
+    Walk {
+        stack: vec![(String::new(), obj.unbind())],
+    }
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "path")?;
+    m.add(
+        "__doc__",
+        "Generic access to the fields of generated types with paths like `players[2].physics.location.x`.",
+    )?;
+    m.add_class::<Walk>()?;
+    m.add_function(wrap_pyfunction!(get, &m)?)?;
+    m.add_function(wrap_pyfunction!(set, &m)?)?;
+    m.add_function(wrap_pyfunction!(walk, &m)?)?;
+    Ok(m)
+}
@@ -8969,14 +8969,7 @@ mod root {
             /// * Enum `AirState` in the file `flatbuffers-schema/schema/gamedata.fbs:133`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum AirState {
                 #[default]
                 ///  All wheels are on the ground and the car is affected by wall-stickiness forces.
@@ -11507,14 +11500,7 @@ mod root {
             /// * Enum `MatchPhase` in the file `flatbuffers-schema/schema/gamedata.fbs:274`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum MatchPhase {
                 #[default]
                 ///  Match has not been created yet.
@@ -18193,14 +18179,7 @@ mod root {
             /// * Enum `PsyonixSkill` in the file `flatbuffers-schema/schema/matchconfig.fbs:35`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum PsyonixSkill {
                 #[default]
                 /// The variant `Beginner` in the enum `PsyonixSkill`
@@ -21383,14 +21362,7 @@ mod root {
             /// * Enum `GameMode` in the file `flatbuffers-schema/schema/matchconfig.fbs:118`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum GameMode {
                 #[default]
                 /// The variant `Soccar` in the enum `GameMode`
@@ -21589,14 +21561,7 @@ mod root {
             /// * Enum `MatchLengthMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:130`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum MatchLengthMutator {
                 #[default]
                 /// The variant `FiveMinutes` in the enum `MatchLengthMutator`
@@ -21775,14 +21740,7 @@ mod root {
             /// * Enum `MaxScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:138`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum MaxScoreMutator {
                 #[default]
                 /// The variant `Unlimited` in the enum `MaxScoreMutator`
@@ -22016,14 +21974,7 @@ mod root {
             /// * Enum `MultiBallMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:157`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum MultiBallMutator {
                 #[default]
                 /// The variant `One` in the enum `MultiBallMutator`
@@ -22197,14 +22148,7 @@ mod root {
             /// * Enum `OvertimeMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:165`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum OvertimeMutator {
                 #[default]
                 /// The variant `Unlimited` in the enum `OvertimeMutator`
@@ -22378,14 +22322,7 @@ mod root {
             /// * Enum `SeriesLengthMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:172`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum SeriesLengthMutator {
                 #[default]
                 /// The variant `Unlimited` in the enum `SeriesLengthMutator`
@@ -22564,14 +22501,7 @@ mod root {
             /// * Enum `GameSpeedMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:180`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum GameSpeedMutator {
                 #[default]
                 /// The variant `Default` in the enum `GameSpeedMutator`
@@ -22741,14 +22671,7 @@ mod root {
             /// * Enum `BallMaxSpeedMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:187`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallMaxSpeedMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallMaxSpeedMutator`
@@ -22923,14 +22846,7 @@ mod root {
             /// * Enum `BallTypeMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:195`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallTypeMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallTypeMutator`
@@ -23164,14 +23080,7 @@ mod root {
             /// * Enum `BallWeightMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:214`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallWeightMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallWeightMutator`
@@ -23370,14 +23279,7 @@ mod root {
             /// * Enum `BallSizeMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:226`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallSizeMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallSizeMutator`
@@ -23561,14 +23463,7 @@ mod root {
             /// * Enum `BallBouncinessMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:235`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallBouncinessMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallBouncinessMutator`
@@ -23754,14 +23649,7 @@ mod root {
             /// * Enum `BallGravityMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:244`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BallGravityMutator {
                 #[default]
                 /// The variant `Default` in the enum `BallGravityMutator`
@@ -23936,14 +23824,7 @@ mod root {
             /// * Enum `BoostAmountMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:252`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BoostAmountMutator {
                 #[default]
                 /// The variant `NormalBoost` in the enum `BoostAmountMutator`
@@ -24127,14 +24008,7 @@ mod root {
             /// * Enum `RumbleMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:261`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum RumbleMutator {
                 #[default]
                 /// The variant `Off` in the enum `RumbleMutator`
@@ -24368,14 +24242,7 @@ mod root {
             /// * Enum `BoostStrengthMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:280`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum BoostStrengthMutator {
                 #[default]
                 /// The variant `One` in the enum `BoostStrengthMutator`
@@ -24559,14 +24426,7 @@ mod root {
             /// * Enum `GravityMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:289`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum GravityMutator {
                 #[default]
                 /// The variant `Default` in the enum `GravityMutator`
@@ -24750,14 +24610,7 @@ mod root {
             /// * Enum `DemolishMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:298`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum DemolishMutator {
                 #[default]
                 /// The variant `Default` in the enum `DemolishMutator`
@@ -24961,14 +24814,7 @@ mod root {
             /// * Enum `RespawnTimeMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:311`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum RespawnTimeMutator {
                 #[default]
                 /// The variant `ThreeSeconds` in the enum `RespawnTimeMutator`
@@ -25147,14 +24993,7 @@ mod root {
             /// * Enum `MaxTimeMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:319`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum MaxTimeMutator {
                 #[default]
                 /// The variant `Unlimited` in the enum `MaxTimeMutator`
@@ -25320,14 +25159,7 @@ mod root {
             /// * Enum `GameEventMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:325`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum GameEventMutator {
                 #[default]
                 /// The variant `Default` in the enum `GameEventMutator`
@@ -25497,14 +25329,7 @@ mod root {
             /// * Enum `AudioMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:332`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum AudioMutator {
                 #[default]
                 /// The variant `Default` in the enum `AudioMutator`
@@ -25670,14 +25495,7 @@ mod root {
             /// * Enum `TerritoryMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:337`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum TerritoryMutator {
                 #[default]
                 /// The variant `Off` in the enum `TerritoryMutator`
@@ -25843,14 +25661,7 @@ mod root {
             /// * Enum `StaleBallMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:342`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum StaleBallMutator {
                 #[default]
                 /// The variant `Unlimited` in the enum `StaleBallMutator`
@@ -26016,14 +25827,7 @@ mod root {
             /// * Enum `JumpMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:347`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum JumpMutator {
                 #[default]
                 /// The variant `Default` in the enum `JumpMutator`
@@ -26217,14 +26021,7 @@ mod root {
             /// * Enum `DodgeTimerMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:357`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum DodgeTimerMutator {
                 #[default]
                 /// The variant `OnePointTwentyFiveSeconds` in the enum `DodgeTimerMutator`
@@ -26405,14 +26202,7 @@ mod root {
             /// * Enum `PossessionScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:364`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum PossessionScoreMutator {
                 #[default]
                 /// The variant `Off` in the enum `PossessionScoreMutator`
@@ -26593,14 +26383,7 @@ mod root {
             /// * Enum `DemolishScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:371`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum DemolishScoreMutator {
                 #[default]
                 /// The variant `Zero` in the enum `DemolishScoreMutator`
@@ -26774,14 +26557,7 @@ mod root {
             /// * Enum `NormalGoalScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:378`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum NormalGoalScoreMutator {
                 #[default]
                 /// The variant `One` in the enum `NormalGoalScoreMutator`
@@ -26972,14 +26748,7 @@ mod root {
             /// * Enum `AerialGoalScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:387`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum AerialGoalScoreMutator {
                 #[default]
                 /// The variant `One` in the enum `AerialGoalScoreMutator`
@@ -27170,14 +26939,7 @@ mod root {
             /// * Enum `AssistGoalScoreMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:396`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum AssistGoalScoreMutator {
                 #[default]
                 /// The variant `Zero` in the enum `AssistGoalScoreMutator`
@@ -27353,14 +27115,7 @@ mod root {
             /// * Enum `InputRestrictionMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:403`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum InputRestrictionMutator {
                 #[default]
                 /// The variant `Default` in the enum `InputRestrictionMutator`
@@ -27528,14 +27283,7 @@ mod root {
             /// * Enum `ScoringRuleMutator` in the file `flatbuffers-schema/schema/matchconfig.fbs:408`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum ScoringRuleMutator {
                 #[default]
                 /// The variant `Default` in the enum `ScoringRuleMutator`
@@ -32236,14 +31984,7 @@ mod root {
             /// * Enum `DebugRendering` in the file `flatbuffers-schema/schema/matchconfig.fbs:513`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum DebugRendering {
                 #[default]
                 ///  Renders are ignored unless explicitly enabled per-agent
@@ -32414,14 +32155,7 @@ mod root {
             /// * Enum `ExistingMatchBehavior` in the file `flatbuffers-schema/schema/matchconfig.fbs:525`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum ExistingMatchBehavior {
                 #[default]
                 ///  Always restart the match, even if config is identical.
@@ -32599,14 +32333,7 @@ mod root {
             /// * Enum `Launcher` in the file `flatbuffers-schema/schema/matchconfig.fbs:539`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum Launcher {
                 #[default]
                 /// The variant `Steam` in the enum `Launcher`
@@ -34592,14 +34319,7 @@ mod root {
             /// * Enum `TextHAlign` in the file `flatbuffers-schema/schema/rendering.fbs:7`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum TextHAlign {
                 #[default]
                 /// The variant `Left` in the enum `TextHAlign`
@@ -34769,14 +34489,7 @@ mod root {
             /// * Enum `TextVAlign` in the file `flatbuffers-schema/schema/rendering.fbs:14`
             #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
             #[repr(u8)]
-            #[::pyo3::pyclass(
-                module = "rlbot_flatbuffers",
-                from_py_object,
-                frozen,
-                hash,
-                eq,
-                eq_int
-            )]
+            #[::pyo3::pyclass(module = "rlbot_flatbuffers", from_py_object, frozen)]
             pub enum TextVAlign {
                 #[default]
                 /// The variant `Top` in the enum `TextVAlign`
@@ -0,0 +1,302 @@
+//! Time queries and trajectory searches on `BallPrediction`.
+//!
+//! The slices are searched by their `game_seconds`, which are expected to be sorted
+//! like they are in the ball predictions that RLBot sends (120 per second, for 6 seconds).
+
+use crate::{
+    arena::BALL_RADIUS,
+    math::Vec3,
+    orientation::{Orientation, Quat},
+    python::{BallPrediction, GoalInfo, Physics, PredictionSlice, Rotator, Vector3},
+};
+use pyo3::{prelude::*, types::*};
+use std::ops::Range;
+
+pub fn slice_at<'py>(
+    slices: &Bound<'py, PyList>,
+    index: usize,
+) -> PyResult<Bound<'py, PredictionSlice>> {
+    Ok(slices.get_item(index)?.cast_into::<PredictionSlice>()?)
+}
+
+pub fn game_seconds(slice: &Bound<PredictionSlice>) -> f64 {
+    slice.get().game_seconds.bind(slice.py()).value()
+}
+
+pub fn location(slice: &Bound<PredictionSlice>) -> Vec3 {
+    let py = slice.py();
+    Vec3::from_py(py, &slice.get().physics.get().location.borrow(py))
+}
+
+/// The index of the first slice at or after `seconds`, or the number of slices if there isn't one
+fn seek(slices: &Bound<PyList>, seconds: f64) -> PyResult<usize> {
+    let (mut low, mut high) = (0, slices.len());
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if game_seconds(&slice_at(slices, mid)?) < seconds {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(low)
+}
+
+fn lerp_quat(a: Quat, b: Quat, t: f64) -> Quat {
+    // take the shortest way around
+    let sign = if a.w * b.w + a.x * b.x + a.y * b.y + a.z * b.z < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+
+    let w = a.w + (sign * b.w - a.w) * t;
+    let x = a.x + (sign * b.x - a.x) * t;
+    let y = a.y + (sign * b.y - a.y) * t;
+    let z = a.z + (sign * b.z - a.z) * t;
+    let length = (w * w + x * x + y * y + z * z).sqrt();
+
+    Quat {
+        w: w / length,
+        x: x / length,
+        y: y / length,
+        z: z / length,
+    }
+}
+
+/// Interpolates the physics of two slices, with the rotation interpolated through quaternions
+fn lerp_physics(py: Python, a: &Physics, b: &Physics, t: f64) -> PyResult<Physics> {
+    let lerp = |a: &Py<Vector3>, b: &Py<Vector3>| {
+        Py::new(
+            py,
+            Vec3::from_py(py, &a.borrow(py))
+                .lerp(Vec3::from_py(py, &b.borrow(py)), t)
+                .to_py(py),
+        )
+    };
+
+    let rotation = lerp_quat(
+        a.rotation.borrow(py).orientation(py).to_quat(),
+        b.rotation.borrow(py).orientation(py).to_quat(),
+        t,
+    );
+
+    Ok(Physics {
+        location: lerp(&a.location, &b.location)?,
+        rotation: Py::new(
+            py,
+            Rotator::from_orientation(py, Orientation::from_quat(rotation)),
+        )?,
+        velocity: lerp(&a.velocity, &b.velocity)?,
+        angular_velocity: lerp(&a.angular_velocity, &b.angular_velocity)?,
+    })
+}
+
+/// A goal that the ball can enter
+struct Goal {
+    location: Vec3,
+    /// Points out of the goal, towards the field
+    direction: Vec3,
+    width: f64,
+    height: f64,
+}
+
+impl Goal {
+    fn from_py(py: Python, goal: &GoalInfo) -> Self {
+        Self {
+            location: Vec3::from_py(py, &goal.location.borrow(py)),
+            direction: Vec3::from_py(py, &goal.direction.borrow(py)),
+            width: goal.width.bind(py).value(),
+            height: goal.height.bind(py).value(),
+        }
+    }
+
+    /// Whether the whole ball is past the goal line, between the posts and under the crossbar
+    fn contains(&self, location: Vec3) -> bool {
+        let offset = location - self.location;
+        let right = self.direction.cross(Vec3::new(0.0, 0.0, 1.0)).normalized();
+
+        -offset.dot(self.direction) > BALL_RADIUS
+            && offset.dot(right).abs() < self.width / 2.0
+            && offset.z.abs() < self.height / 2.0
+    }
+}
+
+/// The conditions that a slice must meet in a search, which all have to be met
+struct Conditions {
+    min_height: Option<f64>,
+    max_height: Option<f64>,
+    region: Option<(Vec3, Vec3)>,
+    goal: Option<Goal>,
+}
+
+impl Conditions {
+    fn from_py(
+        py: Python,
+        min_height: Option<f64>,
+        max_height: Option<f64>,
+        region: Option<(PyRef<Vector3>, PyRef<Vector3>)>,
+        goal: Option<PyRef<GoalInfo>>,
+    ) -> Self {
+        let region = region.map(|(a, b)| {
+            let a = Vec3::from_py(py, &a);
+            let b = Vec3::from_py(py, &b);
+
+            (
+                Vec3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+                Vec3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+            )
+        });
+
+        Self {
+            min_height,
+            max_height,
+            region,
+            goal: goal.map(|goal| Goal::from_py(py, &goal)),
+        }
+    }
+
+    fn matches(&self, location: Vec3) -> bool {
+        self.min_height.is_none_or(|min| location.z >= min)
+            && self.max_height.is_none_or(|max| location.z <= max)
+            && self.region.is_none_or(|(min, max)| {
+                (min.x..=max.x).contains(&location.x)
+                    && (min.y..=max.y).contains(&location.y)
+                    && (min.z..=max.z).contains(&location.z)
+            })
+            && self
+                .goal
+                .as_ref()
+                .is_none_or(|goal| goal.contains(location))
+    }
+}
+
+/// The indices of the slices in `range` where the ball meets `conditions`
+fn search<'a>(
+    slices: &'a Bound<PyList>,
+    conditions: &'a Conditions,
+    range: Range<usize>,
+) -> impl Iterator<Item = PyResult<usize>> + 'a {
+    range.filter_map(move |index| match slice_at(slices, index) {
+        Ok(slice) => conditions.matches(location(&slice)).then_some(Ok(index)),
+        Err(err) => Some(Err(err)),
+    })
+}
+
+impl BallPrediction {
+    /// The indices of the slices from `start_seconds` up to (but not including) `stop_seconds`
+    pub fn time_range(
+        &self,
+        py: Python,
+        start_seconds: Option<f64>,
+        stop_seconds: Option<f64>,
+    ) -> PyResult<Range<usize>> {
+        let slices = self.slices.bind(py);
+
+        let start = match start_seconds {
+            Some(seconds) => seek(slices, seconds)?,
+            None => 0,
+        };
+        let stop = match stop_seconds {
+            Some(seconds) => seek(slices, seconds)?,
+            None => slices.len(),
+        };
+
+        Ok(start..stop.max(start))
+    }
+}
+
+#[pymethods]
+impl BallPrediction {
+    /// The predicted state of the ball at `game_seconds`, interpolated between the two closest slices,
+    /// or `None` if `game_seconds` isn't within the prediction
+    fn state_at(&self, py: Python, game_seconds: f64) -> PyResult<Option<PredictionSlice>> {
+        let slices = self.slices.bind(py);
+        let index = seek(slices, game_seconds)?;
+        if index == slices.len() {
+            return Ok(None);
+        }
+
+        let after = slice_at(slices, index)?;
+        let after_seconds = self::game_seconds(&after);
+
+        let (before, t) = if after_seconds == game_seconds {
+            (after.clone(), 0.0)
+        } else if index == 0 {
+            return Ok(None);
+        } else {
+            let before = slice_at(slices, index - 1)?;
+            let before_seconds = self::game_seconds(&before);
+            let t = (game_seconds - before_seconds) / (after_seconds - before_seconds);
+            (before, t)
+        };
+
+        let physics = lerp_physics(py, before.get().physics.get(), after.get().physics.get(), t)?;
+
+        Ok(Some(PredictionSlice {
+            game_seconds: PyFloat::new(py, game_seconds).unbind(),
+            physics: Py::new(py, physics)?,
+        }))
+    }
+
+    /// The indices of the slices from `start_seconds` up to (but not including) `stop_seconds`,
+    /// found with a binary search
+    #[pyo3(name = "time_range", signature = (start_seconds=None, stop_seconds=None))]
+    fn py_time_range<'py>(
+        &self,
+        py: Python<'py>,
+        start_seconds: Option<f64>,
+        stop_seconds: Option<f64>,
+    ) -> PyResult<Bound<'py, PyRange>> {
+        let range = self.time_range(py, start_seconds, stop_seconds)?;
+        PyRange::new(py, range.start as isize, range.end as isize)
+    }
+
+    /// The index of the first slice where the ball meets all of the given conditions,
+    /// or `None` if there isn't one.
+    /// The ball's center must be between `min_height` and `max_height`,
+    /// inside the box between the two corners of `region`,
+    /// and entirely inside `goal` (past the goal line, between the posts and under the crossbar).
+    /// The search can be limited to the slices from `start_seconds` up to (but not including) `stop_seconds`
+    #[pyo3(signature = (*, min_height=None, max_height=None, region=None, goal=None, start_seconds=None, stop_seconds=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn find_first(
+        &self,
+        py: Python,
+        min_height: Option<f64>,
+        max_height: Option<f64>,
+        region: Option<(PyRef<Vector3>, PyRef<Vector3>)>,
+        goal: Option<PyRef<GoalInfo>>,
+        start_seconds: Option<f64>,
+        stop_seconds: Option<f64>,
+    ) -> PyResult<Option<usize>> {
+        let conditions = Conditions::from_py(py, min_height, max_height, region, goal);
+        let range = self.time_range(py, start_seconds, stop_seconds)?;
+
+        search(self.slices.bind(py), &conditions, range)
+            .next()
+            .transpose()
+    }
+
+    /// The indices of every slice where the ball meets all of the given conditions,
+    /// which are the same as in `find_first`
+    #[pyo3(signature = (*, min_height=None, max_height=None, region=None, goal=None, start_seconds=None, stop_seconds=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn find_all(
+        &self,
+        py: Python,
+        min_height: Option<f64>,
+        max_height: Option<f64>,
+        region: Option<(PyRef<Vector3>, PyRef<Vector3>)>,
+        goal: Option<PyRef<GoalInfo>>,
+        start_seconds: Option<f64>,
+        stop_seconds: Option<f64>,
+    ) -> PyResult<Vec<usize>> {
+        let conditions = Conditions::from_py(py, min_height, max_height, region, goal);
+        let range = self.time_range(py, start_seconds, stop_seconds)?;
+
+        search(self.slices.bind(py), &conditions, range).collect()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::AerialGoalScoreMutator;
 
@@ -19,6 +19,74 @@ impl AerialGoalScoreMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "one" => Ok(Self::One),
+            "zero" => Ok(Self::Zero),
+            "two" => Ok(Self::Two),
+            "three" => Ok(Self::Three),
+            "five" => Ok(Self::Five),
+            "ten" => Ok(Self::Ten),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown AerialGoalScoreMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("One", Self::One)?;
+        members.set_item("Zero", Self::Zero)?;
+        members.set_item("Two", Self::Two)?;
+        members.set_item("Three", Self::Three)?;
+        members.set_item("Five", Self::Five)?;
+        members.set_item("Ten", Self::Ten)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::One,
+            Self::Zero,
+            Self::Two,
+            Self::Three,
+            Self::Five,
+            Self::Ten,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::One => "One",
+            Self::Zero => "Zero",
+            Self::Two => "Two",
+            Self::Three => "Three",
+            Self::Five => "Five",
+            Self::Ten => "Ten",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
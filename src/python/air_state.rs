@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::AirState;
 
@@ -18,6 +18,70 @@ impl AirState {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "onground" => Ok(Self::OnGround),
+            "jumping" => Ok(Self::Jumping),
+            "doublejumping" => Ok(Self::DoubleJumping),
+            "dodging" => Ok(Self::Dodging),
+            "inair" => Ok(Self::InAir),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown AirState name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("OnGround", Self::OnGround)?;
+        members.set_item("Jumping", Self::Jumping)?;
+        members.set_item("DoubleJumping", Self::DoubleJumping)?;
+        members.set_item("Dodging", Self::Dodging)?;
+        members.set_item("InAir", Self::InAir)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::OnGround,
+            Self::Jumping,
+            Self::DoubleJumping,
+            Self::Dodging,
+            Self::InAir,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::OnGround => "OnGround",
+            Self::Jumping => "Jumping",
+            Self::DoubleJumping => "DoubleJumping",
+            Self::Dodging => "Dodging",
+            Self::InAir => "InAir",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
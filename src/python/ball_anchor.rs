@@ -86,4 +86,12 @@ impl BallAnchor {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["index", "local"], changes)
+    }
 }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::BallGravityMutator;
 
@@ -17,6 +17,61 @@ impl BallGravityMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "low" => Ok(Self::Low),
+            "high" => Ok(Self::High),
+            "superhigh" => Ok(Self::SuperHigh),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown BallGravityMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Low", Self::Low)?;
+        members.set_item("High", Self::High)?;
+        members.set_item("SuperHigh", Self::SuperHigh)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![Self::Default, Self::Low, Self::High, Self::SuperHigh]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Low => "Low",
+            Self::High => "High",
+            Self::SuperHigh => "SuperHigh",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
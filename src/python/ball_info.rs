@@ -89,4 +89,75 @@ impl BallInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["physics", "shape"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> BallInfoBuilder {
+        BallInfoBuilder {
+            physics: self.physics.clone_ref(py),
+            shape: self.shape.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct BallInfoBuilder {
+    #[pyo3(set)]
+    pub physics: Py<super::Physics>,
+    #[pyo3(set)]
+    pub shape: Py<PyAny>,
+}
+
+impl BallInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> BallInfo {
+        BallInfo {
+            physics: self.physics.clone_ref(py),
+            shape: self.shape.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl BallInfoBuilder {
+    #[new]
+    #[pyo3(signature = (physics=None, shape=None))]
+    pub fn new(py: Python, physics: Option<Py<super::Physics>>, shape: Option<Py<PyAny>>) -> Self {
+        Self {
+            physics: physics.unwrap_or_else(|| super::Physics::py_default(py)),
+            shape: shape.unwrap_or_else(|| super::CollisionShape::py_default(py)),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("BallInfoBuilder{}", &repr[8..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("physics", "shape")
+    }
+
+    fn build(&self, py: Python) -> Py<BallInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::BallInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::BallMaxSpeedMutator;
 
@@ -17,6 +17,61 @@ impl BallMaxSpeedMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "slow" => Ok(Self::Slow),
+            "fast" => Ok(Self::Fast),
+            "superfast" => Ok(Self::SuperFast),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown BallMaxSpeedMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Slow", Self::Slow)?;
+        members.set_item("Fast", Self::Fast)?;
+        members.set_item("SuperFast", Self::SuperFast)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![Self::Default, Self::Slow, Self::Fast, Self::SuperFast]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Slow => "Slow",
+            Self::Fast => "Fast",
+            Self::SuperFast => "SuperFast",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -100,4 +100,74 @@ impl BallPrediction {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["slices"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> BallPredictionBuilder {
+        BallPredictionBuilder {
+            slices: PyList::new(py, self.slices.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct BallPredictionBuilder {
+    #[pyo3(set)]
+    pub slices: Py<PyList>,
+}
+
+impl BallPredictionBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> BallPrediction {
+        BallPrediction {
+            slices: PyList::new(py, self.slices.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pymethods]
+impl BallPredictionBuilder {
+    #[new]
+    #[pyo3(signature = (slices=None))]
+    pub fn new(py: Python, slices: Option<Py<PyList>>) -> Self {
+        Self {
+            slices: slices.unwrap_or_else(|| PyList::empty(py).unbind()),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("BallPredictionBuilder{}", &repr[14..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str,) {
+        ("slices",)
+    }
+
+    fn build(&self, py: Python) -> Py<BallPrediction> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::BallPrediction::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
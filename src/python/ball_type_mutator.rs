@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::BallTypeMutator;
 
@@ -28,6 +28,110 @@ impl BallTypeMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "cube" => Ok(Self::Cube),
+            "puck" => Ok(Self::Puck),
+            "basketball" => Ok(Self::Basketball),
+            "beachball" => Ok(Self::Beachball),
+            "anniversary" => Ok(Self::Anniversary),
+            "haunted" => Ok(Self::Haunted),
+            "ekin" => Ok(Self::Ekin),
+            "spookycube" => Ok(Self::SpookyCube),
+            "egg" => Ok(Self::Egg),
+            "playerseeking" => Ok(Self::PlayerSeeking),
+            "dropshot" => Ok(Self::Dropshot),
+            "scoreabsorb" => Ok(Self::ScoreAbsorb),
+            "shoe" => Ok(Self::Shoe),
+            "pizzapuck" => Ok(Self::PizzaPuck),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown BallTypeMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Cube", Self::Cube)?;
+        members.set_item("Puck", Self::Puck)?;
+        members.set_item("Basketball", Self::Basketball)?;
+        members.set_item("Beachball", Self::Beachball)?;
+        members.set_item("Anniversary", Self::Anniversary)?;
+        members.set_item("Haunted", Self::Haunted)?;
+        members.set_item("Ekin", Self::Ekin)?;
+        members.set_item("SpookyCube", Self::SpookyCube)?;
+        members.set_item("Egg", Self::Egg)?;
+        members.set_item("PlayerSeeking", Self::PlayerSeeking)?;
+        members.set_item("Dropshot", Self::Dropshot)?;
+        members.set_item("ScoreAbsorb", Self::ScoreAbsorb)?;
+        members.set_item("Shoe", Self::Shoe)?;
+        members.set_item("PizzaPuck", Self::PizzaPuck)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Default,
+            Self::Cube,
+            Self::Puck,
+            Self::Basketball,
+            Self::Beachball,
+            Self::Anniversary,
+            Self::Haunted,
+            Self::Ekin,
+            Self::SpookyCube,
+            Self::Egg,
+            Self::PlayerSeeking,
+            Self::Dropshot,
+            Self::ScoreAbsorb,
+            Self::Shoe,
+            Self::PizzaPuck,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Cube => "Cube",
+            Self::Puck => "Puck",
+            Self::Basketball => "Basketball",
+            Self::Beachball => "Beachball",
+            Self::Anniversary => "Anniversary",
+            Self::Haunted => "Haunted",
+            Self::Ekin => "Ekin",
+            Self::SpookyCube => "SpookyCube",
+            Self::Egg => "Egg",
+            Self::PlayerSeeking => "PlayerSeeking",
+            Self::Dropshot => "Dropshot",
+            Self::ScoreAbsorb => "ScoreAbsorb",
+            Self::Shoe => "Shoe",
+            Self::PizzaPuck => "PizzaPuck",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::BallWeightMutator;
 
@@ -21,6 +21,82 @@ impl BallWeightMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "light" => Ok(Self::Light),
+            "heavy" => Ok(Self::Heavy),
+            "superlight" => Ok(Self::SuperLight),
+            "curveball" => Ok(Self::CurveBall),
+            "beachballcurve" => Ok(Self::BeachBallCurve),
+            "magnusfutball" => Ok(Self::MagnusFutBall),
+            "magnusfutballless" => Ok(Self::MagnusFutballLess),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown BallWeightMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Light", Self::Light)?;
+        members.set_item("Heavy", Self::Heavy)?;
+        members.set_item("SuperLight", Self::SuperLight)?;
+        members.set_item("CurveBall", Self::CurveBall)?;
+        members.set_item("BeachBallCurve", Self::BeachBallCurve)?;
+        members.set_item("MagnusFutBall", Self::MagnusFutBall)?;
+        members.set_item("MagnusFutballLess", Self::MagnusFutballLess)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Default,
+            Self::Light,
+            Self::Heavy,
+            Self::SuperLight,
+            Self::CurveBall,
+            Self::BeachBallCurve,
+            Self::MagnusFutBall,
+            Self::MagnusFutballLess,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Light => "Light",
+            Self::Heavy => "Heavy",
+            Self::SuperLight => "SuperLight",
+            Self::CurveBall => "CurveBall",
+            Self::BeachBallCurve => "BeachBallCurve",
+            Self::MagnusFutBall => "MagnusFutBall",
+            Self::MagnusFutballLess => "MagnusFutballLess",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::BoostAmountMutator;
 
@@ -18,6 +18,70 @@ impl BoostAmountMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "normalboost" => Ok(Self::NormalBoost),
+            "unlimitedboost" => Ok(Self::UnlimitedBoost),
+            "slowrecharge" => Ok(Self::SlowRecharge),
+            "rapidrecharge" => Ok(Self::RapidRecharge),
+            "noboost" => Ok(Self::NoBoost),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown BoostAmountMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("NormalBoost", Self::NormalBoost)?;
+        members.set_item("UnlimitedBoost", Self::UnlimitedBoost)?;
+        members.set_item("SlowRecharge", Self::SlowRecharge)?;
+        members.set_item("RapidRecharge", Self::RapidRecharge)?;
+        members.set_item("NoBoost", Self::NoBoost)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::NormalBoost,
+            Self::UnlimitedBoost,
+            Self::SlowRecharge,
+            Self::RapidRecharge,
+            Self::NoBoost,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NormalBoost => "NormalBoost",
+            Self::UnlimitedBoost => "UnlimitedBoost",
+            Self::SlowRecharge => "SlowRecharge",
+            Self::RapidRecharge => "RapidRecharge",
+            Self::NoBoost => "NoBoost",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -84,4 +84,75 @@ impl BoostPad {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["location", "is_full_boost"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> BoostPadBuilder {
+        BoostPadBuilder {
+            location: self.location.clone_ref(py),
+            is_full_boost: self.is_full_boost,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct BoostPadBuilder {
+    #[pyo3(set)]
+    pub location: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub is_full_boost: bool,
+}
+
+impl BoostPadBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> BoostPad {
+        BoostPad {
+            location: self.location.clone_ref(py),
+            is_full_boost: self.is_full_boost,
+        }
+    }
+}
+
+#[pymethods]
+impl BoostPadBuilder {
+    #[new]
+    #[pyo3(signature = (location=None, is_full_boost=false))]
+    pub fn new(py: Python, location: Option<Py<super::Vector3>>, is_full_boost: bool) -> Self {
+        Self {
+            location: location.unwrap_or_else(|| super::Vector3::py_default(py)),
+            is_full_boost,
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("BoostPadBuilder{}", &repr[8..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("location", "is_full_boost")
+    }
+
+    fn build(&self, py: Python) -> Py<BoostPad> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::BoostPad::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
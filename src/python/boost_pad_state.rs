@@ -99,4 +99,83 @@ impl BoostPadState {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["is_active", "timer"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> BoostPadStateBuilder {
+        BoostPadStateBuilder {
+            is_active: self.is_active,
+            timer: self.timer.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct BoostPadStateBuilder {
+    /// True if the boost can be picked up right now.
+    #[pyo3(set)]
+    pub is_active: bool,
+    /// The number of seconds since the boost has been picked up, or 0 if the boost is active.
+    /// A big boost pad becomes active again after 10 seconds.
+    /// A small boost pad becomes active again after 4 seconds.
+    pub timer: Py<PyFloat>,
+}
+
+impl BoostPadStateBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> BoostPadState {
+        BoostPadState {
+            is_active: self.is_active,
+            timer: self.timer.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl BoostPadStateBuilder {
+    #[new]
+    #[pyo3(signature = (is_active=false, timer=0.0))]
+    pub fn new(py: Python, is_active: bool, timer: f64) -> Self {
+        Self {
+            is_active,
+            timer: PyFloat::new(py, timer).unbind(),
+        }
+    }
+
+    #[setter]
+    pub fn timer(&mut self, py: Python, value: f64) {
+        self.timer = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("BoostPadStateBuilder{}", &repr[13..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("is_active", "timer")
+    }
+
+    fn build(&self, py: Python) -> Py<BoostPadState> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::BoostPadState::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -88,4 +88,92 @@ impl BoxShape {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["length", "width", "height"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> BoxShapeBuilder {
+        BoxShapeBuilder {
+            length: self.length.clone_ref(py),
+            width: self.width.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct BoxShapeBuilder {
+    pub length: Py<PyFloat>,
+    pub width: Py<PyFloat>,
+    pub height: Py<PyFloat>,
+}
+
+impl BoxShapeBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> BoxShape {
+        BoxShape {
+            length: self.length.clone_ref(py),
+            width: self.width.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl BoxShapeBuilder {
+    #[new]
+    #[pyo3(signature = (length=0.0, width=0.0, height=0.0))]
+    pub fn new(py: Python, length: f64, width: f64, height: f64) -> Self {
+        Self {
+            length: PyFloat::new(py, length).unbind(),
+            width: PyFloat::new(py, width).unbind(),
+            height: PyFloat::new(py, height).unbind(),
+        }
+    }
+
+    #[setter]
+    pub fn length(&mut self, py: Python, value: f64) {
+        self.length = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn width(&mut self, py: Python, value: f64) {
+        self.width = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn height(&mut self, py: Python, value: f64) {
+        self.height = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("BoxShapeBuilder{}", &repr[8..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str, &'static str) {
+        ("length", "width", "height")
+    }
+
+    fn build(&self, py: Python) -> Py<BoxShape> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::BoxShape::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
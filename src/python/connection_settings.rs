@@ -111,4 +111,21 @@ impl ConnectionSettings {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "agent_id",
+                "wants_ball_predictions",
+                "wants_comms",
+                "close_between_matches",
+            ],
+            changes,
+        )
+    }
 }
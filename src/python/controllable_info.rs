@@ -80,4 +80,72 @@ impl ControllableInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["index", "identifier"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> ControllableInfoBuilder {
+        ControllableInfoBuilder {
+            index: self.index,
+            identifier: self.identifier,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct ControllableInfoBuilder {
+    #[pyo3(set)]
+    pub index: u32,
+    #[pyo3(set)]
+    pub identifier: i32,
+}
+
+impl ControllableInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> ControllableInfo {
+        ControllableInfo {
+            index: self.index,
+            identifier: self.identifier,
+        }
+    }
+}
+
+#[pymethods]
+impl ControllableInfoBuilder {
+    #[new]
+    #[pyo3(signature = (index=0, identifier=0))]
+    pub fn new(index: u32, identifier: i32) -> Self {
+        Self { index, identifier }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("ControllableInfoBuilder{}", &repr[16..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("index", "identifier")
+    }
+
+    fn build(&self, py: Python) -> Py<ControllableInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::ControllableInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
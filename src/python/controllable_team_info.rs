@@ -107,4 +107,79 @@ impl ControllableTeamInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["team", "controllables"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> ControllableTeamInfoBuilder {
+        ControllableTeamInfoBuilder {
+            team: self.team,
+            controllables: PyList::new(py, self.controllables.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct ControllableTeamInfoBuilder {
+    #[pyo3(set)]
+    pub team: u32,
+    #[pyo3(set)]
+    pub controllables: Py<PyList>,
+}
+
+impl ControllableTeamInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> ControllableTeamInfo {
+        ControllableTeamInfo {
+            team: self.team,
+            controllables: PyList::new(py, self.controllables.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pymethods]
+impl ControllableTeamInfoBuilder {
+    #[new]
+    #[pyo3(signature = (team=0, controllables=None))]
+    pub fn new(py: Python, team: u32, controllables: Option<Py<PyList>>) -> Self {
+        Self {
+            team,
+            controllables: controllables.unwrap_or_else(|| PyList::empty(py).unbind()),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("ControllableTeamInfoBuilder{}", &repr[20..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("team", "controllables")
+    }
+
+    fn build(&self, py: Python) -> Py<ControllableTeamInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::ControllableTeamInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
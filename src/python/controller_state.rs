@@ -207,4 +207,26 @@ impl ControllerState {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "throttle",
+                "steer",
+                "pitch",
+                "yaw",
+                "roll",
+                "jump",
+                "boost",
+                "handbrake",
+                "use_item",
+            ],
+            changes,
+        )
+    }
 }
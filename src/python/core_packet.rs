@@ -83,4 +83,70 @@ impl CorePacket {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["message"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> CorePacketBuilder {
+        CorePacketBuilder {
+            message: self.message.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct CorePacketBuilder {
+    #[pyo3(set)]
+    pub message: Py<PyAny>,
+}
+
+impl CorePacketBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> CorePacket {
+        CorePacket {
+            message: self.message.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl CorePacketBuilder {
+    #[new]
+    #[pyo3(signature = (message=None))]
+    pub fn new(py: Python, message: Option<Py<PyAny>>) -> Self {
+        Self {
+            message: message.unwrap_or_else(|| super::CoreMessage::py_default(py)),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("CorePacketBuilder{}", &repr[10..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str,) {
+        ("message",)
+    }
+
+    fn build(&self, py: Python) -> Py<CorePacket> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::CorePacket::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
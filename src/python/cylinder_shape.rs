@@ -83,4 +83,83 @@ impl CylinderShape {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["diameter", "height"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> CylinderShapeBuilder {
+        CylinderShapeBuilder {
+            diameter: self.diameter.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct CylinderShapeBuilder {
+    pub diameter: Py<PyFloat>,
+    pub height: Py<PyFloat>,
+}
+
+impl CylinderShapeBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> CylinderShape {
+        CylinderShape {
+            diameter: self.diameter.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl CylinderShapeBuilder {
+    #[new]
+    #[pyo3(signature = (diameter=0.0, height=0.0))]
+    pub fn new(py: Python, diameter: f64, height: f64) -> Self {
+        Self {
+            diameter: PyFloat::new(py, diameter).unbind(),
+            height: PyFloat::new(py, height).unbind(),
+        }
+    }
+
+    #[setter]
+    pub fn diameter(&mut self, py: Python, value: f64) {
+        self.diameter = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn height(&mut self, py: Python, value: f64) {
+        self.height = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("CylinderShapeBuilder{}", &repr[13..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("diameter", "height")
+    }
+
+    fn build(&self, py: Python) -> Py<CylinderShape> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::CylinderShape::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
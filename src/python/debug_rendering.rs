@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::DebugRendering;
 
@@ -16,6 +16,58 @@ impl DebugRendering {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "offbydefault" => Ok(Self::OffByDefault),
+            "onbydefault" => Ok(Self::OnByDefault),
+            "alwaysoff" => Ok(Self::AlwaysOff),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown DebugRendering name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("OffByDefault", Self::OffByDefault)?;
+        members.set_item("OnByDefault", Self::OnByDefault)?;
+        members.set_item("AlwaysOff", Self::AlwaysOff)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![Self::OffByDefault, Self::OnByDefault, Self::AlwaysOff]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::OffByDefault => "OffByDefault",
+            Self::OnByDefault => "OnByDefault",
+            Self::AlwaysOff => "AlwaysOff",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
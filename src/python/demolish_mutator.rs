@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::DemolishMutator;
 
@@ -22,6 +22,86 @@ impl DemolishMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "disabled" => Ok(Self::Disabled),
+            "friendlyfire" => Ok(Self::FriendlyFire),
+            "oncontact" => Ok(Self::OnContact),
+            "oncontactff" => Ok(Self::OnContactFf),
+            "onballcontact" => Ok(Self::OnBallContact),
+            "onballcontactff" => Ok(Self::OnBallContactFf),
+            "onballcontactsilent" => Ok(Self::OnBallContactSilent),
+            "onballcontactffsilent" => Ok(Self::OnBallContactFfSilent),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown DemolishMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Disabled", Self::Disabled)?;
+        members.set_item("FriendlyFire", Self::FriendlyFire)?;
+        members.set_item("OnContact", Self::OnContact)?;
+        members.set_item("OnContactFf", Self::OnContactFf)?;
+        members.set_item("OnBallContact", Self::OnBallContact)?;
+        members.set_item("OnBallContactFf", Self::OnBallContactFf)?;
+        members.set_item("OnBallContactSilent", Self::OnBallContactSilent)?;
+        members.set_item("OnBallContactFfSilent", Self::OnBallContactFfSilent)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Default,
+            Self::Disabled,
+            Self::FriendlyFire,
+            Self::OnContact,
+            Self::OnContactFf,
+            Self::OnBallContact,
+            Self::OnBallContactFf,
+            Self::OnBallContactSilent,
+            Self::OnBallContactFfSilent,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Disabled => "Disabled",
+            Self::FriendlyFire => "FriendlyFire",
+            Self::OnContact => "OnContact",
+            Self::OnContactFf => "OnContactFf",
+            Self::OnBallContact => "OnBallContact",
+            Self::OnBallContactFf => "OnBallContactFf",
+            Self::OnBallContactSilent => "OnBallContactSilent",
+            Self::OnBallContactFfSilent => "OnBallContactFfSilent",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
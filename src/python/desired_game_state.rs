@@ -185,4 +185,21 @@ impl DesiredGameState {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "ball_states",
+                "car_states",
+                "match_info",
+                "console_commands",
+            ],
+            changes,
+        )
+    }
 }
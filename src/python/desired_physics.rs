@@ -137,4 +137,16 @@ impl DesiredPhysics {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["location", "rotation", "velocity", "angular_velocity"],
+            changes,
+        )
+    }
 }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::ExistingMatchBehavior;
 
@@ -16,6 +16,62 @@ impl ExistingMatchBehavior {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "restart" => Ok(Self::Restart),
+            "continueandspawn" => Ok(Self::ContinueAndSpawn),
+            "restartifdifferent" => Ok(Self::RestartIfDifferent),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown ExistingMatchBehavior name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Restart", Self::Restart)?;
+        members.set_item("ContinueAndSpawn", Self::ContinueAndSpawn)?;
+        members.set_item("RestartIfDifferent", Self::RestartIfDifferent)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Restart,
+            Self::ContinueAndSpawn,
+            Self::RestartIfDifferent,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Restart => "Restart",
+            Self::ContinueAndSpawn => "ContinueAndSpawn",
+            Self::RestartIfDifferent => "RestartIfDifferent",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
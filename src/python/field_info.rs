@@ -128,4 +128,83 @@ impl FieldInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["boost_pads", "goals"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> FieldInfoBuilder {
+        FieldInfoBuilder {
+            boost_pads: PyList::new(py, self.boost_pads.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            goals: PyList::new(py, self.goals.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct FieldInfoBuilder {
+    #[pyo3(set)]
+    pub boost_pads: Py<PyList>,
+    #[pyo3(set)]
+    pub goals: Py<PyList>,
+}
+
+impl FieldInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> FieldInfo {
+        FieldInfo {
+            boost_pads: PyList::new(py, self.boost_pads.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            goals: PyList::new(py, self.goals.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pymethods]
+impl FieldInfoBuilder {
+    #[new]
+    #[pyo3(signature = (boost_pads=None, goals=None))]
+    pub fn new(py: Python, boost_pads: Option<Py<PyList>>, goals: Option<Py<PyList>>) -> Self {
+        Self {
+            boost_pads: boost_pads.unwrap_or_else(|| PyList::empty(py).unbind()),
+            goals: goals.unwrap_or_else(|| PyList::empty(py).unbind()),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("FieldInfoBuilder{}", &repr[9..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("boost_pads", "goals")
+    }
+
+    fn build(&self, py: Python) -> Py<FieldInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::FieldInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::GameMode;
 
@@ -21,6 +21,82 @@ impl GameMode {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "soccar" => Ok(Self::Soccar),
+            "hoops" => Ok(Self::Hoops),
+            "dropshot" => Ok(Self::Dropshot),
+            "snowday" => Ok(Self::Snowday),
+            "rumble" => Ok(Self::Rumble),
+            "heatseeker" => Ok(Self::Heatseeker),
+            "gridiron" => Ok(Self::Gridiron),
+            "knockout" => Ok(Self::Knockout),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown GameMode name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Soccar", Self::Soccar)?;
+        members.set_item("Hoops", Self::Hoops)?;
+        members.set_item("Dropshot", Self::Dropshot)?;
+        members.set_item("Snowday", Self::Snowday)?;
+        members.set_item("Rumble", Self::Rumble)?;
+        members.set_item("Heatseeker", Self::Heatseeker)?;
+        members.set_item("Gridiron", Self::Gridiron)?;
+        members.set_item("Knockout", Self::Knockout)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Soccar,
+            Self::Hoops,
+            Self::Dropshot,
+            Self::Snowday,
+            Self::Rumble,
+            Self::Heatseeker,
+            Self::Gridiron,
+            Self::Knockout,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Soccar => "Soccar",
+            Self::Hoops => "Hoops",
+            Self::Dropshot => "Dropshot",
+            Self::Snowday => "Snowday",
+            Self::Rumble => "Rumble",
+            Self::Heatseeker => "Heatseeker",
+            Self::Gridiron => "Gridiron",
+            Self::Knockout => "Knockout",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
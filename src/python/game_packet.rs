@@ -203,4 +203,123 @@ impl GamePacket {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["players", "boost_pads", "balls", "match_info", "teams"],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> GamePacketBuilder {
+        GamePacketBuilder {
+            players: PyList::new(py, self.players.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            boost_pads: PyList::new(py, self.boost_pads.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            balls: PyList::new(py, self.balls.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            match_info: self.match_info.clone_ref(py),
+            teams: PyList::new(py, self.teams.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct GamePacketBuilder {
+    #[pyo3(set)]
+    pub players: Py<PyList>,
+    #[pyo3(set)]
+    pub boost_pads: Py<PyList>,
+    #[pyo3(set)]
+    pub balls: Py<PyList>,
+    #[pyo3(set)]
+    pub match_info: Py<super::MatchInfo>,
+    #[pyo3(set)]
+    pub teams: Py<PyList>,
+}
+
+impl GamePacketBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> GamePacket {
+        GamePacket {
+            players: PyList::new(py, self.players.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            boost_pads: PyList::new(py, self.boost_pads.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            balls: PyList::new(py, self.balls.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            match_info: self.match_info.clone_ref(py),
+            teams: PyList::new(py, self.teams.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+        }
+    }
+}
+
+#[pymethods]
+impl GamePacketBuilder {
+    #[new]
+    #[pyo3(signature = (players=None, boost_pads=None, balls=None, match_info=None, teams=None))]
+    pub fn new(
+        py: Python,
+        players: Option<Py<PyList>>,
+        boost_pads: Option<Py<PyList>>,
+        balls: Option<Py<PyList>>,
+        match_info: Option<Py<super::MatchInfo>>,
+        teams: Option<Py<PyList>>,
+    ) -> Self {
+        Self {
+            players: players.unwrap_or_else(|| PyList::empty(py).unbind()),
+            boost_pads: boost_pads.unwrap_or_else(|| PyList::empty(py).unbind()),
+            balls: balls.unwrap_or_else(|| PyList::empty(py).unbind()),
+            match_info: match_info.unwrap_or_else(|| super::MatchInfo::py_default(py)),
+            teams: teams.unwrap_or_else(|| PyList::empty(py).unbind()),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("GamePacketBuilder{}", &repr[10..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    ) {
+        ("players", "boost_pads", "balls", "match_info", "teams")
+    }
+
+    fn build(&self, py: Python) -> Py<GamePacket> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::GamePacket::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
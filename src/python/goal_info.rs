@@ -115,4 +115,115 @@ impl GoalInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["team_num", "location", "direction", "width", "height"],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> GoalInfoBuilder {
+        GoalInfoBuilder {
+            team_num: self.team_num,
+            location: self.location.clone_ref(py),
+            direction: self.direction.clone_ref(py),
+            width: self.width.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct GoalInfoBuilder {
+    #[pyo3(set)]
+    pub team_num: i32,
+    #[pyo3(set)]
+    pub location: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub direction: Py<super::Vector3>,
+    pub width: Py<PyFloat>,
+    pub height: Py<PyFloat>,
+}
+
+impl GoalInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> GoalInfo {
+        GoalInfo {
+            team_num: self.team_num,
+            location: self.location.clone_ref(py),
+            direction: self.direction.clone_ref(py),
+            width: self.width.clone_ref(py),
+            height: self.height.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl GoalInfoBuilder {
+    #[new]
+    #[pyo3(signature = (team_num=0, location=None, direction=None, width=0.0, height=0.0))]
+    pub fn new(
+        py: Python,
+        team_num: i32,
+        location: Option<Py<super::Vector3>>,
+        direction: Option<Py<super::Vector3>>,
+        width: f64,
+        height: f64,
+    ) -> Self {
+        Self {
+            team_num,
+            location: location.unwrap_or_else(|| super::Vector3::py_default(py)),
+            direction: direction.unwrap_or_else(|| super::Vector3::py_default(py)),
+            width: PyFloat::new(py, width).unbind(),
+            height: PyFloat::new(py, height).unbind(),
+        }
+    }
+
+    #[setter]
+    pub fn width(&mut self, py: Python, value: f64) {
+        self.width = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn height(&mut self, py: Python, value: f64) {
+        self.height = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("GoalInfoBuilder{}", &repr[8..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    ) {
+        ("team_num", "location", "direction", "width", "height")
+    }
+
+    fn build(&self, py: Python) -> Py<GoalInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::GoalInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
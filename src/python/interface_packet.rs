@@ -83,4 +83,70 @@ impl InterfacePacket {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["message"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> InterfacePacketBuilder {
+        InterfacePacketBuilder {
+            message: self.message.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct InterfacePacketBuilder {
+    #[pyo3(set)]
+    pub message: Py<PyAny>,
+}
+
+impl InterfacePacketBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> InterfacePacket {
+        InterfacePacket {
+            message: self.message.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl InterfacePacketBuilder {
+    #[new]
+    #[pyo3(signature = (message=None))]
+    pub fn new(py: Python, message: Option<Py<PyAny>>) -> Self {
+        Self {
+            message: message.unwrap_or_else(|| super::InterfaceMessage::py_default(py)),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("InterfacePacketBuilder{}", &repr[15..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str,) {
+        ("message",)
+    }
+
+    fn build(&self, py: Python) -> Py<InterfacePacket> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::InterfacePacket::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
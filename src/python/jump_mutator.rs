@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::JumpMutator;
 
@@ -20,6 +20,78 @@ impl JumpMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "default" => Ok(Self::Default),
+            "grounded" => Ok(Self::Grounded),
+            "two" => Ok(Self::Two),
+            "three" => Ok(Self::Three),
+            "four" => Ok(Self::Four),
+            "unlimited" => Ok(Self::Unlimited),
+            "nojumps" => Ok(Self::NoJumps),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown JumpMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Default", Self::Default)?;
+        members.set_item("Grounded", Self::Grounded)?;
+        members.set_item("Two", Self::Two)?;
+        members.set_item("Three", Self::Three)?;
+        members.set_item("Four", Self::Four)?;
+        members.set_item("Unlimited", Self::Unlimited)?;
+        members.set_item("NoJumps", Self::NoJumps)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Default,
+            Self::Grounded,
+            Self::Two,
+            Self::Three,
+            Self::Four,
+            Self::Unlimited,
+            Self::NoJumps,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Grounded => "Grounded",
+            Self::Two => "Two",
+            Self::Three => "Three",
+            Self::Four => "Four",
+            Self::Unlimited => "Unlimited",
+            Self::NoJumps => "NoJumps",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
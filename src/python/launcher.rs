@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::Launcher;
 
@@ -17,6 +17,61 @@ impl Launcher {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "steam" => Ok(Self::Steam),
+            "epic" => Ok(Self::Epic),
+            "custom" => Ok(Self::Custom),
+            "nolaunch" => Ok(Self::NoLaunch),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown Launcher name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Steam", Self::Steam)?;
+        members.set_item("Epic", Self::Epic)?;
+        members.set_item("Custom", Self::Custom)?;
+        members.set_item("NoLaunch", Self::NoLaunch)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![Self::Steam, Self::Epic, Self::Custom, Self::NoLaunch]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Steam => "Steam",
+            Self::Epic => "Epic",
+            Self::Custom => "Custom",
+            Self::NoLaunch => "NoLaunch",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -156,4 +156,25 @@ impl LoadoutPaint {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "car_paint_id",
+                "decal_paint_id",
+                "wheels_paint_id",
+                "boost_paint_id",
+                "antenna_paint_id",
+                "hat_paint_id",
+                "trails_paint_id",
+                "goal_explosion_paint_id",
+            ],
+            changes,
+        )
+    }
 }
@@ -278,4 +278,33 @@ impl MatchConfiguration {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "launcher",
+                "launcher_arg",
+                "auto_start_agents",
+                "wait_for_agents",
+                "game_map_upk",
+                "player_configurations",
+                "script_configurations",
+                "game_mode",
+                "skip_replays",
+                "instant_start",
+                "mutators",
+                "existing_match_behavior",
+                "enable_rendering",
+                "enable_state_setting",
+                "auto_save_replay",
+                "freeplay",
+            ],
+            changes,
+        )
+    }
 }
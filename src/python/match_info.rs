@@ -158,4 +158,172 @@ impl MatchInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "seconds_elapsed",
+                "game_time_remaining",
+                "is_overtime",
+                "is_unlimited_time",
+                "match_phase",
+                "world_gravity_z",
+                "game_speed",
+                "last_spectated",
+                "frame_num",
+            ],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> MatchInfoBuilder {
+        MatchInfoBuilder {
+            seconds_elapsed: self.seconds_elapsed.clone_ref(py),
+            game_time_remaining: self.game_time_remaining.clone_ref(py),
+            is_overtime: self.is_overtime,
+            is_unlimited_time: self.is_unlimited_time,
+            match_phase: self.match_phase,
+            world_gravity_z: self.world_gravity_z.clone_ref(py),
+            game_speed: self.game_speed.clone_ref(py),
+            last_spectated: self.last_spectated,
+            frame_num: self.frame_num,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct MatchInfoBuilder {
+    pub seconds_elapsed: Py<PyFloat>,
+    pub game_time_remaining: Py<PyFloat>,
+    #[pyo3(set)]
+    pub is_overtime: bool,
+    #[pyo3(set)]
+    pub is_unlimited_time: bool,
+    #[pyo3(set)]
+    pub match_phase: super::MatchPhase,
+    pub world_gravity_z: Py<PyFloat>,
+    pub game_speed: Py<PyFloat>,
+    #[pyo3(set)]
+    pub last_spectated: u32,
+    #[pyo3(set)]
+    pub frame_num: u32,
+}
+
+impl MatchInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> MatchInfo {
+        MatchInfo {
+            seconds_elapsed: self.seconds_elapsed.clone_ref(py),
+            game_time_remaining: self.game_time_remaining.clone_ref(py),
+            is_overtime: self.is_overtime,
+            is_unlimited_time: self.is_unlimited_time,
+            match_phase: self.match_phase,
+            world_gravity_z: self.world_gravity_z.clone_ref(py),
+            game_speed: self.game_speed.clone_ref(py),
+            last_spectated: self.last_spectated,
+            frame_num: self.frame_num,
+        }
+    }
+}
+
+#[pymethods]
+impl MatchInfoBuilder {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (seconds_elapsed=0.0, game_time_remaining=0.0, is_overtime=false, is_unlimited_time=false, match_phase=Default::default(), world_gravity_z=0.0, game_speed=0.0, last_spectated=0, frame_num=0))]
+    pub fn new(
+        py: Python,
+        seconds_elapsed: f64,
+        game_time_remaining: f64,
+        is_overtime: bool,
+        is_unlimited_time: bool,
+        match_phase: super::MatchPhase,
+        world_gravity_z: f64,
+        game_speed: f64,
+        last_spectated: u32,
+        frame_num: u32,
+    ) -> Self {
+        Self {
+            seconds_elapsed: PyFloat::new(py, seconds_elapsed).unbind(),
+            game_time_remaining: PyFloat::new(py, game_time_remaining).unbind(),
+            is_overtime,
+            is_unlimited_time,
+            match_phase,
+            world_gravity_z: PyFloat::new(py, world_gravity_z).unbind(),
+            game_speed: PyFloat::new(py, game_speed).unbind(),
+            last_spectated,
+            frame_num,
+        }
+    }
+
+    #[setter]
+    pub fn seconds_elapsed(&mut self, py: Python, value: f64) {
+        self.seconds_elapsed = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn game_time_remaining(&mut self, py: Python, value: f64) {
+        self.game_time_remaining = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn world_gravity_z(&mut self, py: Python, value: f64) {
+        self.world_gravity_z = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn game_speed(&mut self, py: Python, value: f64) {
+        self.game_speed = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("MatchInfoBuilder{}", &repr[9..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    ) {
+        (
+            "seconds_elapsed",
+            "game_time_remaining",
+            "is_overtime",
+            "is_unlimited_time",
+            "match_phase",
+            "world_gravity_z",
+            "game_speed",
+            "last_spectated",
+            "frame_num",
+        )
+    }
+
+    fn build(&self, py: Python) -> Py<MatchInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::MatchInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::MatchLengthMutator;
 
@@ -17,6 +17,66 @@ impl MatchLengthMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "fiveminutes" => Ok(Self::FiveMinutes),
+            "tenminutes" => Ok(Self::TenMinutes),
+            "twentyminutes" => Ok(Self::TwentyMinutes),
+            "unlimited" => Ok(Self::Unlimited),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown MatchLengthMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("FiveMinutes", Self::FiveMinutes)?;
+        members.set_item("TenMinutes", Self::TenMinutes)?;
+        members.set_item("TwentyMinutes", Self::TwentyMinutes)?;
+        members.set_item("Unlimited", Self::Unlimited)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::FiveMinutes,
+            Self::TenMinutes,
+            Self::TwentyMinutes,
+            Self::Unlimited,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FiveMinutes => "FiveMinutes",
+            Self::TenMinutes => "TenMinutes",
+            Self::TwentyMinutes => "TwentyMinutes",
+            Self::Unlimited => "Unlimited",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
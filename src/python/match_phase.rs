@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::MatchPhase;
 
@@ -21,6 +21,82 @@ impl MatchPhase {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "inactive" => Ok(Self::Inactive),
+            "countdown" => Ok(Self::Countdown),
+            "kickoff" => Ok(Self::Kickoff),
+            "active" => Ok(Self::Active),
+            "goalscored" => Ok(Self::GoalScored),
+            "replay" => Ok(Self::Replay),
+            "paused" => Ok(Self::Paused),
+            "ended" => Ok(Self::Ended),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown MatchPhase name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Inactive", Self::Inactive)?;
+        members.set_item("Countdown", Self::Countdown)?;
+        members.set_item("Kickoff", Self::Kickoff)?;
+        members.set_item("Active", Self::Active)?;
+        members.set_item("GoalScored", Self::GoalScored)?;
+        members.set_item("Replay", Self::Replay)?;
+        members.set_item("Paused", Self::Paused)?;
+        members.set_item("Ended", Self::Ended)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Inactive,
+            Self::Countdown,
+            Self::Kickoff,
+            Self::Active,
+            Self::GoalScored,
+            Self::Replay,
+            Self::Paused,
+            Self::Ended,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Inactive => "Inactive",
+            Self::Countdown => "Countdown",
+            Self::Kickoff => "Kickoff",
+            Self::Active => "Active",
+            Self::GoalScored => "GoalScored",
+            Self::Replay => "Replay",
+            Self::Paused => "Paused",
+            Self::Ended => "Ended",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::MaxScoreMutator;
 
@@ -28,6 +28,110 @@ impl MaxScoreMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "unlimited" => Ok(Self::Unlimited),
+            "onegoal" => Ok(Self::OneGoal),
+            "threegoals" => Ok(Self::ThreeGoals),
+            "fivegoals" => Ok(Self::FiveGoals),
+            "sevengoals" => Ok(Self::SevenGoals),
+            "tengoals" => Ok(Self::TenGoals),
+            "twentygoals" => Ok(Self::TwentyGoals),
+            "thirtygoals" => Ok(Self::ThirtyGoals),
+            "fortygoals" => Ok(Self::FortyGoals),
+            "fiftygoals" => Ok(Self::FiftyGoals),
+            "sixtygoals" => Ok(Self::SixtyGoals),
+            "seventygoals" => Ok(Self::SeventyGoals),
+            "eightygoals" => Ok(Self::EightyGoals),
+            "ninetygoals" => Ok(Self::NinetyGoals),
+            "hundredgoals" => Ok(Self::HundredGoals),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown MaxScoreMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Unlimited", Self::Unlimited)?;
+        members.set_item("OneGoal", Self::OneGoal)?;
+        members.set_item("ThreeGoals", Self::ThreeGoals)?;
+        members.set_item("FiveGoals", Self::FiveGoals)?;
+        members.set_item("SevenGoals", Self::SevenGoals)?;
+        members.set_item("TenGoals", Self::TenGoals)?;
+        members.set_item("TwentyGoals", Self::TwentyGoals)?;
+        members.set_item("ThirtyGoals", Self::ThirtyGoals)?;
+        members.set_item("FortyGoals", Self::FortyGoals)?;
+        members.set_item("FiftyGoals", Self::FiftyGoals)?;
+        members.set_item("SixtyGoals", Self::SixtyGoals)?;
+        members.set_item("SeventyGoals", Self::SeventyGoals)?;
+        members.set_item("EightyGoals", Self::EightyGoals)?;
+        members.set_item("NinetyGoals", Self::NinetyGoals)?;
+        members.set_item("HundredGoals", Self::HundredGoals)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Unlimited,
+            Self::OneGoal,
+            Self::ThreeGoals,
+            Self::FiveGoals,
+            Self::SevenGoals,
+            Self::TenGoals,
+            Self::TwentyGoals,
+            Self::ThirtyGoals,
+            Self::FortyGoals,
+            Self::FiftyGoals,
+            Self::SixtyGoals,
+            Self::SeventyGoals,
+            Self::EightyGoals,
+            Self::NinetyGoals,
+            Self::HundredGoals,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unlimited => "Unlimited",
+            Self::OneGoal => "OneGoal",
+            Self::ThreeGoals => "ThreeGoals",
+            Self::FiveGoals => "FiveGoals",
+            Self::SevenGoals => "SevenGoals",
+            Self::TenGoals => "TenGoals",
+            Self::TwentyGoals => "TwentyGoals",
+            Self::ThirtyGoals => "ThirtyGoals",
+            Self::FortyGoals => "FortyGoals",
+            Self::FiftyGoals => "FiftyGoals",
+            Self::SixtyGoals => "SixtyGoals",
+            Self::SeventyGoals => "SeventyGoals",
+            Self::EightyGoals => "EightyGoals",
+            Self::NinetyGoals => "NinetyGoals",
+            Self::HundredGoals => "HundredGoals",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -367,4 +367,49 @@ impl MutatorSettings {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "match_length",
+                "max_score",
+                "multi_ball",
+                "overtime",
+                "series_length",
+                "game_speed",
+                "ball_max_speed",
+                "ball_type",
+                "ball_weight",
+                "ball_size",
+                "ball_bounciness",
+                "boost_amount",
+                "rumble",
+                "boost_strength",
+                "gravity",
+                "demolish",
+                "respawn_time",
+                "max_time",
+                "game_event",
+                "audio",
+                "ball_gravity",
+                "territory",
+                "stale_ball",
+                "jump",
+                "dodge_timer",
+                "possession_score",
+                "demolish_score",
+                "normal_goal_score",
+                "aerial_goal_score",
+                "assist_goal_score",
+                "input_restriction",
+                "scoring_rule",
+            ],
+            changes,
+        )
+    }
 }
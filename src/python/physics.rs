@@ -113,4 +113,95 @@ impl Physics {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["location", "rotation", "velocity", "angular_velocity"],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> PhysicsBuilder {
+        PhysicsBuilder {
+            location: self.location.clone_ref(py),
+            rotation: self.rotation.clone_ref(py),
+            velocity: self.velocity.clone_ref(py),
+            angular_velocity: self.angular_velocity.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct PhysicsBuilder {
+    #[pyo3(set)]
+    pub location: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub rotation: Py<super::Rotator>,
+    #[pyo3(set)]
+    pub velocity: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub angular_velocity: Py<super::Vector3>,
+}
+
+impl PhysicsBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> Physics {
+        Physics {
+            location: self.location.clone_ref(py),
+            rotation: self.rotation.clone_ref(py),
+            velocity: self.velocity.clone_ref(py),
+            angular_velocity: self.angular_velocity.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl PhysicsBuilder {
+    #[new]
+    #[pyo3(signature = (location=None, rotation=None, velocity=None, angular_velocity=None))]
+    pub fn new(
+        py: Python,
+        location: Option<Py<super::Vector3>>,
+        rotation: Option<Py<super::Rotator>>,
+        velocity: Option<Py<super::Vector3>>,
+        angular_velocity: Option<Py<super::Vector3>>,
+    ) -> Self {
+        Self {
+            location: location.unwrap_or_else(|| super::Vector3::py_default(py)),
+            rotation: rotation.unwrap_or_else(|| super::Rotator::py_default(py)),
+            velocity: velocity.unwrap_or_else(|| super::Vector3::py_default(py)),
+            angular_velocity: angular_velocity.unwrap_or_else(|| super::Vector3::py_default(py)),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("PhysicsBuilder{}", &repr[7..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str, &'static str, &'static str) {
+        ("location", "rotation", "velocity", "angular_velocity")
+    }
+
+    fn build(&self, py: Python) -> Py<Physics> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::Physics::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
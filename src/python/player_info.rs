@@ -266,4 +266,266 @@ impl PlayerInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "physics",
+                "score_info",
+                "hitbox",
+                "hitbox_offset",
+                "latest_touch",
+                "air_state",
+                "dodge_timeout",
+                "demolished_timeout",
+                "is_supersonic",
+                "is_bot",
+                "name",
+                "team",
+                "boost",
+                "player_id",
+                "accolades",
+                "last_input",
+                "has_jumped",
+                "has_double_jumped",
+                "has_dodged",
+                "dodge_elapsed",
+                "dodge_dir",
+            ],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> PlayerInfoBuilder {
+        PlayerInfoBuilder {
+            physics: self.physics.clone_ref(py),
+            score_info: self.score_info.clone_ref(py),
+            hitbox: self.hitbox.clone_ref(py),
+            hitbox_offset: self.hitbox_offset.clone_ref(py),
+            latest_touch: self.latest_touch.as_ref().map(|x| x.clone_ref(py)),
+            air_state: self.air_state,
+            dodge_timeout: self.dodge_timeout.clone_ref(py),
+            demolished_timeout: self.demolished_timeout.clone_ref(py),
+            is_supersonic: self.is_supersonic,
+            is_bot: self.is_bot,
+            name: self.name.clone_ref(py),
+            team: self.team,
+            boost: self.boost.clone_ref(py),
+            player_id: self.player_id,
+            accolades: PyList::new(py, self.accolades.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            last_input: self.last_input.clone_ref(py),
+            has_jumped: self.has_jumped,
+            has_double_jumped: self.has_double_jumped,
+            has_dodged: self.has_dodged,
+            dodge_elapsed: self.dodge_elapsed.clone_ref(py),
+            dodge_dir: self.dodge_dir.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct PlayerInfoBuilder {
+    #[pyo3(set)]
+    pub physics: Py<super::Physics>,
+    #[pyo3(set)]
+    pub score_info: Py<super::ScoreInfo>,
+    #[pyo3(set)]
+    pub hitbox: Py<super::BoxShape>,
+    #[pyo3(set)]
+    pub hitbox_offset: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub latest_touch: Option<Py<super::Touch>>,
+    #[pyo3(set)]
+    pub air_state: super::AirState,
+    pub dodge_timeout: Py<PyFloat>,
+    pub demolished_timeout: Py<PyFloat>,
+    #[pyo3(set)]
+    pub is_supersonic: bool,
+    #[pyo3(set)]
+    pub is_bot: bool,
+    #[pyo3(set)]
+    pub name: Py<PyString>,
+    #[pyo3(set)]
+    pub team: u32,
+    pub boost: Py<PyFloat>,
+    #[pyo3(set)]
+    pub player_id: i32,
+    #[pyo3(set)]
+    pub accolades: Py<PyList>,
+    #[pyo3(set)]
+    pub last_input: Py<super::ControllerState>,
+    #[pyo3(set)]
+    pub has_jumped: bool,
+    #[pyo3(set)]
+    pub has_double_jumped: bool,
+    #[pyo3(set)]
+    pub has_dodged: bool,
+    pub dodge_elapsed: Py<PyFloat>,
+    #[pyo3(set)]
+    pub dodge_dir: Py<super::Vector2>,
+}
+
+impl PlayerInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> PlayerInfo {
+        PlayerInfo {
+            physics: self.physics.clone_ref(py),
+            score_info: self.score_info.clone_ref(py),
+            hitbox: self.hitbox.clone_ref(py),
+            hitbox_offset: self.hitbox_offset.clone_ref(py),
+            latest_touch: self.latest_touch.as_ref().map(|x| x.clone_ref(py)),
+            air_state: self.air_state,
+            dodge_timeout: self.dodge_timeout.clone_ref(py),
+            demolished_timeout: self.demolished_timeout.clone_ref(py),
+            is_supersonic: self.is_supersonic,
+            is_bot: self.is_bot,
+            name: self.name.clone_ref(py),
+            team: self.team,
+            boost: self.boost.clone_ref(py),
+            player_id: self.player_id,
+            accolades: PyList::new(py, self.accolades.bind_borrowed(py).iter())
+                .unwrap()
+                .unbind(),
+            last_input: self.last_input.clone_ref(py),
+            has_jumped: self.has_jumped,
+            has_double_jumped: self.has_double_jumped,
+            has_dodged: self.has_dodged,
+            dodge_elapsed: self.dodge_elapsed.clone_ref(py),
+            dodge_dir: self.dodge_dir.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl PlayerInfoBuilder {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (physics=None, score_info=None, hitbox=None, hitbox_offset=None, latest_touch=None, air_state=Default::default(), dodge_timeout=0.0, demolished_timeout=0.0, is_supersonic=false, is_bot=false, name=None, team=0, boost=0.0, player_id=0, accolades=None, last_input=None, has_jumped=false, has_double_jumped=false, has_dodged=false, dodge_elapsed=0.0, dodge_dir=None))]
+    pub fn new(
+        py: Python,
+        physics: Option<Py<super::Physics>>,
+        score_info: Option<Py<super::ScoreInfo>>,
+        hitbox: Option<Py<super::BoxShape>>,
+        hitbox_offset: Option<Py<super::Vector3>>,
+        latest_touch: Option<Py<super::Touch>>,
+        air_state: super::AirState,
+        dodge_timeout: f64,
+        demolished_timeout: f64,
+        is_supersonic: bool,
+        is_bot: bool,
+        name: Option<Py<PyString>>,
+        team: u32,
+        boost: f64,
+        player_id: i32,
+        accolades: Option<Py<PyList>>,
+        last_input: Option<Py<super::ControllerState>>,
+        has_jumped: bool,
+        has_double_jumped: bool,
+        has_dodged: bool,
+        dodge_elapsed: f64,
+        dodge_dir: Option<Py<super::Vector2>>,
+    ) -> Self {
+        Self {
+            physics: physics.unwrap_or_else(|| super::Physics::py_default(py)),
+            score_info: score_info.unwrap_or_else(|| super::ScoreInfo::py_default(py)),
+            hitbox: hitbox.unwrap_or_else(|| super::BoxShape::py_default(py)),
+            hitbox_offset: hitbox_offset.unwrap_or_else(|| super::Vector3::py_default(py)),
+            latest_touch,
+            air_state,
+            dodge_timeout: PyFloat::new(py, dodge_timeout).unbind(),
+            demolished_timeout: PyFloat::new(py, demolished_timeout).unbind(),
+            is_supersonic,
+            is_bot,
+            name: name.unwrap_or_else(|| crate::pydefault_string(py)),
+            team,
+            boost: PyFloat::new(py, boost).unbind(),
+            player_id,
+            accolades: accolades.unwrap_or_else(|| PyList::empty(py).unbind()),
+            last_input: last_input.unwrap_or_else(|| super::ControllerState::py_default(py)),
+            has_jumped,
+            has_double_jumped,
+            has_dodged,
+            dodge_elapsed: PyFloat::new(py, dodge_elapsed).unbind(),
+            dodge_dir: dodge_dir.unwrap_or_else(|| super::Vector2::py_default(py)),
+        }
+    }
+
+    #[setter]
+    pub fn dodge_timeout(&mut self, py: Python, value: f64) {
+        self.dodge_timeout = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn demolished_timeout(&mut self, py: Python, value: f64) {
+        self.demolished_timeout = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn boost(&mut self, py: Python, value: f64) {
+        self.boost = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn dodge_elapsed(&mut self, py: Python, value: f64) {
+        self.dodge_elapsed = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("PlayerInfoBuilder{}", &repr[10..])
+    }
+
+    #[classattr]
+    fn __match_args__(py: Python) -> Bound<pyo3::types::PyTuple> {
+        pyo3::types::PyTuple::new(
+            py,
+            [
+                "physics",
+                "score_info",
+                "hitbox",
+                "hitbox_offset",
+                "latest_touch",
+                "air_state",
+                "dodge_timeout",
+                "demolished_timeout",
+                "is_supersonic",
+                "is_bot",
+                "name",
+                "team",
+                "boost",
+                "player_id",
+                "accolades",
+                "last_input",
+                "has_jumped",
+                "has_double_jumped",
+                "has_dodged",
+                "dodge_elapsed",
+                "dodge_dir",
+            ],
+        )
+        .unwrap()
+    }
+
+    fn build(&self, py: Python) -> Py<PlayerInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::PlayerInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
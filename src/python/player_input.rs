@@ -89,4 +89,80 @@ impl PlayerInput {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["player_index", "controller_state"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> PlayerInputBuilder {
+        PlayerInputBuilder {
+            player_index: self.player_index,
+            controller_state: self.controller_state.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct PlayerInputBuilder {
+    #[pyo3(set)]
+    pub player_index: u32,
+    #[pyo3(set)]
+    pub controller_state: Py<super::ControllerState>,
+}
+
+impl PlayerInputBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> PlayerInput {
+        PlayerInput {
+            player_index: self.player_index,
+            controller_state: self.controller_state.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl PlayerInputBuilder {
+    #[new]
+    #[pyo3(signature = (player_index=0, controller_state=None))]
+    pub fn new(
+        py: Python,
+        player_index: u32,
+        controller_state: Option<Py<super::ControllerState>>,
+    ) -> Self {
+        Self {
+            player_index,
+            controller_state: controller_state
+                .unwrap_or_else(|| super::ControllerState::py_default(py)),
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("PlayerInputBuilder{}", &repr[11..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("player_index", "controller_state")
+    }
+
+    fn build(&self, py: Python) -> Py<PlayerInput> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::PlayerInput::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -245,4 +245,33 @@ impl PlayerLoadout {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "team_color_id",
+                "custom_color_id",
+                "car_id",
+                "decal_id",
+                "wheels_id",
+                "boost_id",
+                "antenna_id",
+                "hat_id",
+                "paint_finish_id",
+                "custom_finish_id",
+                "engine_audio_id",
+                "trails_id",
+                "goal_explosion_id",
+                "loadout_paint",
+                "primary_color_lookup",
+                "secondary_color_lookup",
+            ],
+            changes,
+        )
+    }
 }
@@ -98,4 +98,82 @@ impl PredictionSlice {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["game_seconds", "physics"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> PredictionSliceBuilder {
+        PredictionSliceBuilder {
+            game_seconds: self.game_seconds.clone_ref(py),
+            physics: self.physics.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct PredictionSliceBuilder {
+    /// The moment in game time that this prediction corresponds to.
+    /// This corresponds to 'seconds_elapsed' in the MatchInfo.
+    pub game_seconds: Py<PyFloat>,
+    /// The predicted location and motion of the object.
+    #[pyo3(set)]
+    pub physics: Py<super::Physics>,
+}
+
+impl PredictionSliceBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> PredictionSlice {
+        PredictionSlice {
+            game_seconds: self.game_seconds.clone_ref(py),
+            physics: self.physics.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl PredictionSliceBuilder {
+    #[new]
+    #[pyo3(signature = (game_seconds=0.0, physics=None))]
+    pub fn new(py: Python, game_seconds: f64, physics: Option<Py<super::Physics>>) -> Self {
+        Self {
+            game_seconds: PyFloat::new(py, game_seconds).unbind(),
+            physics: physics.unwrap_or_else(|| super::Physics::py_default(py)),
+        }
+    }
+
+    #[setter]
+    pub fn game_seconds(&mut self, py: Python, value: f64) {
+        self.game_seconds = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("PredictionSliceBuilder{}", &repr[15..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("game_seconds", "physics")
+    }
+
+    fn build(&self, py: Python) -> Py<PredictionSlice> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::PredictionSlice::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
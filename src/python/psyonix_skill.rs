@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::PsyonixSkill;
 
@@ -17,6 +17,61 @@ impl PsyonixSkill {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "beginner" => Ok(Self::Beginner),
+            "rookie" => Ok(Self::Rookie),
+            "pro" => Ok(Self::Pro),
+            "allstar" => Ok(Self::AllStar),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown PsyonixSkill name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Beginner", Self::Beginner)?;
+        members.set_item("Rookie", Self::Rookie)?;
+        members.set_item("Pro", Self::Pro)?;
+        members.set_item("AllStar", Self::AllStar)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![Self::Beginner, Self::Rookie, Self::Pro, Self::AllStar]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Beginner => "Beginner",
+            Self::Rookie => "Rookie",
+            Self::Pro => "Pro",
+            Self::AllStar => "AllStar",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::RespawnTimeMutator;
 
@@ -17,6 +17,66 @@ impl RespawnTimeMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "threeseconds" => Ok(Self::ThreeSeconds),
+            "twoseconds" => Ok(Self::TwoSeconds),
+            "onesecond" => Ok(Self::OneSecond),
+            "disablegoalreset" => Ok(Self::DisableGoalReset),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown RespawnTimeMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("ThreeSeconds", Self::ThreeSeconds)?;
+        members.set_item("TwoSeconds", Self::TwoSeconds)?;
+        members.set_item("OneSecond", Self::OneSecond)?;
+        members.set_item("DisableGoalReset", Self::DisableGoalReset)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::ThreeSeconds,
+            Self::TwoSeconds,
+            Self::OneSecond,
+            Self::DisableGoalReset,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ThreeSeconds => "ThreeSeconds",
+            Self::TwoSeconds => "TwoSeconds",
+            Self::OneSecond => "OneSecond",
+            Self::DisableGoalReset => "DisableGoalReset",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
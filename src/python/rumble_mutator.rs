@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::RumbleMutator;
 
@@ -28,6 +28,110 @@ impl RumbleMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "off" => Ok(Self::Off),
+            "defaultrumble" => Ok(Self::DefaultRumble),
+            "slow" => Ok(Self::Slow),
+            "civilized" => Ok(Self::Civilized),
+            "destructionderby" => Ok(Self::DestructionDerby),
+            "springloaded" => Ok(Self::SpringLoaded),
+            "spikesonly" => Ok(Self::SpikesOnly),
+            "spikerush" => Ok(Self::SpikeRush),
+            "hauntedballbeam" => Ok(Self::HauntedBallBeam),
+            "tactical" => Ok(Self::Tactical),
+            "batmanrumble" => Ok(Self::BatmanRumble),
+            "grapplingonly" => Ok(Self::GrapplingOnly),
+            "haymakeronly" => Ok(Self::HaymakerOnly),
+            "spikerushforce" => Ok(Self::SpikeRushForce),
+            "rps" => Ok(Self::Rps),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown RumbleMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Off", Self::Off)?;
+        members.set_item("DefaultRumble", Self::DefaultRumble)?;
+        members.set_item("Slow", Self::Slow)?;
+        members.set_item("Civilized", Self::Civilized)?;
+        members.set_item("DestructionDerby", Self::DestructionDerby)?;
+        members.set_item("SpringLoaded", Self::SpringLoaded)?;
+        members.set_item("SpikesOnly", Self::SpikesOnly)?;
+        members.set_item("SpikeRush", Self::SpikeRush)?;
+        members.set_item("HauntedBallBeam", Self::HauntedBallBeam)?;
+        members.set_item("Tactical", Self::Tactical)?;
+        members.set_item("BatmanRumble", Self::BatmanRumble)?;
+        members.set_item("GrapplingOnly", Self::GrapplingOnly)?;
+        members.set_item("HaymakerOnly", Self::HaymakerOnly)?;
+        members.set_item("SpikeRushForce", Self::SpikeRushForce)?;
+        members.set_item("Rps", Self::Rps)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Off,
+            Self::DefaultRumble,
+            Self::Slow,
+            Self::Civilized,
+            Self::DestructionDerby,
+            Self::SpringLoaded,
+            Self::SpikesOnly,
+            Self::SpikeRush,
+            Self::HauntedBallBeam,
+            Self::Tactical,
+            Self::BatmanRumble,
+            Self::GrapplingOnly,
+            Self::HaymakerOnly,
+            Self::SpikeRushForce,
+            Self::Rps,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::DefaultRumble => "DefaultRumble",
+            Self::Slow => "Slow",
+            Self::Civilized => "Civilized",
+            Self::DestructionDerby => "DestructionDerby",
+            Self::SpringLoaded => "SpringLoaded",
+            Self::SpikesOnly => "SpikesOnly",
+            Self::SpikeRush => "SpikeRush",
+            Self::HauntedBallBeam => "HauntedBallBeam",
+            Self::Tactical => "Tactical",
+            Self::BatmanRumble => "BatmanRumble",
+            Self::GrapplingOnly => "GrapplingOnly",
+            Self::HaymakerOnly => "HaymakerOnly",
+            Self::SpikeRushForce => "SpikeRushForce",
+            Self::Rps => "Rps",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -156,4 +156,143 @@ impl ScoreInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "score",
+                "goals",
+                "own_goals",
+                "assists",
+                "saves",
+                "shots",
+                "demolitions",
+            ],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> ScoreInfoBuilder {
+        ScoreInfoBuilder {
+            score: self.score,
+            goals: self.goals,
+            own_goals: self.own_goals,
+            assists: self.assists,
+            saves: self.saves,
+            shots: self.shots,
+            demolitions: self.demolitions,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct ScoreInfoBuilder {
+    /// The accumulated score, roughly indicating how well a player performs.
+    #[pyo3(set)]
+    pub score: u32,
+    /// Number of goals scored.
+    #[pyo3(set)]
+    pub goals: u32,
+    /// Number of own-goals scored.
+    #[pyo3(set)]
+    pub own_goals: u32,
+    /// Number of goals assisted.
+    #[pyo3(set)]
+    pub assists: u32,
+    /// Number of shots saved.
+    #[pyo3(set)]
+    pub saves: u32,
+    /// Number of shots on opponent goal.
+    #[pyo3(set)]
+    pub shots: u32,
+    /// Number of demolitions made.
+    #[pyo3(set)]
+    pub demolitions: u32,
+}
+
+impl ScoreInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> ScoreInfo {
+        ScoreInfo {
+            score: self.score,
+            goals: self.goals,
+            own_goals: self.own_goals,
+            assists: self.assists,
+            saves: self.saves,
+            shots: self.shots,
+            demolitions: self.demolitions,
+        }
+    }
+}
+
+#[pymethods]
+impl ScoreInfoBuilder {
+    #[new]
+    #[pyo3(signature = (score=0, goals=0, own_goals=0, assists=0, saves=0, shots=0, demolitions=0))]
+    pub fn new(
+        score: u32,
+        goals: u32,
+        own_goals: u32,
+        assists: u32,
+        saves: u32,
+        shots: u32,
+        demolitions: u32,
+    ) -> Self {
+        Self {
+            score,
+            goals,
+            own_goals,
+            assists,
+            saves,
+            shots,
+            demolitions,
+        }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("ScoreInfoBuilder{}", &repr[9..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static str,
+    ) {
+        (
+            "score",
+            "goals",
+            "own_goals",
+            "assists",
+            "saves",
+            "shots",
+            "demolitions",
+        )
+    }
+
+    fn build(&self, py: Python) -> Py<ScoreInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::ScoreInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -121,4 +121,16 @@ impl ScriptConfiguration {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["name", "root_dir", "run_command", "script_id", "agent_id"],
+            changes,
+        )
+    }
 }
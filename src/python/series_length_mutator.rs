@@ -1,5 +1,5 @@
 use crate::flat;
-use pyo3::{PyResult, exceptions::PyValueError, pyclass, pymethods};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::*};
 
 pub use flat::SeriesLengthMutator;
 
@@ -17,6 +17,66 @@ impl SeriesLengthMutator {
         }
     }
 
+    #[staticmethod]
+    pub fn from_name(name: &str) -> PyResult<Self> {
+        match crate::enum_name_key(name).as_str() {
+            "unlimited" => Ok(Self::Unlimited),
+            "threegames" => Ok(Self::ThreeGames),
+            "fivegames" => Ok(Self::FiveGames),
+            "sevengames" => Ok(Self::SevenGames),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown SeriesLengthMutator name '{name}'"
+            ))),
+        }
+    }
+
+    #[classattr]
+    fn __members__(py: Python) -> PyResult<Bound<PyMappingProxy>> {
+        let members = PyDict::new(py);
+        members.set_item("Unlimited", Self::Unlimited)?;
+        members.set_item("ThreeGames", Self::ThreeGames)?;
+        members.set_item("FiveGames", Self::FiveGames)?;
+        members.set_item("SevenGames", Self::SevenGames)?;
+        Ok(PyMappingProxy::new(py, members.as_mapping()))
+    }
+
+    #[classmethod]
+    fn members(_cls: &Bound<PyType>) -> Vec<Self> {
+        vec![
+            Self::Unlimited,
+            Self::ThreeGames,
+            Self::FiveGames,
+            Self::SevenGames,
+        ]
+    }
+
+    #[getter]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unlimited => "Unlimited",
+            Self::ThreeGames => "ThreeGames",
+            Self::FiveGames => "FiveGames",
+            Self::SevenGames => "SevenGames",
+        }
+    }
+
+    #[getter]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __index__(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        *self as u64
+    }
+
+    fn __richcmp__(&self, other: &Bound<PyAny>, op: CompareOp) -> Py<PyAny> {
+        crate::enum_richcmp(*self as u8, other, op)
+    }
+
     pub fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -169,4 +169,25 @@ impl String2D {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "text",
+                "x",
+                "y",
+                "scale",
+                "foreground",
+                "background",
+                "h_align",
+                "v_align",
+            ],
+            changes,
+        )
+    }
 }
@@ -151,4 +151,24 @@ impl String3D {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &[
+                "text",
+                "anchor",
+                "scale",
+                "foreground",
+                "background",
+                "h_align",
+                "v_align",
+            ],
+            changes,
+        )
+    }
 }
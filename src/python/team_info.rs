@@ -95,4 +95,76 @@ impl TeamInfo {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["team_index", "score"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> TeamInfoBuilder {
+        TeamInfoBuilder {
+            team_index: self.team_index,
+            score: self.score,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct TeamInfoBuilder {
+    /// The index of the team. Blue is 0, orange is 1.
+    #[pyo3(set)]
+    pub team_index: u32,
+    /// Number of goals scored.
+    /// Note, this value may be different than the sum of the goals scored by the current players on the team as player may join/leave the game or switch teams.
+    /// This value is what is shown on the heads-up display.
+    #[pyo3(set)]
+    pub score: u32,
+}
+
+impl TeamInfoBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> TeamInfo {
+        TeamInfo {
+            team_index: self.team_index,
+            score: self.score,
+        }
+    }
+}
+
+#[pymethods]
+impl TeamInfoBuilder {
+    #[new]
+    #[pyo3(signature = (team_index=0, score=0))]
+    pub fn new(team_index: u32, score: u32) -> Self {
+        Self { team_index, score }
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("TeamInfoBuilder{}", &repr[8..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("team_index", "score")
+    }
+
+    fn build(&self, py: Python) -> Py<TeamInfo> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::TeamInfo::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
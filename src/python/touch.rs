@@ -102,4 +102,99 @@ impl Touch {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(
+            slf.as_any(),
+            &["game_seconds", "location", "normal", "ball_index"],
+            changes,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> TouchBuilder {
+        TouchBuilder {
+            game_seconds: self.game_seconds.clone_ref(py),
+            location: self.location.clone_ref(py),
+            normal: self.normal.clone_ref(py),
+            ball_index: self.ball_index,
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct TouchBuilder {
+    pub game_seconds: Py<PyFloat>,
+    #[pyo3(set)]
+    pub location: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub normal: Py<super::Vector3>,
+    #[pyo3(set)]
+    pub ball_index: u32,
+}
+
+impl TouchBuilder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> Touch {
+        Touch {
+            game_seconds: self.game_seconds.clone_ref(py),
+            location: self.location.clone_ref(py),
+            normal: self.normal.clone_ref(py),
+            ball_index: self.ball_index,
+        }
+    }
+}
+
+#[pymethods]
+impl TouchBuilder {
+    #[new]
+    #[pyo3(signature = (game_seconds=0.0, location=None, normal=None, ball_index=0))]
+    pub fn new(
+        py: Python,
+        game_seconds: f64,
+        location: Option<Py<super::Vector3>>,
+        normal: Option<Py<super::Vector3>>,
+        ball_index: u32,
+    ) -> Self {
+        Self {
+            game_seconds: PyFloat::new(py, game_seconds).unbind(),
+            location: location.unwrap_or_else(|| super::Vector3::py_default(py)),
+            normal: normal.unwrap_or_else(|| super::Vector3::py_default(py)),
+            ball_index,
+        }
+    }
+
+    #[setter]
+    pub fn game_seconds(&mut self, py: Python, value: f64) {
+        self.game_seconds = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("TouchBuilder{}", &repr[5..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str, &'static str, &'static str) {
+        ("game_seconds", "location", "normal", "ball_index")
+    }
+
+    fn build(&self, py: Python) -> Py<Touch> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::Touch::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
@@ -91,4 +91,83 @@ impl Vector2 {
 
         Ok(crate::into_py_from(py, &flat_t))
     }
+
+    #[pyo3(signature = (**changes))]
+    fn replace<'py>(
+        slf: &Bound<'py, Self>,
+        changes: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::path::replace(slf.as_any(), &["x", "y"], changes)
+    }
+
+    #[allow(unused_variables)]
+    fn to_builder(&self, py: Python) -> Vector2Builder {
+        Vector2Builder {
+            x: self.x.clone_ref(py),
+            y: self.y.clone_ref(py),
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers", subclass, get_all)]
+pub struct Vector2Builder {
+    pub x: Py<PyFloat>,
+    pub y: Py<PyFloat>,
+}
+
+impl Vector2Builder {
+    #[allow(unused_variables)]
+    fn to_frozen(&self, py: Python) -> Vector2 {
+        Vector2 {
+            x: self.x.clone_ref(py),
+            y: self.y.clone_ref(py),
+        }
+    }
+}
+
+#[pymethods]
+impl Vector2Builder {
+    #[new]
+    #[pyo3(signature = (x=0.0, y=0.0))]
+    pub fn new(py: Python, x: f64, y: f64) -> Self {
+        Self {
+            x: PyFloat::new(py, x).unbind(),
+            y: PyFloat::new(py, y).unbind(),
+        }
+    }
+
+    #[setter]
+    pub fn x(&mut self, py: Python, value: f64) {
+        self.x = PyFloat::new(py, value).unbind();
+    }
+
+    #[setter]
+    pub fn y(&mut self, py: Python, value: f64) {
+        self.y = PyFloat::new(py, value).unbind();
+    }
+
+    pub fn __str__(&self, py: Python) -> String {
+        self.__repr__(py)
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        let repr = self.to_frozen(py).__repr__(py);
+        format!("Vector2Builder{}", &repr[7..])
+    }
+
+    #[classattr]
+    fn __match_args__() -> (&'static str, &'static str) {
+        ("x", "y")
+    }
+
+    fn build(&self, py: Python) -> Py<Vector2> {
+        Py::new(py, self.to_frozen(py)).unwrap()
+    }
+
+    fn pack<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        let flat_t = flat::Vector2::from_gil(py, &self.to_frozen(py));
+        PyBytes::new(py, builder.finish(flat_t, None))
+    }
 }
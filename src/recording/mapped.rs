@@ -0,0 +1,269 @@
+use super::{
+    Header, Index, IndexEntry, RecordKind, Result, read_index,
+    reader::{Packet, normalize_index, unpack_match_config},
+    slice_record,
+};
+use crate::{
+    arrow::{RecordBatch, Table},
+    flat,
+    python::{GamePacket, MatchConfiguration},
+};
+use memmap2::Mmap;
+use pyo3::{prelude::*, types::*};
+use std::{fs::File, io::Cursor, path::PathBuf, sync::Arc};
+
+/// A recording that's memory-mapped instead of read into memory.
+/// Records are sliced out of the mapping, so they're only copied when they're unpacked.
+/// Frame ranges include every record in the range, even if the frame numbers start over partway through.
+///
+/// The file must not be modified while it's open
+#[pyclass(module = "rlbot_flatbuffers.recording", frozen)]
+pub struct MappedRecording {
+    mmap: Mmap,
+    header: Header,
+    index: Index,
+}
+
+impl MappedRecording {
+    pub fn open(path: &PathBuf) -> Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the file must not be modified while it's mapped, which is documented above
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let mut reader = Cursor::new(&mmap[..]);
+        let header = Header::read(&mut reader)?;
+        let index = read_index(&mut reader, &header)?;
+
+        Ok(Self {
+            mmap,
+            header,
+            index,
+        })
+    }
+
+    /// The packed message of the record at `index` in the index, checking that it isn't corrupted
+    pub fn record(&self, index: usize) -> Result<&[u8]> {
+        slice_record(&self.mmap, &self.index[index])
+    }
+
+    /// The indices of the records with a frame number in `start_frame..stop_frame`.
+    /// When the frame numbers aren't sorted (e.g. the match restarted),
+    /// the whole index is scanned so every record in the range is included
+    #[must_use]
+    pub fn frame_range(&self, start_frame: Option<u32>, stop_frame: Option<u32>) -> Vec<usize> {
+        if !self.index.frames_sorted {
+            return self
+                .index
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| {
+                    start_frame.is_none_or(|start| entry.frame_num >= start)
+                        && stop_frame.is_none_or(|stop| entry.frame_num < stop)
+                })
+                .map(|(index, _)| index)
+                .collect();
+        }
+
+        let start = start_frame.map_or(0, |frame_num| self.index.seek_frame(frame_num));
+        let stop = stop_frame.map_or(self.index.len(), |frame_num| {
+            self.index.seek_frame(frame_num)
+        });
+
+        (start..stop.max(start)).collect()
+    }
+}
+
+#[pymethods]
+impl MappedRecording {
+    /// Memory-maps the recording at `path`
+    ///
+    /// :raises ValueError: If the file isn't a valid recording
+    #[new]
+    fn new(path: PathBuf) -> PyResult<Self> {
+        Ok(Self::open(&path)?)
+    }
+
+    /// The schema hash of the version of this module that wrote the recording.
+    /// If it isn't the same as `SCHEMA_HASH`, some records might not unpack correctly
+    #[getter]
+    fn schema_hash(&self) -> u64 {
+        self.header.schema_hash
+    }
+
+    #[getter]
+    fn match_config(&self, py: Python) -> PyResult<Option<Py<MatchConfiguration>>> {
+        unpack_match_config(py, &self.header)
+    }
+
+    fn __len__(&self) -> usize {
+        self.index.len()
+    }
+
+    /// A lazy view of a record, which isn't read until it's used
+    fn __getitem__(slf: &Bound<Self>, index: isize) -> PyResult<RecordView> {
+        let index = normalize_index(index, slf.get().index.len())?;
+
+        Ok(RecordView {
+            recording: slf.clone().unbind(),
+            index,
+            entry: slf.get().index[index],
+        })
+    }
+
+    /// Lazy views of the records with a frame number from `start_frame` up to (but not including) `stop_frame`
+    #[pyo3(signature = (start_frame=None, stop_frame=None))]
+    fn views(
+        slf: &Bound<Self>,
+        start_frame: Option<u32>,
+        stop_frame: Option<u32>,
+    ) -> Vec<RecordView> {
+        let recording = slf.get();
+
+        recording
+            .frame_range(start_frame, stop_frame)
+            .into_iter()
+            .map(|index| RecordView {
+                recording: slf.clone().unbind(),
+                index,
+                entry: recording.index[index],
+            })
+            .collect()
+    }
+
+    /// Unpacks the records with a frame number from `start_frame` up to (but not including) `stop_frame`.
+    /// The records are checked and parsed without holding the GIL,
+    /// so ranges can be unpacked from multiple threads in parallel
+    ///
+    /// :raises ValueError: If a record's checksum doesn't match
+    #[pyo3(signature = (start_frame=None, stop_frame=None))]
+    fn unpack_range(
+        &self,
+        py: Python,
+        start_frame: Option<u32>,
+        stop_frame: Option<u32>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let packets = py.detach(|| {
+            self.frame_range(start_frame, stop_frame)
+                .into_iter()
+                .map(|index| Packet::unpack(self.index[index].kind, self.record(index)?))
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+
+        Ok(packets
+            .into_iter()
+            .map(|packet| packet.into_py(py))
+            .collect())
+    }
+
+    /// Flattens the `GamePacket`s with a frame number from `start_frame` up to (but not including) `stop_frame`
+    /// into an Arrow record batch, with a row per packet
+    ///
+    /// :raises ValueError: If a record's checksum doesn't match
+    #[pyo3(signature = (start_frame=None, stop_frame=None))]
+    fn to_arrow(
+        &self,
+        py: Python,
+        start_frame: Option<u32>,
+        stop_frame: Option<u32>,
+    ) -> PyResult<RecordBatch> {
+        let packets = py.detach(|| {
+            self.frame_range(start_frame, stop_frame)
+                .into_iter()
+                .filter(|&index| self.index[index].kind == RecordKind::Core)
+                .map(|index| Packet::unpack(RecordKind::Core, self.record(index)?))
+                .filter_map(|packet| match packet {
+                    Ok(Packet::Core(flat::CorePacket {
+                        message: flat::CoreMessage::GamePacket(packet),
+                    })) => Some(Ok(packet)),
+                    Ok(_) => None,
+                    Err(err) => Some(Err(err)),
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?;
+
+        let packets = PyList::new(
+            py,
+            packets
+                .iter()
+                .map(|packet| crate::into_py_from::<_, GamePacket>(py, &**packet)),
+        )?;
+
+        Ok(RecordBatch {
+            table: Arc::new(Table::from_items(&packets)?),
+        })
+    }
+
+    /// The index of the first record at or after `frame_num`,
+    /// or the number of records if there isn't one.
+    /// If the frame numbers don't always increase, like after a match restart,
+    /// the records are scanned in order instead of binary searched
+    fn seek_frame(&self, frame_num: u32) -> usize {
+        self.index.seek_frame(frame_num)
+    }
+
+    /// The index of the first record at or after `seconds` of game time,
+    /// or the number of records if there isn't one.
+    /// Like with `seek_frame`, the records are scanned in order if the game time doesn't always increase
+    fn seek_time(&self, seconds: f64) -> usize {
+        self.index.seek_time(seconds)
+    }
+}
+
+/// A record in a `MappedRecording`, which is only read when it's used
+#[pyclass(module = "rlbot_flatbuffers.recording", frozen)]
+pub struct RecordView {
+    recording: Py<MappedRecording>,
+    /// The index of the record in the recording
+    #[pyo3(get)]
+    index: usize,
+    entry: IndexEntry,
+}
+
+#[pymethods]
+impl RecordView {
+    /// `"core"` for a `CorePacket` or `"interface"` for an `InterfacePacket`
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.entry.kind.as_str()
+    }
+
+    /// The latest `MatchInfo.frame_num` when the record was written
+    #[getter]
+    fn frame_num(&self) -> u32 {
+        self.entry.frame_num
+    }
+
+    /// The latest `MatchInfo.seconds_elapsed` when the record was written
+    #[getter]
+    fn seconds(&self) -> f64 {
+        self.entry.seconds
+    }
+
+    /// A copy of the packed `CorePacket` or `InterfacePacket`
+    ///
+    /// :raises ValueError: If the record's checksum doesn't match
+    #[getter]
+    fn data(&self, py: Python) -> PyResult<Py<PyBytes>> {
+        let data = self.recording.get().record(self.index)?;
+        Ok(PyBytes::new(py, data).unbind())
+    }
+
+    /// Unpacks the record into a `CorePacket` or `InterfacePacket`, straight from the mapped file
+    ///
+    /// :raises ValueError: If the record's checksum doesn't match
+    /// :raises InvalidFlatbuffer: If the record doesn't contain a valid packet
+    fn unpack(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let data = self.recording.get().record(self.index)?;
+        Ok(Packet::unpack(self.entry.kind, data)?.into_py(py))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RecordView(index={}, kind='{}', frame_num={}, seconds={:?})",
+            self.index,
+            self.entry.kind.as_str(),
+            self.entry.frame_num,
+            self.entry.seconds,
+        )
+    }
+}
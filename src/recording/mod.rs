@@ -0,0 +1,456 @@
+//! A file format for recording the messages of a match, exposed to Python as `rlbot_flatbuffers.recording`.
+//!
+//! A recording is laid out as (all integers are little-endian):
+//!
+//! - The header: [`MAGIC`], the format version (u32), the schema hash (u64),
+//!   and the length (u32) of the packed `MatchConfiguration` that follows, which may be empty
+//! - The records, each a [`RecordHeader`] followed by the packed message
+//! - The index, an [`IndexEntry`] per record
+//! - The footer: the offset of the index (u64), the number of records (u64) and [`FOOTER_MAGIC`]
+//!
+//! If a recording wasn't closed properly, the footer is missing and the index is rebuilt by
+//! scanning the records.
+
+mod mapped;
+mod reader;
+mod writer;
+
+pub use mapped::{MappedRecording, RecordView};
+pub use reader::{Record, RecordingReader};
+pub use writer::RecordingWriter;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::{
+    fmt, io,
+    io::{Read, Seek, SeekFrom, Write},
+    ops::Deref,
+};
+
+pub const MAGIC: [u8; 8] = *b"RLBOTREC";
+pub const FOOTER_MAGIC: [u8; 8] = *b"RLBOTIDX";
+pub const VERSION: u32 = 1;
+
+/// The length of everything in the header before the match config
+const HEADER_LEN: u64 = 8 + 4 + 8 + 4;
+const FOOTER_LEN: u64 = 8 + 8 + 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RecordKind {
+    Core = 0,
+    Interface = 1,
+}
+
+impl RecordKind {
+    fn from_u8(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Core),
+            1 => Some(Self::Interface),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Core => "core",
+            Self::Interface => "interface",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordingError {
+    Io(io::Error),
+    /// The file isn't a valid recording
+    Format(String),
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::Format(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl From<io::Error> for RecordingError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<RecordingError> for PyErr {
+    fn from(err: RecordingError) -> Self {
+        match err {
+            RecordingError::Io(err) => err.into(),
+            RecordingError::Format(msg) => PyValueError::new_err(msg),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, RecordingError>;
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// The CRC-32 (IEEE) checksum of `data`, which is the same as `zlib.crc32`
+#[must_use]
+pub fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut buf = [0; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+/// Reads `len` bytes, growing the buffer as they're read instead of trusting a length
+/// from the file enough to allocate all of it up front
+fn read_vec(reader: &mut impl Read, len: u32) -> io::Result<Vec<u8>> {
+    let mut data = Vec::new();
+    reader
+        .by_ref()
+        .take(u64::from(len))
+        .read_to_end(&mut data)?;
+
+    if data.len() != len as usize {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(data)
+}
+
+#[derive(Clone, Debug)]
+pub struct Header {
+    pub schema_hash: u64,
+    /// The packed `MatchConfiguration`, or empty if the recording doesn't have one
+    pub match_config: Vec<u8>,
+}
+
+impl Header {
+    /// The number of bytes the header takes up in the file
+    #[must_use]
+    pub fn encoded_len(&self) -> u64 {
+        HEADER_LEN + self.match_config.len() as u64
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&self.schema_hash.to_le_bytes())?;
+        writer.write_all(&(self.match_config.len() as u32).to_le_bytes())?;
+        writer.write_all(&self.match_config)
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self> {
+        if read_array(reader)? != MAGIC {
+            return Err(RecordingError::Format(String::from(
+                "The file isn't a recording",
+            )));
+        }
+
+        let version = u32::from_le_bytes(read_array(reader)?);
+        if version != VERSION {
+            return Err(RecordingError::Format(format!(
+                "Unsupported recording version {version}, expected {VERSION}"
+            )));
+        }
+
+        let schema_hash = u64::from_le_bytes(read_array(reader)?);
+        let config_len = u32::from_le_bytes(read_array(reader)?);
+        let match_config = read_vec(reader, config_len)?;
+
+        Ok(Self {
+            schema_hash,
+            match_config,
+        })
+    }
+}
+
+/// Precedes the packed message of every record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordHeader {
+    pub kind: RecordKind,
+    /// The latest `MatchInfo.frame_num` when the record was written
+    pub frame_num: u32,
+    /// The latest `MatchInfo.seconds_elapsed` when the record was written
+    pub seconds: f64,
+    pub len: u32,
+    /// The [`crc32`] of the packed message
+    pub checksum: u32,
+}
+
+impl RecordHeader {
+    pub const LEN: u64 = 1 + 3 + 4 + 8 + 4 + 4;
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&[self.kind as u8, 0, 0, 0])?;
+        writer.write_all(&self.frame_num.to_le_bytes())?;
+        writer.write_all(&self.seconds.to_le_bytes())?;
+        writer.write_all(&self.len.to_le_bytes())?;
+        writer.write_all(&self.checksum.to_le_bytes())
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self> {
+        let [kind, ..] = read_array::<4>(reader)?;
+        let kind = RecordKind::from_u8(kind)
+            .ok_or_else(|| RecordingError::Format(format!("Unknown record kind {kind}")))?;
+
+        Ok(Self {
+            kind,
+            frame_num: u32::from_le_bytes(read_array(reader)?),
+            seconds: f64::from_le_bytes(read_array(reader)?),
+            len: u32::from_le_bytes(read_array(reader)?),
+            checksum: u32::from_le_bytes(read_array(reader)?),
+        })
+    }
+}
+
+/// The location of a record in the file, along with what's needed to seek to it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IndexEntry {
+    pub offset: u64,
+    pub kind: RecordKind,
+    pub frame_num: u32,
+    pub seconds: f64,
+}
+
+impl IndexEntry {
+    pub const LEN: u64 = 8 + 1 + 3 + 4 + 8;
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.offset.to_le_bytes())?;
+        writer.write_all(&[self.kind as u8, 0, 0, 0])?;
+        writer.write_all(&self.frame_num.to_le_bytes())?;
+        writer.write_all(&self.seconds.to_le_bytes())
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self> {
+        let offset = u64::from_le_bytes(read_array(reader)?);
+        let [kind, ..] = read_array::<4>(reader)?;
+
+        Ok(Self {
+            offset,
+            kind: RecordKind::from_u8(kind)
+                .ok_or_else(|| RecordingError::Format(format!("Unknown record kind {kind}")))?,
+            frame_num: u32::from_le_bytes(read_array(reader)?),
+            seconds: f64::from_le_bytes(read_array(reader)?),
+        })
+    }
+}
+
+/// Writes the index and the footer, which must come right after the last record at `index_offset`
+pub fn write_index(
+    writer: &mut impl Write,
+    index: &[IndexEntry],
+    index_offset: u64,
+) -> io::Result<()> {
+    for entry in index {
+        entry.write(writer)?;
+    }
+
+    writer.write_all(&index_offset.to_le_bytes())?;
+    writer.write_all(&(index.len() as u64).to_le_bytes())?;
+    writer.write_all(&FOOTER_MAGIC)
+}
+
+/// The index of a recording, which is binary searched when seeking if it's sorted.
+/// A recording that spans a match restart or a replay rewind isn't,
+/// so the records are scanned in order instead
+#[derive(Clone, Debug)]
+pub struct Index {
+    entries: Vec<IndexEntry>,
+    frames_sorted: bool,
+    seconds_sorted: bool,
+}
+
+impl Index {
+    #[must_use]
+    pub fn new(entries: Vec<IndexEntry>) -> Self {
+        Self {
+            frames_sorted: entries.is_sorted_by_key(|entry| entry.frame_num),
+            seconds_sorted: entries.is_sorted_by(|a, b| a.seconds <= b.seconds),
+            entries,
+        }
+    }
+
+    /// The first record that's at or after the given frame number
+    #[must_use]
+    pub fn seek_frame(&self, frame_num: u32) -> usize {
+        if self.frames_sorted {
+            self.entries
+                .partition_point(|entry| entry.frame_num < frame_num)
+        } else {
+            self.position(|entry| entry.frame_num >= frame_num)
+        }
+    }
+
+    /// The first record that's at or after the given game time
+    #[must_use]
+    pub fn seek_time(&self, seconds: f64) -> usize {
+        if self.seconds_sorted {
+            self.entries
+                .partition_point(|entry| entry.seconds < seconds)
+        } else {
+            self.position(|entry| entry.seconds >= seconds)
+        }
+    }
+
+    /// The first record that matches `predicate`, or the number of records if there isn't one
+    fn position(&self, predicate: impl FnMut(&IndexEntry) -> bool) -> usize {
+        self.entries
+            .iter()
+            .position(predicate)
+            .unwrap_or(self.entries.len())
+    }
+}
+
+impl Deref for Index {
+    type Target = [IndexEntry];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+/// Reads the index from the footer,
+/// or rebuilds it by scanning the records if the recording wasn't closed properly
+pub fn read_index(reader: &mut (impl Read + Seek), header: &Header) -> Result<Index> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+
+    if file_len >= header.encoded_len() + FOOTER_LEN {
+        reader.seek(SeekFrom::End(-(FOOTER_LEN as i64)))?;
+        let index_offset = u64::from_le_bytes(read_array(reader)?);
+        let count = u64::from_le_bytes(read_array(reader)?);
+        let magic: [u8; 8] = read_array(reader)?;
+
+        let expected_offset = count
+            .checked_mul(IndexEntry::LEN)
+            .and_then(|len| (file_len - FOOTER_LEN).checked_sub(len));
+        if magic == FOOTER_MAGIC && expected_offset == Some(index_offset) {
+            reader.seek(SeekFrom::Start(index_offset))?;
+            let entries = (0..count)
+                .map(|_| IndexEntry::read(reader))
+                .collect::<Result<_>>()?;
+            return Ok(Index::new(entries));
+        }
+    }
+
+    Ok(Index::new(scan_index(
+        reader,
+        header.encoded_len(),
+        file_len,
+    )?))
+}
+
+fn scan_index(
+    reader: &mut (impl Read + Seek),
+    mut offset: u64,
+    file_len: u64,
+) -> Result<Vec<IndexEntry>> {
+    let mut index = Vec::new();
+
+    // a record that was only partially written is ignored
+    while offset + RecordHeader::LEN <= file_len {
+        reader.seek(SeekFrom::Start(offset))?;
+        let Ok(record) = RecordHeader::read(reader) else {
+            break;
+        };
+
+        let end = offset + RecordHeader::LEN + u64::from(record.len);
+        if end > file_len {
+            break;
+        }
+
+        index.push(IndexEntry {
+            offset,
+            kind: record.kind,
+            frame_num: record.frame_num,
+            seconds: record.seconds,
+        });
+        offset = end;
+    }
+
+    Ok(index)
+}
+
+fn check_checksum(entry: &IndexEntry, header: &RecordHeader, data: &[u8]) -> Result<()> {
+    if crc32(data) != header.checksum {
+        return Err(RecordingError::Format(format!(
+            "The record at offset {} is corrupted, its checksum doesn't match",
+            entry.offset
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads the packed message of the record at `entry`, checking that it isn't corrupted
+pub fn read_record(reader: &mut (impl Read + Seek), entry: &IndexEntry) -> Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(entry.offset))?;
+    let header = RecordHeader::read(reader)?;
+    let data = read_vec(reader, header.len)?;
+
+    check_checksum(entry, &header, &data)?;
+    Ok(data)
+}
+
+/// Slices the packed message of the record at `entry` out of a recording that's in memory,
+/// checking that it isn't corrupted
+pub fn slice_record<'a>(recording: &'a [u8], entry: &IndexEntry) -> Result<&'a [u8]> {
+    let out_of_bounds = || {
+        RecordingError::Format(format!(
+            "The record at offset {} is past the end of the recording",
+            entry.offset
+        ))
+    };
+
+    let mut reader = usize::try_from(entry.offset)
+        .ok()
+        .and_then(|offset| recording.get(offset..))
+        .ok_or_else(out_of_bounds)?;
+    let header = RecordHeader::read(&mut reader).map_err(|_| out_of_bounds())?;
+    let data = reader
+        .get(..header.len as usize)
+        .ok_or_else(out_of_bounds)?;
+
+    check_checksum(entry, &header, data)?;
+    Ok(data)
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "recording")?;
+    m.add(
+        "__doc__",
+        "Reading and writing recordings of the messages of a match.",
+    )?;
+    m.add("SCHEMA_HASH", crate::schema::hash())?;
+    m.add_class::<RecordingWriter>()?;
+    m.add_class::<RecordingReader>()?;
+    m.add_class::<Record>()?;
+    m.add_class::<MappedRecording>()?;
+    m.add_class::<RecordView>()?;
+    Ok(m)
+}
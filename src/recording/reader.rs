@@ -0,0 +1,249 @@
+use super::{Header, Index, IndexEntry, RecordKind, read_index, read_record};
+use crate::{
+    flat, flat_err_to_py,
+    python::{CorePacket, InterfacePacket, MatchConfiguration},
+};
+use planus::ReadAsRoot;
+use pyo3::{exceptions::PyIndexError, prelude::*, types::*};
+use std::{fs::File, io::BufReader, path::PathBuf};
+
+/// A message in a recording
+#[pyclass(module = "rlbot_flatbuffers.recording", frozen)]
+pub struct Record {
+    /// The index of the record in the recording
+    #[pyo3(get)]
+    pub index: usize,
+    pub kind: RecordKind,
+    /// The latest `MatchInfo.frame_num` when the record was written
+    #[pyo3(get)]
+    pub frame_num: u32,
+    /// The latest `MatchInfo.seconds_elapsed` when the record was written
+    #[pyo3(get)]
+    pub seconds: f64,
+    /// The packed `CorePacket` or `InterfacePacket`
+    #[pyo3(get)]
+    pub data: Py<PyBytes>,
+}
+
+impl Record {
+    pub fn new(py: Python, index: usize, entry: &IndexEntry, data: &[u8]) -> Self {
+        Self {
+            index,
+            kind: entry.kind,
+            frame_num: entry.frame_num,
+            seconds: entry.seconds,
+            data: PyBytes::new(py, data).unbind(),
+        }
+    }
+}
+
+/// An unpacked record
+pub enum Packet {
+    Core(flat::CorePacket),
+    Interface(flat::InterfacePacket),
+}
+
+impl Packet {
+    /// Unpacks the packed message of a record, which doesn't need the GIL
+    pub fn unpack(kind: RecordKind, data: &[u8]) -> PyResult<Self> {
+        Ok(match kind {
+            RecordKind::Core => {
+                let flat_t_ref = flat::CorePacketRef::read_as_root(data).map_err(flat_err_to_py)?;
+                Self::Core(flat::CorePacket::try_from(flat_t_ref).map_err(flat_err_to_py)?)
+            }
+            RecordKind::Interface => {
+                let flat_t_ref =
+                    flat::InterfacePacketRef::read_as_root(data).map_err(flat_err_to_py)?;
+                Self::Interface(
+                    flat::InterfacePacket::try_from(flat_t_ref).map_err(flat_err_to_py)?,
+                )
+            }
+        })
+    }
+
+    /// Converts the packet into a `CorePacket` or `InterfacePacket`
+    pub fn into_py(self, py: Python) -> Py<PyAny> {
+        match self {
+            Self::Core(flat_t) => crate::into_py_from::<_, CorePacket>(py, &flat_t).into_any(),
+            Self::Interface(flat_t) => {
+                crate::into_py_from::<_, InterfacePacket>(py, &flat_t).into_any()
+            }
+        }
+    }
+}
+
+/// Unpacks the `MatchConfiguration` stored in the header of a recording, if there is one
+pub fn unpack_match_config(
+    py: Python,
+    header: &Header,
+) -> PyResult<Option<Py<MatchConfiguration>>> {
+    if header.match_config.is_empty() {
+        return Ok(None);
+    }
+
+    let flat_t_ref =
+        flat::MatchConfigurationRef::read_as_root(&header.match_config).map_err(flat_err_to_py)?;
+    let flat_t = flat::MatchConfiguration::try_from(flat_t_ref).map_err(flat_err_to_py)?;
+
+    Ok(Some(crate::into_py_from(py, &flat_t)))
+}
+
+/// Converts a Python index, which may be negative, into an index into `len` items
+pub fn normalize_index(index: isize, len: usize) -> PyResult<usize> {
+    let normalized = if index < 0 {
+        index + len as isize
+    } else {
+        index
+    };
+
+    usize::try_from(normalized)
+        .ok()
+        .filter(|&index| index < len)
+        .ok_or_else(|| PyIndexError::new_err("record index out of range"))
+}
+
+#[pymethods]
+impl Record {
+    /// `"core"` for a `CorePacket` or `"interface"` for an `InterfacePacket`
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
+    /// Unpacks the record into a `CorePacket` or `InterfacePacket`
+    ///
+    /// :raises InvalidFlatbuffer: If the record doesn't contain a valid packet
+    fn unpack(&self, py: Python) -> PyResult<Py<PyAny>> {
+        Ok(Packet::unpack(self.kind, self.data.as_bytes(py))?.into_py(py))
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!(
+            "Record(index={}, kind='{}', frame_num={}, seconds={:?}, len={})",
+            self.index,
+            self.kind.as_str(),
+            self.frame_num,
+            self.seconds,
+            self.data.as_bytes(py).len()
+        )
+    }
+}
+
+/// Reads a recording, with random access to every record through its index
+#[pyclass(module = "rlbot_flatbuffers.recording")]
+pub struct RecordingReader {
+    file: BufReader<File>,
+    header: Header,
+    index: Index,
+}
+
+impl RecordingReader {
+    fn record(&mut self, py: Python, index: usize) -> PyResult<Record> {
+        let entry = self.index[index];
+        let data = read_record(&mut self.file, &entry)?;
+        Ok(Record::new(py, index, &entry, &data))
+    }
+}
+
+#[pymethods]
+impl RecordingReader {
+    /// Opens the recording at `path`
+    ///
+    /// :raises ValueError: If the file isn't a valid recording
+    #[new]
+    fn new(path: PathBuf) -> PyResult<Self> {
+        let mut file = BufReader::new(File::open(path)?);
+        let header = Header::read(&mut file)?;
+        let index = read_index(&mut file, &header)?;
+
+        Ok(Self {
+            file,
+            header,
+            index,
+        })
+    }
+
+    /// The schema hash of the version of this module that wrote the recording.
+    /// If it isn't the same as `SCHEMA_HASH`, some records might not unpack correctly
+    #[getter]
+    fn schema_hash(&self) -> u64 {
+        self.header.schema_hash
+    }
+
+    #[getter]
+    fn match_config(&self, py: Python) -> PyResult<Option<Py<MatchConfiguration>>> {
+        unpack_match_config(py, &self.header)
+    }
+
+    fn __len__(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Reads a record, checking that it isn't corrupted
+    ///
+    /// :raises ValueError: If the record's checksum doesn't match
+    fn __getitem__(&mut self, py: Python, index: isize) -> PyResult<Record> {
+        let index = normalize_index(index, self.index.len())?;
+        self.record(py, index)
+    }
+
+    fn __iter__(slf: Py<Self>, py: Python) -> RecordIterator {
+        let stop = slf.borrow(py).index.len();
+        RecordIterator {
+            reader: slf,
+            next: 0,
+            stop,
+        }
+    }
+
+    /// Iterates over the records from `start` until `stop`, reading one record at a time
+    #[pyo3(signature = (start=0, stop=None))]
+    fn records(slf: Py<Self>, py: Python, start: usize, stop: Option<usize>) -> RecordIterator {
+        let len = slf.borrow(py).index.len();
+        RecordIterator {
+            reader: slf,
+            next: start,
+            stop: stop.map_or(len, |stop| stop.min(len)),
+        }
+    }
+
+    /// The index of the first record at or after `frame_num`,
+    /// or the number of records if there isn't one.
+    /// If the frame numbers don't always increase, like after a match restart,
+    /// the records are scanned in order instead of binary searched
+    fn seek_frame(&self, frame_num: u32) -> usize {
+        self.index.seek_frame(frame_num)
+    }
+
+    /// The index of the first record at or after `seconds` of game time,
+    /// or the number of records if there isn't one.
+    /// Like with `seek_frame`, the records are scanned in order if the game time doesn't always increase
+    fn seek_time(&self, seconds: f64) -> usize {
+        self.index.seek_time(seconds)
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers.recording")]
+pub struct RecordIterator {
+    reader: Py<RecordingReader>,
+    next: usize,
+    stop: usize,
+}
+
+#[pymethods]
+impl RecordIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<Record>> {
+        if self.next >= self.stop {
+            return Ok(None);
+        }
+
+        let record = self.reader.borrow_mut(py).record(py, self.next)?;
+        self.next += 1;
+
+        Ok(Some(record))
+    }
+}
@@ -0,0 +1,167 @@
+use super::{Header, IndexEntry, RecordHeader, RecordKind, crc32, write_index};
+use crate::{
+    FromGil, flat,
+    python::{CorePacket, InterfacePacket, MatchConfiguration},
+};
+use planus::Builder;
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+/// Writes messages to a new recording file.
+/// The index is written when the recording is closed, which also happens when it's garbage collected
+#[pyclass(module = "rlbot_flatbuffers.recording")]
+pub struct RecordingWriter {
+    file: Option<BufWriter<File>>,
+    index: Vec<IndexEntry>,
+    offset: u64,
+    frame_num: u32,
+    seconds: f64,
+}
+
+impl RecordingWriter {
+    pub fn create(path: &PathBuf, match_config: Vec<u8>) -> io::Result<Self> {
+        let header = Header {
+            schema_hash: crate::schema::hash(),
+            match_config,
+        };
+
+        let mut file = BufWriter::new(File::create(path)?);
+        header.write(&mut file)?;
+
+        Ok(Self {
+            file: Some(file),
+            index: Vec::new(),
+            offset: header.encoded_len(),
+            frame_num: 0,
+            seconds: 0.0,
+        })
+    }
+
+    /// Writes a packed `CorePacket` or `InterfacePacket`,
+    /// timestamped with the latest frame number and game time
+    pub fn write_record(&mut self, kind: RecordKind, data: &[u8]) -> PyResult<()> {
+        let Some(file) = &mut self.file else {
+            return Err(PyValueError::new_err("The recording is already closed"));
+        };
+
+        let header = RecordHeader {
+            kind,
+            frame_num: self.frame_num,
+            seconds: self.seconds,
+            len: data.len() as u32,
+            checksum: crc32(data),
+        };
+        header.write(file)?;
+        file.write_all(data)?;
+
+        self.index.push(IndexEntry {
+            offset: self.offset,
+            kind,
+            frame_num: self.frame_num,
+            seconds: self.seconds,
+        });
+        self.offset += RecordHeader::LEN + data.len() as u64;
+
+        Ok(())
+    }
+
+    /// Writes the index and closes the file, if it isn't already closed
+    pub fn finish(&mut self) -> io::Result<()> {
+        let Some(mut file) = self.file.take() else {
+            return Ok(());
+        };
+
+        write_index(&mut file, &self.index, self.offset)?;
+        file.flush()
+    }
+}
+
+impl Drop for RecordingWriter {
+    fn drop(&mut self) {
+        // errors can't be reported here, call `close` to handle them
+        let _ = self.finish();
+    }
+}
+
+#[pymethods]
+impl RecordingWriter {
+    /// Creates (or overwrites) the recording at `path`, which can optionally store the match config
+    #[new]
+    #[pyo3(signature = (path, match_config=None))]
+    fn new(
+        py: Python,
+        path: PathBuf,
+        match_config: Option<PyRef<MatchConfiguration>>,
+    ) -> PyResult<Self> {
+        let match_config = match match_config {
+            Some(match_config) => {
+                let mut builder = Builder::with_capacity(u16::MAX as usize);
+                let flat_t = flat::MatchConfiguration::from_gil(py, &*match_config);
+                builder.finish(flat_t, None).to_vec()
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Self::create(&path, match_config)?)
+    }
+
+    /// Writes a `CorePacket` or `InterfacePacket` to the recording.
+    /// Every record is timestamped with the frame number and game time of the latest `GamePacket`
+    fn write(&mut self, py: Python, packet: &Bound<PyAny>) -> PyResult<()> {
+        let mut builder = Builder::with_capacity(u16::MAX as usize);
+
+        if let Ok(packet) = packet.cast::<CorePacket>() {
+            let flat_t = flat::CorePacket::from_gil(py, packet.get());
+
+            if let flat::CoreMessage::GamePacket(game_packet) = &flat_t.message {
+                self.frame_num = game_packet.match_info.frame_num;
+                self.seconds = f64::from(game_packet.match_info.seconds_elapsed);
+            }
+
+            self.write_record(RecordKind::Core, builder.finish(flat_t, None))
+        } else if let Ok(packet) = packet.cast::<InterfacePacket>() {
+            let flat_t = flat::InterfacePacket::from_gil(py, packet.get());
+            self.write_record(RecordKind::Interface, builder.finish(flat_t, None))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "Expected a CorePacket or InterfacePacket, got {}",
+                packet.get_type().name()?
+            )))
+        }
+    }
+
+    /// Writes the index and closes the file
+    fn close(&mut self) -> PyResult<()> {
+        Ok(self.finish()?)
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.file.is_none()
+    }
+
+    /// The number of records written so far
+    fn __len__(&self) -> usize {
+        self.index.len()
+    }
+
+    fn __enter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: &Bound<PyAny>,
+        _exc_value: &Bound<PyAny>,
+        _traceback: &Bound<PyAny>,
+    ) -> PyResult<()> {
+        self.close()
+    }
+}
@@ -0,0 +1,4814 @@
+//! The schema of every generated type, sorted by name.
+//! This file is generated by `codegen/schema.rs`.
+
+use super::{FieldSchema, SchemaKind, TypeSchema, VariantSchema};
+
+pub static TYPES: [TypeSchema; 113] = [
+    TypeSchema {
+        name: "AerialGoalScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "One",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Zero",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Three",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Five",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Ten",
+                value: 5,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "AirState",
+        kind: SchemaKind::Enum,
+        doc: Some(
+            "Possible states of a car in regards to ground contact and jump/dodging forces.\nSee more about jumping physics at https://wiki.rlbot.org/botmaking/jumping-physics/",
+        ),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "OnGround",
+                value: 0,
+                doc: Some(
+                    "All wheels are on the ground and the car is affected by wall-stickiness forces.",
+                ),
+            },
+            VariantSchema {
+                name: "Jumping",
+                value: 1,
+                doc: Some(
+                    "The car is currently affected by jumping forces of an initial jump.\nLasts until the player lets go of jump button but at most for 0.2 seconds (240 ticks).\nThe following AirState is typically InAir.",
+                ),
+            },
+            VariantSchema {
+                name: "DoubleJumping",
+                value: 2,
+                doc: Some(
+                    "The car is currently affected by jumping forces of a secondary jump (just an impulse in practice).\nLasts for 13 ticks.\nThe following AirState is typically InAir.",
+                ),
+            },
+            VariantSchema {
+                name: "Dodging",
+                value: 3,
+                doc: Some(
+                    "The car is currently affected by forces and torque of a dodges.\nThis lasts for 79 ticks.\nThe following AirState is typically InAir.",
+                ),
+            },
+            VariantSchema {
+                name: "InAir",
+                value: 4,
+                doc: Some("The car is free falling."),
+            },
+        ],
+    },
+    TypeSchema {
+        name: "AssistGoalScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Zero",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "One",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Three",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "AudioMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Audio mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Haunted",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallAnchor",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderAnchor attached to a ball.\nThe local field allows for an offset in local coordinates taking the ball's orientation into account.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the ball."),
+            },
+            FieldSchema {
+                name: "local",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "An offset in local coordinates.\nx is forwards, y is left, and z is up.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "BallBouncinessMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball bounciness mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Low",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "High",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SuperHigh",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Lowish",
+                value: 4,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallGravityMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball gravity mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Low",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "High",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SuperHigh",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallInfo",
+        kind: SchemaKind::Table,
+        doc: Some("Information about a ball."),
+        fields: &[
+            FieldSchema {
+                name: "physics",
+                type_hint: "Physics",
+                base_type: "Physics",
+                default: "Physics()",
+                optional: false,
+                is_list: false,
+                doc: Some("The physical state of the ball."),
+            },
+            FieldSchema {
+                name: "shape",
+                type_hint: "BoxShape | CylinderShape | SphereShape",
+                base_type: "CollisionShape",
+                default: "BoxShape()",
+                optional: false,
+                is_list: false,
+                doc: Some("The collision shape of the ball."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "BallMaxSpeedMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball max speed mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Slow",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Fast",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SuperFast",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallPrediction",
+        kind: SchemaKind::Table,
+        doc: Some("A prediction of a ball's trajectory, assuming no collision with cars."),
+        fields: &[FieldSchema {
+            name: "slices",
+            type_hint: "Sequence[PredictionSlice]",
+            base_type: "PredictionSlice",
+            default: "[]",
+            optional: false,
+            is_list: true,
+            doc: Some(
+                "A list of predicted states of the ball at specific times in the future, assuming no collision with cars.\nThe beginning of the list is now, and the end is 6 seconds into the future.\nThe prediction is made at 120 Hz, resulting in 720 entries.",
+            ),
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "BallSizeMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball size mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Small",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Medium",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Large",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Gigantic",
+                value: 4,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallTypeMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball type mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Cube",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Puck",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Basketball",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Beachball",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Anniversary",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Haunted",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Ekin",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SpookyCube",
+                value: 8,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Egg",
+                value: 9,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PlayerSeeking",
+                value: 10,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Dropshot",
+                value: 11,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ScoreAbsorb",
+                value: 12,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Shoe",
+                value: 13,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PizzaPuck",
+                value: 14,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BallWeightMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Ball weight mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Light",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Heavy",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SuperLight",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "CurveBall",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "BeachBallCurve",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MagnusFutBall",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MagnusFutballLess",
+                value: 7,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BoostAmountMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Boost amount mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "NormalBoost",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "UnlimitedBoost",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SlowRecharge",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "RapidRecharge",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "NoBoost",
+                value: 4,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BoostPad",
+        kind: SchemaKind::Table,
+        doc: Some("Static information about a boost pad such as location and size."),
+        fields: &[
+            FieldSchema {
+                name: "location",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some("The location of the boost pad."),
+            },
+            FieldSchema {
+                name: "is_full_boost",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Whether the boost pad provides a full tank of boost.\nA big boost pad provides 100 boost and respawns in 10 seconds.\nA small boost pad provides 12 boost and respawns in 4 seconds.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "BoostPadState",
+        kind: SchemaKind::Struct,
+        doc: Some(
+            "The state of a boost pad.\nNote, static properties of boost pads, such as their location and size, are found in the field info.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "is_active",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if the boost can be picked up right now."),
+            },
+            FieldSchema {
+                name: "timer",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The number of seconds since the boost has been picked up, or 0 if the boost is active.\nA big boost pad becomes active again after 10 seconds.\nA small boost pad becomes active again after 4 seconds.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "BoostStrengthMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Boost strength mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "One",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OneAndAHalf",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Five",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Ten",
+                value: 4,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "BoxShape",
+        kind: SchemaKind::Table,
+        doc: Some("A box with dimensions.\nUsed for cars and balls with a box shapes."),
+        fields: &[
+            FieldSchema {
+                name: "length",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "width",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "height",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "CarAnchor",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderAnchor attached to a car.\nThe local field allows for an offset in local coordinates taking the car's orientation into account.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the car."),
+            },
+            FieldSchema {
+                name: "local",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "An offset in local coordinates.\nx is forwards, y is left, and z is up.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "CollisionShape",
+        kind: SchemaKind::Union,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "BoxShape",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SphereShape",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "CylinderShape",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Color",
+        kind: SchemaKind::Struct,
+        doc: Some("An RGBA color."),
+        fields: &[
+            FieldSchema {
+                name: "r",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "g",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "b",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "a",
+                type_hint: "int",
+                base_type: "int",
+                default: "255",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ConnectionSettings",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Sent by clients when connecting to RLBot to indicate what type of messages are desired.\nThis could be sent by a bot, or a bot manager governing several bots, an\noverlay, or any other utility that connects to the RLBot process.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "agent_id",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The ID of the bot/script that is associated with the incoming connection.",
+                ),
+            },
+            FieldSchema {
+                name: "wants_ball_predictions",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If this is set, RLBot will send BallPrediction data back to the client when available.",
+                ),
+            },
+            FieldSchema {
+                name: "wants_comms",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If this is set, RLBot will send MatchComms to the client when available.",
+                ),
+            },
+            FieldSchema {
+                name: "close_between_matches",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If this is set, RLBot will close the connection when a match is stopped or when a new\nmatch is started. The GUI and other match runners should likely not set this.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ConsoleCommand",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A console command which will be executed inside Rocket League.\nSee https://wiki.rlbot.org/framework/console-commands/ for a list of known commands.",
+        ),
+        fields: &[FieldSchema {
+            name: "command",
+            type_hint: "str",
+            base_type: "str",
+            default: "\"\"",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ControllableInfo",
+        kind: SchemaKind::Table,
+        doc: Some("Information about a car that the client can control."),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the bot/script."),
+            },
+            FieldSchema {
+                name: "identifier",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The id of the bot/script.\nThis value is mostly used internally to keep track of participants in the match.\nThe id can be used to find the corresponding PlayerConfiguration in the MatchConfiguration.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ControllableTeamInfo",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Server message with information about the cars that the client can control.\nSent to bot clients as a response to ConnectionSettings.\nThere may be more than one car in case the bot is a hivemind.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "team",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The assigned team for this client."),
+            },
+            FieldSchema {
+                name: "controllables",
+                type_hint: "Sequence[ControllableInfo]",
+                base_type: "ControllableInfo",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The bots that RLBot will allow this client to control."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ControllerState",
+        kind: SchemaKind::Struct,
+        doc: Some(
+            "A combination of button presses and analog steering values like those produced by a physical controller or keyboard.\nThis is sent by bots each tick to RLBot to indicate what they want to do that tick.\nFor example, if you want to hold the jump button for 20 ticks, then you must send 20 controller states where jump is true.\nRemember to send controller states with jump set to false to let go of the jump button afterwards.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "throttle",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("-1 for full reverse, 1 for full forward."),
+            },
+            FieldSchema {
+                name: "steer",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("-1 for full left, 1 for full right."),
+            },
+            FieldSchema {
+                name: "pitch",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("-1 for nose down, 1 for nose up."),
+            },
+            FieldSchema {
+                name: "yaw",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("-1 for full left, 1 for full right."),
+            },
+            FieldSchema {
+                name: "roll",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("-1 for roll left, 1 for roll right."),
+            },
+            FieldSchema {
+                name: "jump",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if you want to press the jump button."),
+            },
+            FieldSchema {
+                name: "boost",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if you want to press the boost button."),
+            },
+            FieldSchema {
+                name: "handbrake",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if you want to press the handbrake button."),
+            },
+            FieldSchema {
+                name: "use_item",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "True if you want to press the 'use item' button. Used in Rumble and other game modes.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "CoreMessage",
+        kind: SchemaKind::Union,
+        doc: Some("Messages sent from core to interfaces"),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "DisconnectSignal",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "GamePacket",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FieldInfo",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MatchConfiguration",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MatchComm",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "BallPrediction",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ControllableTeamInfo",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "RenderingStatus",
+                value: 8,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PingRequest",
+                value: 9,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PingResponse",
+                value: 10,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "CorePacket",
+        kind: SchemaKind::Table,
+        doc: Some("Packet containing a CoreMessage"),
+        fields: &[FieldSchema {
+            name: "message",
+            type_hint: "BallPrediction | ControllableTeamInfo | DisconnectSignal | FieldInfo | GamePacket | MatchComm | MatchConfiguration | PingRequest | PingResponse | RenderingStatus",
+            base_type: "CoreMessage",
+            default: "BallPrediction()",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "CustomBot",
+        kind: SchemaKind::Table,
+        doc: Some("A bot controlled by the RLBot framework."),
+        fields: &[
+            FieldSchema {
+                name: "name",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Requested bot name. When match start, RLBot will ensure each bot has a unique name; bots with\nduplicate names will be renamed with a suffix like `(2)`",
+                ),
+            },
+            FieldSchema {
+                name: "root_dir",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The root directory of the bot and the working directory for the run command.",
+                ),
+            },
+            FieldSchema {
+                name: "run_command",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("The console command that starts this bot."),
+            },
+            FieldSchema {
+                name: "loadout",
+                type_hint: "PlayerLoadout | None",
+                base_type: "PlayerLoadout",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some("The loadout of the player."),
+            },
+            FieldSchema {
+                name: "agent_id",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "A unique user-defined string that is used to connect clients to the right players/scripts.\nIf a bot/script has a run command, RLBot will pass this agent id to the process using an environment variable, RLBOT_AGENT_ID.\nUpon connecting the process announces that it is responsible for this agent id and RLBot will pair the two.\nThe recommended format for agent ids is \"developer_name/bot_name\".",
+                ),
+            },
+            FieldSchema {
+                name: "hivemind",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Whether this player is part of a hivemind bot where all players/cars are controlled by the same process.\nPlayers in the hivemind must have the same name, team, run command, and agent id.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "CylinderShape",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A cylinder with diameter and height.\nUsed for balls with a cylindrical shape like the puck.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "diameter",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "height",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DebugRendering",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "OffByDefault",
+                value: 0,
+                doc: Some("Renders are ignored unless explicitly enabled per-agent"),
+            },
+            VariantSchema {
+                name: "OnByDefault",
+                value: 1,
+                doc: Some("Rendering is enabled for everyone by default"),
+            },
+            VariantSchema {
+                name: "AlwaysOff",
+                value: 2,
+                doc: Some("Ignore all render attempts at all times"),
+            },
+        ],
+    },
+    TypeSchema {
+        name: "DemolishMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Demolition mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Disabled",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FriendlyFire",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnContact",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnContactFf",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnBallContact",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnBallContactFf",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnBallContactSilent",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OnBallContactFfSilent",
+                value: 8,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "DemolishScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Zero",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "One",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Three",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "DesiredBallState",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A ball state with nullable components.\nUsed for game state setting to define which part of a ball's state should change.",
+        ),
+        fields: &[FieldSchema {
+            name: "physics",
+            type_hint: "DesiredPhysics",
+            base_type: "DesiredPhysics",
+            default: "DesiredPhysics()",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DesiredCarState",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A car state with nullable components.\nUsed for game state setting to define which part of a car's state should change.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "physics",
+                type_hint: "DesiredPhysics | None",
+                base_type: "DesiredPhysics",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "boost_amount",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DesiredGameState",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A game state with nullable subcomponents.\nUsed for game state setting to define which part of the game should change.\nValues not set will not be updated.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "ball_states",
+                type_hint: "Sequence[DesiredBallState]",
+                base_type: "DesiredBallState",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("A list of desired ball states."),
+            },
+            FieldSchema {
+                name: "car_states",
+                type_hint: "Sequence[DesiredCarState]",
+                base_type: "DesiredCarState",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("A list of desired car states."),
+            },
+            FieldSchema {
+                name: "match_info",
+                type_hint: "DesiredMatchInfo | None",
+                base_type: "DesiredMatchInfo",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some("The desired game info."),
+            },
+            FieldSchema {
+                name: "console_commands",
+                type_hint: "Sequence[ConsoleCommand]",
+                base_type: "ConsoleCommand",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some(
+                    "A list of console commands to execute.\nSee https://wiki.rlbot.org/framework/console-commands/ for a list of known commands.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DesiredMatchInfo",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Match info with nullable components.\nUsed for game state setting to define which part of the match info should change.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "world_gravity_z",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "The strength of gravity. Default is usually -650 depending on mutators.\nTo set gravity to 0, use 0.0000001 instead, as 0 will set gravity back to the default.",
+                ),
+            },
+            FieldSchema {
+                name: "game_speed",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some("The game speed. Default is 1.0."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DesiredPhysics",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A physical state of an object, with nullable components.\nUsed for game state setting to define which part of a physics body should change.\nIf a component is null, then the component will keep its current value.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "location",
+                type_hint: "Vector3Partial | None",
+                base_type: "Vector3Partial",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "rotation",
+                type_hint: "RotatorPartial | None",
+                base_type: "RotatorPartial",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "velocity",
+                type_hint: "Vector3Partial | None",
+                base_type: "Vector3Partial",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "angular_velocity",
+                type_hint: "Vector3Partial | None",
+                base_type: "Vector3Partial",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DisconnectSignal",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Sent to core to indicate that you want to disconnect.\nSent from core to indicate that you should exit.",
+        ),
+        fields: &[],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "DodgeTimerMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "OnePointTwentyFiveSeconds",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TwoSeconds",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThreeSeconds",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Unlimited",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "ExistingMatchBehavior",
+        kind: SchemaKind::Enum,
+        doc: Some(
+            "Possible behaviours when a match is started while another match is in progress.",
+        ),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Restart",
+                value: 0,
+                doc: Some("Always restart the match, even if config is identical."),
+            },
+            VariantSchema {
+                name: "ContinueAndSpawn",
+                value: 1,
+                doc: Some(
+                    "Never restart an existing match if possible, just try to remove or spawn cars to match the configuration.\nIf we are not in the middle of a match, a match will be started. Handy for LAN matches.",
+                ),
+            },
+            VariantSchema {
+                name: "RestartIfDifferent",
+                value: 2,
+                doc: Some("Restart the match if any match settings differ.\nNo other otherwise."),
+            },
+        ],
+    },
+    TypeSchema {
+        name: "FieldInfo",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Static information about the field.\nSent to bots, scripts, etc. upon connecting.\nDynamic information is found in the GamePacket.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "boost_pads",
+                type_hint: "Sequence[BoostPad]",
+                base_type: "BoostPad",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some(
+                    "Static information about boost pads on the field.\nThe dynamic information is found in the GamePacket\nThe boost pads are ordered by y-coordinate and then x-coordinate.",
+                ),
+            },
+            FieldSchema {
+                name: "goals",
+                type_hint: "Sequence[GoalInfo]",
+                base_type: "GoalInfo",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("Information about the goals on the field."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "GameEventMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Game event mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Haunted",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rugby",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "GameMode",
+        kind: SchemaKind::Enum,
+        doc: Some("Various game modes."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Soccar",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Hoops",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Dropshot",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Snowday",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rumble",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Heatseeker",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Gridiron",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Knockout",
+                value: 7,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "GamePacket",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A packet of data from the game.\nIs is sent every tick to bots, scripts, etc.\nStatic data is found in the FieldInfo.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "players",
+                type_hint: "Sequence[PlayerInfo]",
+                base_type: "PlayerInfo",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The current state of all players and their cars."),
+            },
+            FieldSchema {
+                name: "boost_pads",
+                type_hint: "Sequence[BoostPadState]",
+                base_type: "BoostPadState",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some(
+                    "The current state of all boost pads.\nThe static information about boost pads are found in the FieldInfo.\nThe boost pads are ordered by y-coordinate and then x-coordinate.",
+                ),
+            },
+            FieldSchema {
+                name: "balls",
+                type_hint: "Sequence[BallInfo]",
+                base_type: "BallInfo",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The current state of all balls."),
+            },
+            FieldSchema {
+                name: "match_info",
+                type_hint: "MatchInfo",
+                base_type: "MatchInfo",
+                default: "MatchInfo()",
+                optional: false,
+                is_list: false,
+                doc: Some("The current state of the match such as timers and gravity."),
+            },
+            FieldSchema {
+                name: "teams",
+                type_hint: "Sequence[TeamInfo]",
+                base_type: "TeamInfo",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The current state of teams, i.e. the team scores."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "GameSpeedMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Game speed mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SloMo",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TimeWarp",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "GoalInfo",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Static information about a goal on the field such as dimensions and location.\nMore values can be found on https://wiki.rlbot.org/botmaking/useful-game-values/",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "team_num",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the team that this goal belongs to."),
+            },
+            FieldSchema {
+                name: "location",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some("The center location of the goal."),
+            },
+            FieldSchema {
+                name: "direction",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some("The unit direction point away from the opening of the goal."),
+            },
+            FieldSchema {
+                name: "width",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("The width of the goal. 1785 uu wide on a standard field."),
+            },
+            FieldSchema {
+                name: "height",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("The height of the goal. 643 uu tall on a standard field."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "GravityMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Gravity mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Low",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "High",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SuperHigh",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Reverse",
+                value: 4,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Human",
+        kind: SchemaKind::Table,
+        doc: Some("A normal human player."),
+        fields: &[],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "InitComplete",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Indicates that the session has finished all initialization and is ready to start receiving\ngame messages without delay.",
+        ),
+        fields: &[],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "InputRestrictionMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Backwards",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "InterfaceMessage",
+        kind: SchemaKind::Union,
+        doc: Some("Messages sent from interfaces to core"),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "DisconnectSignal",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "StartCommand",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MatchConfiguration",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PlayerInput",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "DesiredGameState",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "RenderGroup",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "RemoveRenderGroup",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "MatchComm",
+                value: 8,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ConnectionSettings",
+                value: 9,
+                doc: None,
+            },
+            VariantSchema {
+                name: "StopCommand",
+                value: 10,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SetLoadout",
+                value: 11,
+                doc: None,
+            },
+            VariantSchema {
+                name: "InitComplete",
+                value: 12,
+                doc: None,
+            },
+            VariantSchema {
+                name: "RenderingStatus",
+                value: 13,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PingRequest",
+                value: 14,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PingResponse",
+                value: 15,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "InterfacePacket",
+        kind: SchemaKind::Table,
+        doc: Some("Packet containing a InterfaceMessage"),
+        fields: &[FieldSchema {
+            name: "message",
+            type_hint: "ConnectionSettings | DesiredGameState | DisconnectSignal | InitComplete | MatchComm | MatchConfiguration | PingRequest | PingResponse | PlayerInput | RemoveRenderGroup | RenderGroup | RenderingStatus | SetLoadout | StartCommand | StopCommand",
+            base_type: "InterfaceMessage",
+            default: "ConnectionSettings()",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "JumpMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Grounded",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Three",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Four",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Unlimited",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "NoJumps",
+                value: 6,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Launcher",
+        kind: SchemaKind::Enum,
+        doc: Some("Possible to launch Rocket League."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Steam",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Epic",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Custom",
+                value: 2,
+                doc: Some(
+                    "E.g. if you use Legendary.\nThe game path is specified in the MatchConfiguration.",
+                ),
+            },
+            VariantSchema {
+                name: "NoLaunch",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Line3D",
+        kind: SchemaKind::Table,
+        doc: Some("A RenderMessage for a line in 3D space between two RenderAnchors."),
+        fields: &[
+            FieldSchema {
+                name: "start",
+                type_hint: "RenderAnchor",
+                base_type: "RenderAnchor",
+                default: "RenderAnchor()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "end",
+                type_hint: "RenderAnchor",
+                base_type: "RenderAnchor",
+                default: "RenderAnchor()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "color",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "LoadoutPaint",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Specification for 'painted' items. See https://wiki.rlbot.org/botmaking/bot-customization/",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "car_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "decal_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "wheels_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "boost_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "antenna_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "hat_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "trails_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "goal_explosion_paint_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "MatchComm",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A message sent to other bots and scripts through RLBot.\nUse the team_only field if the message should only be received by team mates.\nThe message can also have a human-readable summary displayed in quick chat through the display field.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The index of the player that sent this message.\nFor scripts, this value is the index in the match configuration instead.",
+                ),
+            },
+            FieldSchema {
+                name: "team",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The team of the player that sent this message.\nFor scripts, this value is 2.",
+                ),
+            },
+            FieldSchema {
+                name: "team_only",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if this message is team-only, false if everyone can see it"),
+            },
+            FieldSchema {
+                name: "display",
+                type_hint: "str | None",
+                base_type: "str",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "The message that will be displayed on the screen in quick chat.\nThis is intended for communication with humans.\nUse the content field for communication with bots and scripts.",
+                ),
+            },
+            FieldSchema {
+                name: "content",
+                type_hint: "bytes",
+                base_type: "bytes",
+                default: "bytes()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The contents of the message.\nUse the display field for messages in quick chat.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "MatchConfiguration",
+        kind: SchemaKind::Table,
+        doc: Some("Definition of a match.\nCan be sent to RLBot to request the start of a match."),
+        fields: &[
+            FieldSchema {
+                name: "launcher",
+                type_hint: "Launcher",
+                base_type: "Launcher",
+                default: "Launcher()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "How to launch Rocket League.\nIf left unset, RLBot will not launch the game.\nTo use Legendary, use Custom and set launcher_arg=\"legendary\".",
+                ),
+            },
+            FieldSchema {
+                name: "launcher_arg",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("Additional configuration for the launching method.\nSee launcher."),
+            },
+            FieldSchema {
+                name: "auto_start_agents",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If true, RLBot will start the bots and scripts that has a non-empty run command in their player/script configuration.",
+                ),
+            },
+            FieldSchema {
+                name: "wait_for_agents",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If true, RLBot will start the match only once all bots and script have connected and are ready.\nIf false, the match will start as soon as the map loads.",
+                ),
+            },
+            FieldSchema {
+                name: "game_map_upk",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The name of a upk file, like UtopiaStadium_P, which should be loaded.\nOn Steam version of Rocket League this can be used to load custom map files,\nbut on Epic version it only works on the Psyonix maps.\nAvailable maps can be found here: https://github.com/VirxEC/python-interface/blob/master/rlbot/utils/maps.py",
+                ),
+            },
+            FieldSchema {
+                name: "player_configurations",
+                type_hint: "Sequence[PlayerConfiguration]",
+                base_type: "PlayerConfiguration",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The players in the match."),
+            },
+            FieldSchema {
+                name: "script_configurations",
+                type_hint: "Sequence[ScriptConfiguration]",
+                base_type: "ScriptConfiguration",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The custom scripts used in the match."),
+            },
+            FieldSchema {
+                name: "game_mode",
+                type_hint: "GameMode",
+                base_type: "GameMode",
+                default: "GameMode()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The game mode.\nThis affects a few of the game rules although many game modes can also be recreated solely from mutators.\nSee what mutators and game mode combinations make up the official modes at https://github.com/VirxEC/python-interface/tree/master/tests/gamemodes",
+                ),
+            },
+            FieldSchema {
+                name: "skip_replays",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("Whether to skip goal replays."),
+            },
+            FieldSchema {
+                name: "instant_start",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("Whether to start without a kickoff countdown."),
+            },
+            FieldSchema {
+                name: "mutators",
+                type_hint: "MutatorSettings | None",
+                base_type: "MutatorSettings",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some("Mutator settings."),
+            },
+            FieldSchema {
+                name: "existing_match_behavior",
+                type_hint: "ExistingMatchBehavior",
+                base_type: "ExistingMatchBehavior",
+                default: "ExistingMatchBehavior()",
+                optional: false,
+                is_list: false,
+                doc: Some("How to handle any ongoing match."),
+            },
+            FieldSchema {
+                name: "enable_rendering",
+                type_hint: "DebugRendering",
+                base_type: "DebugRendering",
+                default: "DebugRendering()",
+                optional: false,
+                is_list: false,
+                doc: Some("Whether debug rendering is displayed."),
+            },
+            FieldSchema {
+                name: "enable_state_setting",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Whether clients are allowed to manipulate the game state, e.g. teleporting cars and ball.",
+                ),
+            },
+            FieldSchema {
+                name: "auto_save_replay",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("Whether the match replay should be saved."),
+            },
+            FieldSchema {
+                name: "freeplay",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If set to true, a free play match is launched instead of an exhibition match.\nThis allows the players to use training keybinds, Bakkesmod plugins, and other features that are only allowed in free play.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "MatchInfo",
+        kind: SchemaKind::Table,
+        doc: Some("Information about the current match such as time and gravity."),
+        fields: &[
+            FieldSchema {
+                name: "seconds_elapsed",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "How many seconds have elapsed since the first game packet of the match.\nThis value ticks up even during kickoffs, replays, pause, etc.",
+                ),
+            },
+            FieldSchema {
+                name: "game_time_remaining",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Seconds remaining of the match.\nThis value ticks up instead of down during overtime or when the game duration mutator is set to Unlimited.\nI.e. it matches the in-game timer at the top.",
+                ),
+            },
+            FieldSchema {
+                name: "is_overtime",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if the game is in overtime."),
+            },
+            FieldSchema {
+                name: "is_unlimited_time",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("True if the game duration is set to Unlimited."),
+            },
+            FieldSchema {
+                name: "match_phase",
+                type_hint: "MatchPhase",
+                base_type: "MatchPhase",
+                default: "MatchPhase()",
+                optional: false,
+                is_list: false,
+                doc: Some("The current phase of the match, i.e. kickoff, replay, active, etc."),
+            },
+            FieldSchema {
+                name: "world_gravity_z",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("The current strength of gravity. Default is -650."),
+            },
+            FieldSchema {
+                name: "game_speed",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Game speed multiplier. Regular game speed is 1.0."),
+            },
+            FieldSchema {
+                name: "last_spectated",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Index of the player who was most recently a spectated by the host."),
+            },
+            FieldSchema {
+                name: "frame_num",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Tracks the number of physics frames the game has computed.\nMay increase by more than one across consecutive packets.\nData type will roll over after 414 days at 120Hz.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "MatchLengthMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Match length mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "FiveMinutes",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TenMinutes",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TwentyMinutes",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Unlimited",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "MatchPhase",
+        kind: SchemaKind::Enum,
+        doc: Some("Possible phases of the match."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Inactive",
+                value: 0,
+                doc: Some("Match has not been created yet."),
+            },
+            VariantSchema {
+                name: "Countdown",
+                value: 1,
+                doc: Some("3-2-1 countdown of a kickoff."),
+            },
+            VariantSchema {
+                name: "Kickoff",
+                value: 2,
+                doc: Some(
+                    "After kickoff countdown, but before ball has been hit.\nThe match automatically proceeds to Active after 2 seconds.",
+                ),
+            },
+            VariantSchema {
+                name: "Active",
+                value: 3,
+                doc: Some("The ball is in play and time is ticking."),
+            },
+            VariantSchema {
+                name: "GoalScored",
+                value: 4,
+                doc: Some("A goal was just scored. Waiting for replay to start."),
+            },
+            VariantSchema {
+                name: "Replay",
+                value: 5,
+                doc: Some("Goal replay is being shown."),
+            },
+            VariantSchema {
+                name: "Paused",
+                value: 6,
+                doc: Some("The match is paused."),
+            },
+            VariantSchema {
+                name: "Ended",
+                value: 7,
+                doc: Some("The match has ended."),
+            },
+        ],
+    },
+    TypeSchema {
+        name: "MaxScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Max score mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Unlimited",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OneGoal",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThreeGoals",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FiveGoals",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SevenGoals",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TenGoals",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TwentyGoals",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThirtyGoals",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FortyGoals",
+                value: 8,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FiftyGoals",
+                value: 9,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SixtyGoals",
+                value: 10,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SeventyGoals",
+                value: 11,
+                doc: None,
+            },
+            VariantSchema {
+                name: "EightyGoals",
+                value: 12,
+                doc: None,
+            },
+            VariantSchema {
+                name: "NinetyGoals",
+                value: 13,
+                doc: None,
+            },
+            VariantSchema {
+                name: "HundredGoals",
+                value: 14,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "MaxTimeMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Max time mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Unlimited",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ElevenMinutes",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "MultiBallMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Multi ball mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "One",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Four",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Six",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "MutatorSettings",
+        kind: SchemaKind::Table,
+        doc: Some("All mutators options."),
+        fields: &[
+            FieldSchema {
+                name: "match_length",
+                type_hint: "MatchLengthMutator",
+                base_type: "MatchLengthMutator",
+                default: "MatchLengthMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Duration of the match."),
+            },
+            FieldSchema {
+                name: "max_score",
+                type_hint: "MaxScoreMutator",
+                base_type: "MaxScoreMutator",
+                default: "MaxScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Max score of match. If this score is reached, the team immediately wins.",
+                ),
+            },
+            FieldSchema {
+                name: "multi_ball",
+                type_hint: "MultiBallMutator",
+                base_type: "MultiBallMutator",
+                default: "MultiBallMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("The number of balls."),
+            },
+            FieldSchema {
+                name: "overtime",
+                type_hint: "OvertimeMutator",
+                base_type: "OvertimeMutator",
+                default: "OvertimeMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("The overtime rules and tiebreaker."),
+            },
+            FieldSchema {
+                name: "series_length",
+                type_hint: "SeriesLengthMutator",
+                base_type: "SeriesLengthMutator",
+                default: "SeriesLengthMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("The series length."),
+            },
+            FieldSchema {
+                name: "game_speed",
+                type_hint: "GameSpeedMutator",
+                base_type: "GameSpeedMutator",
+                default: "GameSpeedMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("A game speed multiplier."),
+            },
+            FieldSchema {
+                name: "ball_max_speed",
+                type_hint: "BallMaxSpeedMutator",
+                base_type: "BallMaxSpeedMutator",
+                default: "BallMaxSpeedMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball max speed."),
+            },
+            FieldSchema {
+                name: "ball_type",
+                type_hint: "BallTypeMutator",
+                base_type: "BallTypeMutator",
+                default: "BallTypeMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball type and shape."),
+            },
+            FieldSchema {
+                name: "ball_weight",
+                type_hint: "BallWeightMutator",
+                base_type: "BallWeightMutator",
+                default: "BallWeightMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball weight and how much is curves."),
+            },
+            FieldSchema {
+                name: "ball_size",
+                type_hint: "BallSizeMutator",
+                base_type: "BallSizeMutator",
+                default: "BallSizeMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball size."),
+            },
+            FieldSchema {
+                name: "ball_bounciness",
+                type_hint: "BallBouncinessMutator",
+                base_type: "BallBouncinessMutator",
+                default: "BallBouncinessMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball bounciness."),
+            },
+            FieldSchema {
+                name: "boost_amount",
+                type_hint: "BoostAmountMutator",
+                base_type: "BoostAmountMutator",
+                default: "BoostAmountMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Boost amount/recharge."),
+            },
+            FieldSchema {
+                name: "rumble",
+                type_hint: "RumbleMutator",
+                base_type: "RumbleMutator",
+                default: "RumbleMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Rumble item rules."),
+            },
+            FieldSchema {
+                name: "boost_strength",
+                type_hint: "BoostStrengthMutator",
+                base_type: "BoostStrengthMutator",
+                default: "BoostStrengthMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Boost strength multiplier."),
+            },
+            FieldSchema {
+                name: "gravity",
+                type_hint: "GravityMutator",
+                base_type: "GravityMutator",
+                default: "GravityMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Strength of gravity."),
+            },
+            FieldSchema {
+                name: "demolish",
+                type_hint: "DemolishMutator",
+                base_type: "DemolishMutator",
+                default: "DemolishMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Demolition conditions."),
+            },
+            FieldSchema {
+                name: "respawn_time",
+                type_hint: "RespawnTimeMutator",
+                base_type: "RespawnTimeMutator",
+                default: "RespawnTimeMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Demolition respawn time."),
+            },
+            FieldSchema {
+                name: "max_time",
+                type_hint: "MaxTimeMutator",
+                base_type: "MaxTimeMutator",
+                default: "MaxTimeMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Max real-time duration of match including kickoff, replays, and more.\nIf the score is tied upon time-out, the number of shots determine the winner.",
+                ),
+            },
+            FieldSchema {
+                name: "game_event",
+                type_hint: "GameEventMutator",
+                base_type: "GameEventMutator",
+                default: "GameEventMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Additional game behaviour for custom modes."),
+            },
+            FieldSchema {
+                name: "audio",
+                type_hint: "AudioMutator",
+                base_type: "AudioMutator",
+                default: "AudioMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Additional audio options for custom modes."),
+            },
+            FieldSchema {
+                name: "ball_gravity",
+                type_hint: "BallGravityMutator",
+                base_type: "BallGravityMutator",
+                default: "BallGravityMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Ball gravity."),
+            },
+            FieldSchema {
+                name: "territory",
+                type_hint: "TerritoryMutator",
+                base_type: "TerritoryMutator",
+                default: "TerritoryMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Territory mutator."),
+            },
+            FieldSchema {
+                name: "stale_ball",
+                type_hint: "StaleBallMutator",
+                base_type: "StaleBallMutator",
+                default: "StaleBallMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Stale ball mutator."),
+            },
+            FieldSchema {
+                name: "jump",
+                type_hint: "JumpMutator",
+                base_type: "JumpMutator",
+                default: "JumpMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Jumps mutator."),
+            },
+            FieldSchema {
+                name: "dodge_timer",
+                type_hint: "DodgeTimerMutator",
+                base_type: "DodgeTimerMutator",
+                default: "DodgeTimerMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Dodge timer mutator."),
+            },
+            FieldSchema {
+                name: "possession_score",
+                type_hint: "PossessionScoreMutator",
+                base_type: "PossessionScoreMutator",
+                default: "PossessionScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Possession score mutator."),
+            },
+            FieldSchema {
+                name: "demolish_score",
+                type_hint: "DemolishScoreMutator",
+                base_type: "DemolishScoreMutator",
+                default: "DemolishScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Demolish score mutator."),
+            },
+            FieldSchema {
+                name: "normal_goal_score",
+                type_hint: "NormalGoalScoreMutator",
+                base_type: "NormalGoalScoreMutator",
+                default: "NormalGoalScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Normal goal score mutator."),
+            },
+            FieldSchema {
+                name: "aerial_goal_score",
+                type_hint: "AerialGoalScoreMutator",
+                base_type: "AerialGoalScoreMutator",
+                default: "AerialGoalScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Aerial goal score mutator."),
+            },
+            FieldSchema {
+                name: "assist_goal_score",
+                type_hint: "AssistGoalScoreMutator",
+                base_type: "AssistGoalScoreMutator",
+                default: "AssistGoalScoreMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Assist goal score mutator."),
+            },
+            FieldSchema {
+                name: "input_restriction",
+                type_hint: "InputRestrictionMutator",
+                base_type: "InputRestrictionMutator",
+                default: "InputRestrictionMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Player input restriction mutator."),
+            },
+            FieldSchema {
+                name: "scoring_rule",
+                type_hint: "ScoringRuleMutator",
+                base_type: "ScoringRuleMutator",
+                default: "ScoringRuleMutator()",
+                optional: false,
+                is_list: false,
+                doc: Some("Additional rules about scoring (ball-goal interaction)."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "NormalGoalScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "One",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Zero",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Two",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Three",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Five",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Ten",
+                value: 5,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "OvertimeMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Overtime mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Unlimited",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FiveMaxFirstScore",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FiveMaxRandomTeam",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Physics",
+        kind: SchemaKind::Struct,
+        doc: Some("The physical state of an object."),
+        fields: &[
+            FieldSchema {
+                name: "location",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "rotation",
+                type_hint: "Rotator",
+                base_type: "Rotator",
+                default: "Rotator()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "velocity",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "angular_velocity",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PingRequest",
+        kind: SchemaKind::Table,
+        doc: Some("Ping request message"),
+        fields: &[FieldSchema {
+            name: "cookie",
+            type_hint: "int",
+            base_type: "int",
+            default: "0",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PingResponse",
+        kind: SchemaKind::Table,
+        doc: Some("Ping response message"),
+        fields: &[FieldSchema {
+            name: "cookie",
+            type_hint: "int",
+            base_type: "int",
+            default: "0",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PlayerClass",
+        kind: SchemaKind::Union,
+        doc: Some("Possible player types."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Human",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "CustomBot",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PsyonixBot",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "PlayerConfiguration",
+        kind: SchemaKind::Table,
+        doc: Some("A PlayerConfiguration defines a player of a match."),
+        fields: &[
+            FieldSchema {
+                name: "variety",
+                type_hint: "CustomBot | Human | PsyonixBot",
+                base_type: "PlayerClass",
+                default: "CustomBot()",
+                optional: false,
+                is_list: false,
+                doc: Some("The type of the player, i.e. human, Psyonix bot, or a custom bot."),
+            },
+            FieldSchema {
+                name: "team",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The team of the player. Blue is 0, orange is 1."),
+            },
+            FieldSchema {
+                name: "player_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The value will be set by RLBot and is always overriden.\nThis value is mostly used internally to keep track of participants in the match.\nThe player id can be used to find the corresponding player in the GamePacket.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PlayerInfo",
+        kind: SchemaKind::Table,
+        doc: Some("A collection of information about a player and their car."),
+        fields: &[
+            FieldSchema {
+                name: "physics",
+                type_hint: "Physics",
+                base_type: "Physics",
+                default: "Physics()",
+                optional: false,
+                is_list: false,
+                doc: Some("The physical state of the player's car."),
+            },
+            FieldSchema {
+                name: "score_info",
+                type_hint: "ScoreInfo",
+                base_type: "ScoreInfo",
+                default: "ScoreInfo()",
+                optional: false,
+                is_list: false,
+                doc: Some("The various scores of this player, e.g. those on the leaderboard."),
+            },
+            FieldSchema {
+                name: "hitbox",
+                type_hint: "BoxShape",
+                base_type: "BoxShape",
+                default: "BoxShape()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The hitbox of the player's car.\nNote that the hitbox is not centered at the cars location.\nSee the hitbox offset.",
+                ),
+            },
+            FieldSchema {
+                name: "hitbox_offset",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some("The center of the hitbox in local coordinates."),
+            },
+            FieldSchema {
+                name: "latest_touch",
+                type_hint: "Touch | None",
+                base_type: "Touch",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "Information about the latest touch with a ball.\nIs null if the player has yet to touch the ball.",
+                ),
+            },
+            FieldSchema {
+                name: "air_state",
+                type_hint: "AirState",
+                base_type: "AirState",
+                default: "AirState()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Whether the player's car is on the ground or in the air, and what jump/dodging forces currently affects the car.",
+                ),
+            },
+            FieldSchema {
+                name: "dodge_timeout",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "How long until the player cannot dodge/double jump anymore.\nThe value is -1 while on ground or when airborne for too long after jumping.\nA dodge/double jump is possible for 1.25 seconds after the first jump plus\nup to an additional 0.2 seconds depending how long the jump button was pressed for the first jump.\nNote that falling off a surface instead of jumping, does not activate the dodge timeout, and making a dodge/double jump possible indefinitely.\nThis is commonly known as a flip reset.\nThe car is holding a flip reset if `air_state == InAir && !has_jumped && !has_double_jumped && !has_dodged`.",
+                ),
+            },
+            FieldSchema {
+                name: "demolished_timeout",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "How long until the player is not demolished anymore.\nThe value is -1 if while not demolished.",
+                ),
+            },
+            FieldSchema {
+                name: "is_supersonic",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Whether the player's car is moving at supersonic speed and can demolish.",
+                ),
+            },
+            FieldSchema {
+                name: "is_bot",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("Whether the player is a bot or a human."),
+            },
+            FieldSchema {
+                name: "name",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The name of the player as it appears in game, i.e. possibly appended with \"(2)\".\nThe original name can be found in the match configuration.",
+                ),
+            },
+            FieldSchema {
+                name: "team",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The team of the player."),
+            },
+            FieldSchema {
+                name: "boost",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("The current boost, from 0 to 100."),
+            },
+            FieldSchema {
+                name: "player_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The id of the player.\nThis value is mostly used internally to keep track of participants in the match.\nThe id can be used to find the corresponding PlayerConfiguration in the MatchConfiguration.",
+                ),
+            },
+            FieldSchema {
+                name: "accolades",
+                type_hint: "Sequence[str]",
+                base_type: "str",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some(
+                    "Events from the latest tick involving this player. Possible values include:\nWin, Loss, TimePlayed;\nShot, Assist, Center, Clear, PoolShot;\nGoal, AerialGoal, BicycleGoal, BulletGoal, BackwardsGoal, LongGoal, OvertimeGoal, TurtleGoal;\nAerialHit, BicycleHit, BulletHit, JuggleHit, FirstTouch, BallHit;\nSave, EpicSave, FreezeSave;\nHatTrick, Savior, Playmaker, MVP;\nFastestGoal, SlowestGoal, FurthestGoal, OwnGoal;\nMostBallTouches, FewestBallTouches, MostBoostPickups, FewestBoostPickups, BoostPickups;\nCarTouches, Demolition, Demolish;\nLowFive, HighFive;\nNote that the list clears every tick.",
+                ),
+            },
+            FieldSchema {
+                name: "last_input",
+                type_hint: "ControllerState",
+                base_type: "ControllerState",
+                default: "ControllerState()",
+                optional: false,
+                is_list: false,
+                doc: Some("The last controller input from this player."),
+            },
+            FieldSchema {
+                name: "has_jumped",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "True if the player has jumped into the air.\nSee dodge_timeout to know if a dodge/double jump is temporarily available.\nNote that falling off a surface instead of jumping, does not activate the dodge timeout, and making a dodge/double jump possible indefinitely.\nThis is commonly known as a flip reset.\nThe car is holding a flip reset if `air_state == InAir && !has_jumped && !has_double_jumped && !has_dodged`.",
+                ),
+            },
+            FieldSchema {
+                name: "has_double_jumped",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "True if the player has doubled jumped since it left the ground. False while on the ground.",
+                ),
+            },
+            FieldSchema {
+                name: "has_dodged",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "True if the player has dodged since it left the ground. False while the ground.",
+                ),
+            },
+            FieldSchema {
+                name: "dodge_elapsed",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The time in seconds since the last dodge was initiated.\nResets to 0 when the player lands on the ground.",
+                ),
+            },
+            FieldSchema {
+                name: "dodge_dir",
+                type_hint: "Vector2",
+                base_type: "Vector2",
+                default: "Vector2()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The unit direction of the latest dodge.\nThe value will be (0,0) if it was a stall.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PlayerInput",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A player index and the controller state of that player.\nUsed to indicate what the player is doing this tick.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "player_index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "controller_state",
+                type_hint: "ControllerState",
+                base_type: "ControllerState",
+                default: "ControllerState()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PlayerLoadout",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "Defines the car type, color, and other aspects of the player's appearance.\nSee https://wiki.rlbot.org/botmaking/bot-customization/",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "team_color_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "custom_color_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "car_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "decal_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "wheels_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "boost_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "antenna_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "hat_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "paint_finish_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "custom_finish_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "engine_audio_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "trails_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "goal_explosion_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "loadout_paint",
+                type_hint: "LoadoutPaint | None",
+                base_type: "LoadoutPaint",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "primary_color_lookup",
+                type_hint: "Color | None",
+                base_type: "Color",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "Sets the primary color of the car to the swatch that most closely matches the provided\nRGB color value. If set, this overrides teamColorId.",
+                ),
+            },
+            FieldSchema {
+                name: "secondary_color_lookup",
+                type_hint: "Color | None",
+                base_type: "Color",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "Sets the secondary color of the car to the swatch that most closely matches the provided\nRGB color value. If set, this overrides customColorId.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PolyLine3D",
+        kind: SchemaKind::Table,
+        doc: Some("A RenderMessage for a line in 3D space going through a series of points."),
+        fields: &[
+            FieldSchema {
+                name: "points",
+                type_hint: "Sequence[Vector3]",
+                base_type: "Vector3",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: None,
+            },
+            FieldSchema {
+                name: "color",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PossessionScoreMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Off",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OneSecond",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TwoSeconds",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThreeSeconds",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "PredictionSlice",
+        kind: SchemaKind::Struct,
+        doc: Some(
+            "An entry in the ball prediction describing where a ball will be at some future time.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "game_seconds",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The moment in game time that this prediction corresponds to.\nThis corresponds to 'seconds_elapsed' in the MatchInfo.",
+                ),
+            },
+            FieldSchema {
+                name: "physics",
+                type_hint: "Physics",
+                base_type: "Physics",
+                default: "Physics()",
+                optional: false,
+                is_list: false,
+                doc: Some("The predicted location and motion of the object."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PsyonixBot",
+        kind: SchemaKind::Table,
+        doc: Some("A Psyonix bot, e.g. All Star bot."),
+        fields: &[
+            FieldSchema {
+                name: "name",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Requested bot name. When match start, RLBot will ensure each bot has a unique name; bots with\nduplicate names will be renamed with a suffix like `(2)`. For psyonix bots, a blank name will\nbe replaced with one of the official names.",
+                ),
+            },
+            FieldSchema {
+                name: "loadout",
+                type_hint: "PlayerLoadout | None",
+                base_type: "PlayerLoadout",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some("The loadout of the player."),
+            },
+            FieldSchema {
+                name: "bot_skill",
+                type_hint: "PsyonixSkill",
+                base_type: "PsyonixSkill",
+                default: "PsyonixSkill()",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "PsyonixSkill",
+        kind: SchemaKind::Enum,
+        doc: Some("Various skill levels of Psyonix bots."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Beginner",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rookie",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Pro",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "AllStar",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Rect2D",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderMessage for a rectangle in 2D space.\nNote that the position and size is given in screen-space coordinates.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "x",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Screen-space x coordinate such that x=0 is left edge and x=1 is right edge of window.",
+                ),
+            },
+            FieldSchema {
+                name: "y",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Screen-space y coordinate such that y=0 is top edge and y=1 is bottom edge of window.",
+                ),
+            },
+            FieldSchema {
+                name: "width",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Screen-space size such that width=0.1 is 10% of window width."),
+            },
+            FieldSchema {
+                name: "height",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Screen-space size such that height=0.1 is 10% of window height."),
+            },
+            FieldSchema {
+                name: "color",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("Color of the rectangle."),
+            },
+            FieldSchema {
+                name: "h_align",
+                type_hint: "TextHAlign",
+                base_type: "TextHAlign",
+                default: "TextHAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The horizontal alignment of the rectangle."),
+            },
+            FieldSchema {
+                name: "v_align",
+                type_hint: "TextVAlign",
+                base_type: "TextVAlign",
+                default: "TextVAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The vertical alignment of the rectangle."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "Rect3D",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderMessage for a rectangle in 3D space.\nNote that the size is given in screen-space sizes.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "anchor",
+                type_hint: "RenderAnchor",
+                base_type: "RenderAnchor",
+                default: "RenderAnchor()",
+                optional: false,
+                is_list: false,
+                doc: Some("The position of the rectangle."),
+            },
+            FieldSchema {
+                name: "width",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Screen-space size such that width=0.1 is 10% of window width."),
+            },
+            FieldSchema {
+                name: "height",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Screen-space size such that height=0.1 is 10% of window height."),
+            },
+            FieldSchema {
+                name: "color",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("The color of the rectangle."),
+            },
+            FieldSchema {
+                name: "h_align",
+                type_hint: "TextHAlign",
+                base_type: "TextHAlign",
+                default: "TextHAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The horizontal alignment of the anchor in the rectangle."),
+            },
+            FieldSchema {
+                name: "v_align",
+                type_hint: "TextVAlign",
+                base_type: "TextVAlign",
+                default: "TextVAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The vertical alignment of the anchor in the rectangle."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RelativeAnchor",
+        kind: SchemaKind::Union,
+        doc: Some("Anchors attached to objects."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "BallAnchor",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "CarAnchor",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "RemoveRenderGroup",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A client message request removal of a RenderGroup.\nA client can only clear its own RenderGroups.",
+        ),
+        fields: &[FieldSchema {
+            name: "id",
+            type_hint: "int",
+            base_type: "int",
+            default: "0",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RenderAnchor",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderAnchor is a point in space consisting of a world component and optionally a relative component.\nThe relative component is given by a car or ball and includes a local offset that takes the orientation of the object into account.\nThe RenderAnchor stays attached to the object and does not have to be updated each tick.\nRendering that uses a RenderAnchor attached to an object disappears if the object is destroyed, i.e. the car demolished or the ball is scored.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "world",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "An offset in global coordinates.\nIf the relative component is null, then this simply a point in 3D space.",
+                ),
+            },
+            FieldSchema {
+                name: "relative",
+                type_hint: "BallAnchor | CarAnchor | None",
+                base_type: "RelativeAnchor",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "An optional offset given by the position of an object and includes a local offset that takes the object's orientation into account.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RenderGroup",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A group of RenderMessages that are drawn and cleared together.\nA RenderGroup will stay rendered until it is overriden or cleared.\nThe group is identified by a unique id.\nA client can only clear its own RenderGroups.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "render_messages",
+                type_hint: "Sequence[RenderMessage]",
+                base_type: "RenderMessage",
+                default: "[]",
+                optional: false,
+                is_list: true,
+                doc: Some("The content of the RenderGroup."),
+            },
+            FieldSchema {
+                name: "id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The id of the RenderGroup."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RenderMessage",
+        kind: SchemaKind::Table,
+        doc: Some("A RenderMessage, describing a piece of debug rendering."),
+        fields: &[FieldSchema {
+            name: "variety",
+            type_hint: "Line3D | PolyLine3D | Rect2D | Rect3D | String2D | String3D",
+            base_type: "RenderType",
+            default: "Line3D()",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RenderType",
+        kind: SchemaKind::Union,
+        doc: Some("The different types of RenderMessages."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Line3D",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "PolyLine3D",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "String2D",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "String3D",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rect2D",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rect3D",
+                value: 6,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "RenderingStatus",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "As an interface message, this requests for a specificed agent to have its ability to render changed.\nThis changed will then be broadcasted to all current connections as a core message.\nDoes nothing if rendering has been completely disabled.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "If `is_bot`, this is the index of the bot in `GamePacket` that has been updated.\nOtherwise, this is the index of the script in `MatchConfiguration` that has been updated.",
+                ),
+            },
+            FieldSchema {
+                name: "is_bot",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("Identifies if the index is that of a bot or a script"),
+            },
+            FieldSchema {
+                name: "status",
+                type_hint: "bool",
+                base_type: "bool",
+                default: "False",
+                optional: false,
+                is_list: false,
+                doc: Some("If rendering is now enabled or disabled for the specific agent"),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RespawnTimeMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Respawn time mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "ThreeSeconds",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "TwoSeconds",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "OneSecond",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "DisableGoalReset",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Rotator",
+        kind: SchemaKind::Struct,
+        doc: Some(
+            "Expresses the rotation state of an object in Euler angles. Values are in radians.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "pitch",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "In radians with range (-pi/2,+pi/2) where 0 is flat, +pi/2 is nose straight up, -pi/2 is nose straight down.",
+                ),
+            },
+            FieldSchema {
+                name: "yaw",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "In radians with range [-pi,+pi) where 0 is towards positive x, rotating clockwise as increased (when seen from above).",
+                ),
+            },
+            FieldSchema {
+                name: "roll",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "In radians with range (-pi,+pi) where 0 is upright, positive is tilted right, negative is tilted left.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RotatorPartial",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A rotator describing a rotation with nullable pith, yaw, and roll.\nUsed for game state setting to define which part of a rotator should change.\nIf a component is null, then the component will keep its current value.\nValues are in radians.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "pitch",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "In radians with range (-pi/2,+pi/2) where 0 is flat, +pi/2 is nose straight up, -pi/2 is nose straight down.",
+                ),
+            },
+            FieldSchema {
+                name: "yaw",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "In radians with range [-pi,+pi) where 0 is towards positive x, rotating clockwise as increased (when seen from above).",
+                ),
+            },
+            FieldSchema {
+                name: "roll",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: Some(
+                    "In radians with range (-pi,+pi) where 0 is upright, positive is tilted right, negative is tilted left.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "RumbleMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Rumble mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Off",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "DefaultRumble",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Slow",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Civilized",
+                value: 3,
+                doc: None,
+            },
+            VariantSchema {
+                name: "DestructionDerby",
+                value: 4,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SpringLoaded",
+                value: 5,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SpikesOnly",
+                value: 6,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SpikeRush",
+                value: 7,
+                doc: None,
+            },
+            VariantSchema {
+                name: "HauntedBallBeam",
+                value: 8,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Tactical",
+                value: 9,
+                doc: None,
+            },
+            VariantSchema {
+                name: "BatmanRumble",
+                value: 10,
+                doc: None,
+            },
+            VariantSchema {
+                name: "GrapplingOnly",
+                value: 11,
+                doc: None,
+            },
+            VariantSchema {
+                name: "HaymakerOnly",
+                value: 12,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SpikeRushForce",
+                value: 13,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Rps",
+                value: 14,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "ScoreInfo",
+        kind: SchemaKind::Struct,
+        doc: Some("A collection of values shown on the scoreboard (and a few more)."),
+        fields: &[
+            FieldSchema {
+                name: "score",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The accumulated score, roughly indicating how well a player performs."),
+            },
+            FieldSchema {
+                name: "goals",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of goals scored."),
+            },
+            FieldSchema {
+                name: "own_goals",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of own-goals scored."),
+            },
+            FieldSchema {
+                name: "assists",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of goals assisted."),
+            },
+            FieldSchema {
+                name: "saves",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of shots saved."),
+            },
+            FieldSchema {
+                name: "shots",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of shots on opponent goal."),
+            },
+            FieldSchema {
+                name: "demolitions",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("Number of demolitions made."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "ScoringRuleMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Default",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Disabled",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "ScriptConfiguration",
+        kind: SchemaKind::Table,
+        doc: Some("A ScriptConfiguration defines a script of a match."),
+        fields: &[
+            FieldSchema {
+                name: "name",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("The name of the script."),
+            },
+            FieldSchema {
+                name: "root_dir",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The root directory of the script and the working directory for the run command.",
+                ),
+            },
+            FieldSchema {
+                name: "run_command",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("A console command that will start up the script."),
+            },
+            FieldSchema {
+                name: "script_id",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The id of the script.\nThis value is mostly used internally to keep track of participants in the match.",
+                ),
+            },
+            FieldSchema {
+                name: "agent_id",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "A unique user-defined string that is used to connect clients to the right players/scripts.\nIf a bot/script has a run command, RLBot will pass this agent id to the process using an environment variable, RLBOT_AGENT_ID.\nUpon connecting the process announces that it is responsible for this agent id and RLBot will pair the two.\nThe recommended format for agent ids is \"developername/botname\".",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "SeriesLengthMutator",
+        kind: SchemaKind::Enum,
+        doc: Some("Series length mutator options."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Unlimited",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThreeGames",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "FiveGames",
+                value: 2,
+                doc: None,
+            },
+            VariantSchema {
+                name: "SevenGames",
+                value: 3,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "SetLoadout",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A client message to change the loadout of a car.\nIf sent before the ready message, this simply sets the loadout of the car.\nIf sent after the ready message and if game state setting is enabled, this will respawn the car with the new loadout.\nBots can only set the loadout of their own car(s).",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the car to change loadout off."),
+            },
+            FieldSchema {
+                name: "loadout",
+                type_hint: "PlayerLoadout",
+                base_type: "PlayerLoadout",
+                default: "PlayerLoadout()",
+                optional: false,
+                is_list: false,
+                doc: Some("The new loadout of the car."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "SphereShape",
+        kind: SchemaKind::Table,
+        doc: Some("A sphere with diameter.\nUsed for balls with a spherical shapes."),
+        fields: &[FieldSchema {
+            name: "diameter",
+            type_hint: "float",
+            base_type: "float",
+            default: "0.0",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "StaleBallMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Unlimited",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "ThirtySeconds",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "StartCommand",
+        kind: SchemaKind::Table,
+        doc: Some("A client message to start a match using a path to a match config file."),
+        fields: &[FieldSchema {
+            name: "config_path",
+            type_hint: "str",
+            base_type: "str",
+            default: "\"\"",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "StopCommand",
+        kind: SchemaKind::Table,
+        doc: Some("A client message to stop a match and optionally the RLBot server too."),
+        fields: &[FieldSchema {
+            name: "shutdown_server",
+            type_hint: "bool",
+            base_type: "bool",
+            default: "False",
+            optional: false,
+            is_list: false,
+            doc: None,
+        }],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "String2D",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A RenderMessage for text in 2D space.\nNote that the position is given in screen-space coordinates.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "text",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("The text to be displayed."),
+            },
+            FieldSchema {
+                name: "x",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Screen-space x coordinate such that x=0 is left edge and x=1 is right edge of window.",
+                ),
+            },
+            FieldSchema {
+                name: "y",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Screen-space y coordinate such that y=0 is top edge and y=1 is bottom edge of window.",
+                ),
+            },
+            FieldSchema {
+                name: "scale",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Scale of the text.\nWhen scale is 1, the characters are 20 pixels tall and 10 pixels wide.",
+                ),
+            },
+            FieldSchema {
+                name: "foreground",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("The color of the text."),
+            },
+            FieldSchema {
+                name: "background",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("The color of the background for the text."),
+            },
+            FieldSchema {
+                name: "h_align",
+                type_hint: "TextHAlign",
+                base_type: "TextHAlign",
+                default: "TextHAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The horizontal alignment of the text."),
+            },
+            FieldSchema {
+                name: "v_align",
+                type_hint: "TextVAlign",
+                base_type: "TextVAlign",
+                default: "TextVAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The vertical alignment of the text."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "String3D",
+        kind: SchemaKind::Table,
+        doc: Some("A RenderMessage for text in 3D space."),
+        fields: &[
+            FieldSchema {
+                name: "text",
+                type_hint: "str",
+                base_type: "str",
+                default: "\"\"",
+                optional: false,
+                is_list: false,
+                doc: Some("The text to be displayed."),
+            },
+            FieldSchema {
+                name: "anchor",
+                type_hint: "RenderAnchor",
+                base_type: "RenderAnchor",
+                default: "RenderAnchor()",
+                optional: false,
+                is_list: false,
+                doc: Some("The position of the text."),
+            },
+            FieldSchema {
+                name: "scale",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The scale of the text.\nWhen scale is 1, the characters are 20 pixels tall and 10 pixels wide.",
+                ),
+            },
+            FieldSchema {
+                name: "foreground",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("The color of the text."),
+            },
+            FieldSchema {
+                name: "background",
+                type_hint: "Color",
+                base_type: "Color",
+                default: "Color()",
+                optional: false,
+                is_list: false,
+                doc: Some("The color of the background for the text."),
+            },
+            FieldSchema {
+                name: "h_align",
+                type_hint: "TextHAlign",
+                base_type: "TextHAlign",
+                default: "TextHAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The horizontal alignment of the text."),
+            },
+            FieldSchema {
+                name: "v_align",
+                type_hint: "TextVAlign",
+                base_type: "TextVAlign",
+                default: "TextVAlign()",
+                optional: false,
+                is_list: false,
+                doc: Some("The vertical alignment of the text."),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "TeamInfo",
+        kind: SchemaKind::Struct,
+        doc: Some("Information about teams. Currently only the number of goals scored."),
+        fields: &[
+            FieldSchema {
+                name: "team_index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some("The index of the team. Blue is 0, orange is 1."),
+            },
+            FieldSchema {
+                name: "score",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "Number of goals scored.\nNote, this value may be different than the sum of the goals scored by the current players on the team as player may join/leave the game or switch teams.\nThis value is what is shown on the heads-up display.",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "TerritoryMutator",
+        kind: SchemaKind::Enum,
+        doc: None,
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Off",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Territory",
+                value: 1,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "TextHAlign",
+        kind: SchemaKind::Enum,
+        doc: Some("Horizontal text alignment."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Left",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Center",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Right",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "TextVAlign",
+        kind: SchemaKind::Enum,
+        doc: Some("Vertical text alignment."),
+        fields: &[],
+        variants: &[
+            VariantSchema {
+                name: "Top",
+                value: 0,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Center",
+                value: 1,
+                doc: None,
+            },
+            VariantSchema {
+                name: "Bottom",
+                value: 2,
+                doc: None,
+            },
+        ],
+    },
+    TypeSchema {
+        name: "Touch",
+        kind: SchemaKind::Table,
+        doc: Some("Information about a ball touch."),
+        fields: &[
+            FieldSchema {
+                name: "game_seconds",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: Some("Seconds that had elapsed in the game when the touch occurred."),
+            },
+            FieldSchema {
+                name: "location",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some("The point of contact for the touch."),
+            },
+            FieldSchema {
+                name: "normal",
+                type_hint: "Vector3",
+                base_type: "Vector3",
+                default: "Vector3()",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The direction of the touch as a unit vector pointing from the point of contact towards the center of the ball.",
+                ),
+            },
+            FieldSchema {
+                name: "ball_index",
+                type_hint: "int",
+                base_type: "int",
+                default: "0",
+                optional: false,
+                is_list: false,
+                doc: Some(
+                    "The index of the ball that was touched (in case there are multiple balls).",
+                ),
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "Vector2",
+        kind: SchemaKind::Struct,
+        doc: Some("A vector with an x and y component."),
+        fields: &[
+            FieldSchema {
+                name: "x",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "y",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "Vector3",
+        kind: SchemaKind::Struct,
+        doc: Some(
+            "A vector with an x, y, z component.\nNote that Rocket League uses a left-handed coordinate system.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "x",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "y",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "z",
+                type_hint: "float",
+                base_type: "float",
+                default: "0.0",
+                optional: false,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+    TypeSchema {
+        name: "Vector3Partial",
+        kind: SchemaKind::Table,
+        doc: Some(
+            "A 3D vector where x, y, and z can be null.\nUsed for game state setting to define which part of a vector should change.\nIf a component is null, then the component will keep its current value.",
+        ),
+        fields: &[
+            FieldSchema {
+                name: "x",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "y",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+            FieldSchema {
+                name: "z",
+                type_hint: "float | None",
+                base_type: "float",
+                default: "None",
+                optional: true,
+                is_list: false,
+                doc: None,
+            },
+        ],
+        variants: &[],
+    },
+];
@@ -0,0 +1,237 @@
+//! Runtime reflection of the flatbuffers schema, exposed to Python as `rlbot_flatbuffers.schema`.
+
+mod data;
+
+pub use data::TYPES;
+
+use pyo3::{exceptions::PyKeyError, prelude::*, types::*};
+use std::sync::OnceLock;
+
+const MODULE_NAME: &str = "rlbot_flatbuffers";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaKind {
+    Table,
+    Struct,
+    Enum,
+    Union,
+}
+
+impl SchemaKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Table => "table",
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Union => "union",
+        }
+    }
+}
+
+#[pyclass(module = "rlbot_flatbuffers.schema", frozen, skip_from_py_object)]
+#[derive(Clone, Copy, Debug)]
+pub struct TypeSchema {
+    pub name: &'static str,
+    pub kind: SchemaKind,
+    pub doc: Option<&'static str>,
+    pub fields: &'static [FieldSchema],
+    pub variants: &'static [VariantSchema],
+}
+
+#[pyclass(
+    module = "rlbot_flatbuffers.schema",
+    frozen,
+    get_all,
+    skip_from_py_object
+)]
+#[derive(Clone, Copy, Debug)]
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub type_hint: &'static str,
+    pub base_type: &'static str,
+    pub default: &'static str,
+    pub optional: bool,
+    pub is_list: bool,
+    pub doc: Option<&'static str>,
+}
+
+#[pyclass(
+    module = "rlbot_flatbuffers.schema",
+    frozen,
+    get_all,
+    skip_from_py_object
+)]
+#[derive(Clone, Copy, Debug)]
+pub struct VariantSchema {
+    pub name: &'static str,
+    pub value: u64,
+    pub doc: Option<&'static str>,
+}
+
+/// Finds the schema of the type with the given name
+#[must_use]
+pub fn find(name: &str) -> Option<&'static TypeSchema> {
+    TYPES
+        .binary_search_by_key(&name, |item| item.name)
+        .ok()
+        .map(|idx| &TYPES[idx])
+}
+
+/// A 64-bit FNV-1a hash of the names, fields and variants of every type,
+/// which changes whenever the schema changes in a way that affects the generated types
+#[must_use]
+pub fn hash() -> u64 {
+    static HASH: OnceLock<u64> = OnceLock::new();
+
+    *HASH.get_or_init(|| {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut write = |value: &[u8]| {
+            // a separator keeps e.g. "ab" + "c" and "a" + "bc" from having the same hash
+            for &byte in value.iter().chain(&[0]) {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+
+        for item in &TYPES {
+            write(item.name.as_bytes());
+            write(item.kind.as_str().as_bytes());
+
+            for field in item.fields {
+                write(field.name.as_bytes());
+                write(field.type_hint.as_bytes());
+            }
+
+            for variant in item.variants {
+                write(variant.name.as_bytes());
+                write(&variant.value.to_le_bytes());
+            }
+        }
+
+        hash
+    })
+}
+
+impl TypeSchema {
+    #[must_use]
+    pub fn find_field(&self, name: &str) -> Option<&'static FieldSchema> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+#[pymethods]
+impl TypeSchema {
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[getter]
+    fn kind(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
+    #[getter]
+    fn doc(&self) -> Option<&'static str> {
+        self.doc
+    }
+
+    #[getter]
+    fn fields<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, self.fields.iter().copied())
+    }
+
+    #[getter]
+    fn variants<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, self.variants.iter().copied())
+    }
+
+    /// Gets the schema of the field with the given name
+    fn field(&self, name: &str) -> PyResult<FieldSchema> {
+        self.find_field(name).copied().ok_or_else(|| {
+            PyKeyError::new_err(format!("{} has no field named '{name}'", self.name))
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TypeSchema(name='{}', kind='{}')",
+            self.name,
+            self.kind.as_str()
+        )
+    }
+}
+
+#[pymethods]
+impl FieldSchema {
+    fn __repr__(&self) -> String {
+        format!(
+            "FieldSchema(name='{}', type_hint='{}', default='{}')",
+            self.name, self.type_hint, self.default
+        )
+    }
+}
+
+#[pymethods]
+impl VariantSchema {
+    fn __repr__(&self) -> String {
+        format!("VariantSchema(name='{}', value={})", self.name, self.value)
+    }
+}
+
+/// Finds the schema of a generated class, or of the generated class it subclasses
+pub fn find_class(cls: &Bound<PyType>) -> PyResult<Option<&'static TypeSchema>> {
+    // walk the mro so that Python subclasses of generated types are also supported
+    for base in cls.mro() {
+        let base = base.cast_into::<PyType>()?;
+        if base.module()? != MODULE_NAME {
+            continue;
+        }
+
+        let name = base.name()?;
+        let name = name.to_str()?;
+
+        if let Some(schema) = find(name).or_else(|| find(name.strip_suffix("Builder")?)) {
+            return Ok(Some(schema));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Gets the schema of a type from its name, its class, or an instance of it
+#[pyfunction]
+fn get(obj: &Bound<PyAny>) -> PyResult<TypeSchema> {
+    let schema = if let Ok(name) = obj.cast::<PyString>() {
+        find(name.to_str()?)
+    } else if let Ok(cls) = obj.cast::<PyType>() {
+        find_class(cls)?
+    } else {
+        find_class(&obj.get_type())?
+    };
+
+    match schema {
+        Some(schema) => Ok(*schema),
+        None => Err(PyKeyError::new_err(format!(
+            "No schema found for {}",
+            obj.repr()?
+        ))),
+    }
+}
+
+/// Gets the schema of every type, sorted by name
+#[pyfunction]
+fn types() -> Vec<TypeSchema> {
+    TYPES.to_vec()
+}
+
+pub fn module(py: Python) -> PyResult<Bound<PyModule>> {
+    let m = PyModule::new(py, "schema")?;
+    m.add("__doc__", "Runtime reflection of the flatbuffers schema.")?;
+    m.add_class::<TypeSchema>()?;
+    m.add_class::<FieldSchema>()?;
+    m.add_class::<VariantSchema>()?;
+    m.add_function(wrap_pyfunction!(get, &m)?)?;
+    m.add_function(wrap_pyfunction!(types, &m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,630 @@
+//! Helpers for creating `DesiredGameState`s from the state of the game.
+//!
+//! The Python types are converted into [`GameState`], which is easier to compare and combine,
+//! and then back into a `DesiredGameState`.
+
+use crate::python::{
+    BallInfo, DesiredBallState, DesiredCarState, DesiredGameState, DesiredMatchInfo,
+    DesiredPhysics, GamePacket, Physics, PlayerInfo, Rotator, RotatorPartial, Vector3,
+    Vector3Partial,
+};
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::*,
+};
+use std::f64::consts::TAU;
+
+/// The parts of a `GamePacket` that `DesiredGameState.from_packet` can include
+const INCLUDE_OPTIONS: [&str; 4] = ["balls", "cars", "boost", "match_info"];
+
+#[derive(Clone, Copy, Debug)]
+struct Include {
+    balls: bool,
+    cars: bool,
+    boost: bool,
+    match_info: bool,
+}
+
+impl Include {
+    const ALL: Self = Self {
+        balls: true,
+        cars: true,
+        boost: true,
+        match_info: true,
+    };
+
+    /// `include` can be a single option or an iterable of them
+    fn from_py(include: Option<&Bound<PyAny>>) -> PyResult<Self> {
+        let Some(include) = include else {
+            return Ok(Self::ALL);
+        };
+
+        let options = if include.is_instance_of::<PyString>() {
+            vec![include.extract::<String>()?]
+        } else {
+            include
+                .try_iter()?
+                .map(|option| option?.extract::<String>())
+                .collect::<PyResult<_>>()?
+        };
+
+        let mut parsed = Self {
+            balls: false,
+            cars: false,
+            boost: false,
+            match_info: false,
+        };
+
+        for option in options {
+            match option.as_str() {
+                "balls" => parsed.balls = true,
+                "cars" => parsed.cars = true,
+                "boost" => parsed.boost = true,
+                "match_info" => parsed.match_info = true,
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unknown option '{option}' in include, expected one of: {}",
+                        INCLUDE_OPTIONS.join(", ")
+                    )));
+                }
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// How `DesiredGameState.merge` handles a value that's set by more than one state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Conflict {
+    Last,
+    Error,
+}
+
+impl Conflict {
+    fn from_str(conflict: &str) -> PyResult<Self> {
+        match conflict {
+            "last" => Ok(Self::Last),
+            "error" => Ok(Self::Error),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown conflict mode '{conflict}', expected 'last' or 'error'"
+            ))),
+        }
+    }
+}
+
+/// The components of a `Vector3Partial` or a `RotatorPartial`, where `None` is unset
+type Partial = [Option<f64>; 3];
+
+fn float_value(py: Python, value: &Option<Py<PyFloat>>) -> Option<f64> {
+    value.as_ref().map(|value| value.bind(py).value())
+}
+
+fn float_to_py(py: Python, value: Option<f64>) -> Option<Py<PyFloat>> {
+    value.map(|value| PyFloat::new(py, value).unbind())
+}
+
+fn vector_partial(py: Python, vec: &Py<Vector3>) -> Partial {
+    let vec = vec.borrow(py);
+    [&vec.x, &vec.y, &vec.z].map(|value| Some(value.bind(py).value()))
+}
+
+fn rotator_partial(py: Python, rot: &Py<Rotator>) -> Partial {
+    let rot = rot.borrow(py);
+    [&rot.pitch, &rot.yaw, &rot.roll].map(|value| Some(value.bind(py).value()))
+}
+
+fn from_vector3_partial(py: Python, vec: &Option<Py<Vector3Partial>>) -> Partial {
+    vec.as_ref().map_or([None; 3], |vec| {
+        let vec = vec.borrow(py);
+        [&vec.x, &vec.y, &vec.z].map(|value| float_value(py, value))
+    })
+}
+
+fn from_rotator_partial(py: Python, rot: &Option<Py<RotatorPartial>>) -> Partial {
+    rot.as_ref().map_or([None; 3], |rot| {
+        let rot = rot.borrow(py);
+        [&rot.pitch, &rot.yaw, &rot.roll].map(|value| float_value(py, value))
+    })
+}
+
+fn to_vector3_partial(py: Python, partial: Partial) -> PyResult<Option<Py<Vector3Partial>>> {
+    if partial == [None; 3] {
+        return Ok(None);
+    }
+
+    let [x, y, z] = partial.map(|value| float_to_py(py, value));
+    Py::new(py, Vector3Partial { x, y, z }).map(Some)
+}
+
+fn to_rotator_partial(py: Python, partial: Partial) -> PyResult<Option<Py<RotatorPartial>>> {
+    if partial == [None; 3] {
+        return Ok(None);
+    }
+
+    let [pitch, yaw, roll] = partial.map(|value| float_to_py(py, value));
+    Py::new(py, RotatorPartial { pitch, yaw, roll }).map(Some)
+}
+
+/// Keeps `target` only if it's more than `tolerance` away from `current`
+fn diff_value(target: Option<f64>, current: Option<f64>, tolerance: f64) -> Option<f64> {
+    target.filter(|&target| current.is_none_or(|current| (target - current).abs() > tolerance))
+}
+
+/// Like [`diff_value`], but for angles in radians that may have wrapped around
+fn diff_angle(target: Option<f64>, current: Option<f64>, tolerance: f64) -> Option<f64> {
+    target.filter(|&target| {
+        current.is_none_or(|current| {
+            let delta = (target - current).rem_euclid(TAU);
+            delta.min(TAU - delta) > tolerance
+        })
+    })
+}
+
+fn diff_partial(
+    target: Partial,
+    current: Partial,
+    tolerance: f64,
+    diff: fn(Option<f64>, Option<f64>, f64) -> Option<f64>,
+) -> Partial {
+    [0, 1, 2].map(|i| diff(target[i], current[i], tolerance))
+}
+
+const VECTOR_COMPONENTS: [&str; 3] = ["x", "y", "z"];
+const ROTATOR_COMPONENTS: [&str; 3] = ["pitch", "yaw", "roll"];
+
+/// Merges the values of the state at index `source` into the values merged so far
+struct Merger {
+    conflict: Conflict,
+    source: usize,
+}
+
+impl Merger {
+    fn value(
+        &self,
+        merged: &mut Option<f64>,
+        value: Option<f64>,
+        path: impl FnOnce() -> String,
+    ) -> PyResult<()> {
+        let Some(value) = value else {
+            return Ok(());
+        };
+
+        match *merged {
+            Some(existing) if self.conflict == Conflict::Error && existing != value => {
+                Err(PyValueError::new_err(format!(
+                    "Conflicting values for {}: {existing:?} and {value:?} (from states[{}])",
+                    path(),
+                    self.source
+                )))
+            }
+            _ => {
+                *merged = Some(value);
+                Ok(())
+            }
+        }
+    }
+
+    fn partial(
+        &self,
+        merged: &mut Partial,
+        partial: Partial,
+        path: &str,
+        components: [&str; 3],
+    ) -> PyResult<()> {
+        for (i, component) in components.into_iter().enumerate() {
+            self.value(&mut merged[i], partial[i], || format!("{path}.{component}"))?;
+        }
+
+        Ok(())
+    }
+
+    fn physics(
+        &self,
+        merged: &mut PhysicsState,
+        physics: &PhysicsState,
+        path: &str,
+    ) -> PyResult<()> {
+        self.partial(
+            &mut merged.location,
+            physics.location,
+            &format!("{path}.location"),
+            VECTOR_COMPONENTS,
+        )?;
+        self.partial(
+            &mut merged.rotation,
+            physics.rotation,
+            &format!("{path}.rotation"),
+            ROTATOR_COMPONENTS,
+        )?;
+        self.partial(
+            &mut merged.velocity,
+            physics.velocity,
+            &format!("{path}.velocity"),
+            VECTOR_COMPONENTS,
+        )?;
+        self.partial(
+            &mut merged.angular_velocity,
+            physics.angular_velocity,
+            &format!("{path}.angular_velocity"),
+            VECTOR_COMPONENTS,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct PhysicsState {
+    location: Partial,
+    rotation: Partial,
+    velocity: Partial,
+    angular_velocity: Partial,
+}
+
+impl PhysicsState {
+    fn from_physics(py: Python, physics: &Physics) -> Self {
+        Self {
+            location: vector_partial(py, &physics.location),
+            rotation: rotator_partial(py, &physics.rotation),
+            velocity: vector_partial(py, &physics.velocity),
+            angular_velocity: vector_partial(py, &physics.angular_velocity),
+        }
+    }
+
+    fn from_desired(py: Python, physics: &DesiredPhysics) -> Self {
+        Self {
+            location: from_vector3_partial(py, &physics.location),
+            rotation: from_rotator_partial(py, &physics.rotation),
+            velocity: from_vector3_partial(py, &physics.velocity),
+            angular_velocity: from_vector3_partial(py, &physics.angular_velocity),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn diff(&self, current: &Self, tolerance: f64) -> Self {
+        Self {
+            location: diff_partial(self.location, current.location, tolerance, diff_value),
+            rotation: diff_partial(self.rotation, current.rotation, tolerance, diff_angle),
+            velocity: diff_partial(self.velocity, current.velocity, tolerance, diff_value),
+            angular_velocity: diff_partial(
+                self.angular_velocity,
+                current.angular_velocity,
+                tolerance,
+                diff_value,
+            ),
+        }
+    }
+
+    fn to_py(self, py: Python) -> PyResult<Py<DesiredPhysics>> {
+        Py::new(
+            py,
+            DesiredPhysics {
+                location: to_vector3_partial(py, self.location)?,
+                rotation: to_rotator_partial(py, self.rotation)?,
+                velocity: to_vector3_partial(py, self.velocity)?,
+                angular_velocity: to_vector3_partial(py, self.angular_velocity)?,
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct CarState {
+    physics: PhysicsState,
+    boost_amount: Option<f64>,
+}
+
+impl CarState {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn diff(&self, current: &Self, tolerance: f64) -> Self {
+        Self {
+            physics: self.physics.diff(&current.physics, tolerance),
+            boost_amount: diff_value(self.boost_amount, current.boost_amount, tolerance),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct MatchState {
+    world_gravity_z: Option<f64>,
+    game_speed: Option<f64>,
+}
+
+impl MatchState {
+    fn diff(&self, current: &Self, tolerance: f64) -> Self {
+        Self {
+            world_gravity_z: diff_value(self.world_gravity_z, current.world_gravity_z, tolerance),
+            game_speed: diff_value(self.game_speed, current.game_speed, tolerance),
+        }
+    }
+}
+
+/// A `DesiredGameState` with its values unpacked from Python objects
+#[derive(Debug, Default)]
+struct GameState {
+    balls: Vec<PhysicsState>,
+    cars: Vec<CarState>,
+    match_info: MatchState,
+    console_commands: Vec<Py<PyAny>>,
+}
+
+impl GameState {
+    fn from_packet(py: Python, packet: &GamePacket, include: Include) -> PyResult<Self> {
+        let mut state = Self::default();
+
+        if include.balls {
+            for ball in packet.balls.bind(py) {
+                let ball = ball.cast_into::<BallInfo>()?;
+                state
+                    .balls
+                    .push(PhysicsState::from_physics(py, ball.get().physics.get()));
+            }
+        }
+
+        if include.cars || include.boost {
+            for player in packet.players.bind(py) {
+                let player = player.cast_into::<PlayerInfo>()?;
+                let player = player.get();
+
+                state.cars.push(CarState {
+                    physics: if include.cars {
+                        PhysicsState::from_physics(py, player.physics.get())
+                    } else {
+                        PhysicsState::default()
+                    },
+                    boost_amount: include.boost.then(|| player.boost.bind(py).value()),
+                });
+            }
+        }
+
+        if include.match_info {
+            let match_info = packet.match_info.get();
+            state.match_info = MatchState {
+                world_gravity_z: Some(match_info.world_gravity_z.bind(py).value()),
+                game_speed: Some(match_info.game_speed.bind(py).value()),
+            };
+        }
+
+        Ok(state)
+    }
+
+    fn from_desired(py: Python, desired: &DesiredGameState) -> PyResult<Self> {
+        let mut state = Self::default();
+
+        for ball in desired.ball_states.bind(py) {
+            let ball = ball.cast_into::<DesiredBallState>()?;
+            state.balls.push(PhysicsState::from_desired(
+                py,
+                &ball.borrow().physics.borrow(py),
+            ));
+        }
+
+        for car in desired.car_states.bind(py) {
+            let car = car.cast_into::<DesiredCarState>()?;
+            let car = car.borrow();
+
+            state.cars.push(CarState {
+                physics: car
+                    .physics
+                    .as_ref()
+                    .map(|physics| PhysicsState::from_desired(py, &physics.borrow(py)))
+                    .unwrap_or_default(),
+                boost_amount: float_value(py, &car.boost_amount),
+            });
+        }
+
+        if let Some(match_info) = &desired.match_info {
+            let match_info = match_info.borrow(py);
+            state.match_info = MatchState {
+                world_gravity_z: float_value(py, &match_info.world_gravity_z),
+                game_speed: float_value(py, &match_info.game_speed),
+            };
+        }
+
+        state.console_commands = desired
+            .console_commands
+            .bind(py)
+            .iter()
+            .map(Bound::unbind)
+            .collect();
+
+        Ok(state)
+    }
+
+    /// Keeps only what's needed to go from `current` to `self`.
+    /// Unchanged balls and cars are left empty, and trailing ones are removed
+    fn diff(self, current: &Self, tolerance: f64) -> Self {
+        let mut balls: Vec<_> = self
+            .balls
+            .iter()
+            .enumerate()
+            .map(|(i, ball)| match current.balls.get(i) {
+                Some(current) => ball.diff(current, tolerance),
+                None => *ball,
+            })
+            .collect();
+        while balls.last().is_some_and(PhysicsState::is_empty) {
+            balls.pop();
+        }
+
+        let mut cars: Vec<_> = self
+            .cars
+            .iter()
+            .enumerate()
+            .map(|(i, car)| match current.cars.get(i) {
+                Some(current) => car.diff(current, tolerance),
+                None => *car,
+            })
+            .collect();
+        while cars.last().is_some_and(CarState::is_empty) {
+            cars.pop();
+        }
+
+        Self {
+            balls,
+            cars,
+            match_info: self.match_info.diff(&current.match_info, tolerance),
+            console_commands: self.console_commands,
+        }
+    }
+
+    /// Combines the states in order, merging balls and cars by index
+    /// and concatenating the console commands
+    fn merge(states: Vec<Self>, conflict: Conflict) -> PyResult<Self> {
+        let mut merged = Self::default();
+
+        for (source, state) in states.into_iter().enumerate() {
+            let merger = Merger { conflict, source };
+
+            if merged.balls.len() < state.balls.len() {
+                merged
+                    .balls
+                    .resize(state.balls.len(), PhysicsState::default());
+            }
+            for (i, ball) in state.balls.iter().enumerate() {
+                merger.physics(
+                    &mut merged.balls[i],
+                    ball,
+                    &format!("ball_states[{i}].physics"),
+                )?;
+            }
+
+            if merged.cars.len() < state.cars.len() {
+                merged.cars.resize(state.cars.len(), CarState::default());
+            }
+            for (i, car) in state.cars.iter().enumerate() {
+                merger.physics(
+                    &mut merged.cars[i].physics,
+                    &car.physics,
+                    &format!("car_states[{i}].physics"),
+                )?;
+                merger.value(&mut merged.cars[i].boost_amount, car.boost_amount, || {
+                    format!("car_states[{i}].boost_amount")
+                })?;
+            }
+
+            merger.value(
+                &mut merged.match_info.world_gravity_z,
+                state.match_info.world_gravity_z,
+                || String::from("match_info.world_gravity_z"),
+            )?;
+            merger.value(
+                &mut merged.match_info.game_speed,
+                state.match_info.game_speed,
+                || String::from("match_info.game_speed"),
+            )?;
+
+            merged.console_commands.extend(state.console_commands);
+        }
+
+        Ok(merged)
+    }
+
+    fn into_py(self, py: Python) -> PyResult<DesiredGameState> {
+        let ball_states = PyList::empty(py);
+        for ball in self.balls {
+            ball_states.append(DesiredBallState {
+                physics: ball.to_py(py)?,
+            })?;
+        }
+
+        let car_states = PyList::empty(py);
+        for car in self.cars {
+            let physics = if car.physics.is_empty() {
+                None
+            } else {
+                Some(car.physics.to_py(py)?)
+            };
+
+            car_states.append(DesiredCarState {
+                physics,
+                boost_amount: float_to_py(py, car.boost_amount),
+            })?;
+        }
+
+        let match_info = if self.match_info == MatchState::default() {
+            None
+        } else {
+            Some(Py::new(
+                py,
+                DesiredMatchInfo {
+                    world_gravity_z: float_to_py(py, self.match_info.world_gravity_z),
+                    game_speed: float_to_py(py, self.match_info.game_speed),
+                },
+            )?)
+        };
+
+        Ok(DesiredGameState {
+            ball_states: ball_states.unbind(),
+            car_states: car_states.unbind(),
+            match_info,
+            console_commands: PyList::new(py, self.console_commands)?.unbind(),
+        })
+    }
+}
+
+#[pymethods]
+impl DesiredGameState {
+    /// Creates a desired game state that restores everything in `packet`.
+    /// `include` limits it to some of `"balls"`, `"cars"`, `"boost"` and `"match_info"`
+    #[staticmethod]
+    #[pyo3(signature = (packet, include=None))]
+    fn from_packet(
+        py: Python,
+        packet: PyRef<GamePacket>,
+        include: Option<&Bound<PyAny>>,
+    ) -> PyResult<Self> {
+        GameState::from_packet(py, &packet, Include::from_py(include)?)?.into_py(py)
+    }
+
+    /// Creates the smallest desired game state that turns `current` into `target`.
+    /// Only values that differ by more than `tolerance` are set,
+    /// and balls and cars that don't need to change are left empty.
+    /// `tolerance` is intentionally shared by every value regardless of its unit,
+    /// since it's meant to skip float rounding rather than small but real changes
+    #[staticmethod]
+    #[pyo3(signature = (current, target, tolerance=0.01))]
+    fn diff(
+        py: Python,
+        current: PyRef<GamePacket>,
+        target: &Bound<PyAny>,
+        tolerance: f64,
+    ) -> PyResult<Self> {
+        let current = GameState::from_packet(py, &current, Include::ALL)?;
+
+        let target = if let Ok(packet) = target.cast::<GamePacket>() {
+            GameState::from_packet(py, packet.get(), Include::ALL)?
+        } else if let Ok(desired) = target.cast::<DesiredGameState>() {
+            GameState::from_desired(py, &desired.borrow())?
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "Expected a GamePacket or DesiredGameState as the target, got {}",
+                target.get_type().name()?
+            )));
+        };
+
+        target.diff(&current, tolerance).into_py(py)
+    }
+
+    /// Combines `states` into one, where later states take priority.
+    /// Balls and cars are merged by index, and console commands are concatenated.
+    /// With `conflict="error"`, setting a value to two different values raises a `ValueError`
+    #[staticmethod]
+    #[pyo3(signature = (*states, conflict="last"))]
+    fn merge(py: Python, states: Vec<PyRef<Self>>, conflict: &str) -> PyResult<Self> {
+        let conflict = Conflict::from_str(conflict)?;
+
+        let states = states
+            .iter()
+            .map(|state| GameState::from_desired(py, state))
+            .collect::<PyResult<_>>()?;
+
+        GameState::merge(states, conflict)?.into_py(py)
+    }
+}